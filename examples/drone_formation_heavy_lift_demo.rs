@@ -59,6 +59,8 @@ fn create_heavy_lift_mission() -> MissionPayload {
         drone_fingerprint: None,
         priority: MissionPriority::High,
         tags: vec!["heavy-lift".to_string(), "formation-flight".to_string(), "cargo-transport".to_string(), "precision-landing".to_string()],
+        revision: 0,
+        supersedes: None,
     };
 
     // Define formation flight path from point A to point B
@@ -78,6 +80,7 @@ fn create_heavy_lift_mission() -> MissionPayload {
                 east: -73.5300, west: -73.5800,
                 min_altitude: 20.0, max_altitude: 150.0,
             }),
+            navigation_mode: NavigationMode::Gps,
         }],
         home_location: GeoCoordinate {
             latitude: 45.5017, longitude: -73.5673, altitude_msl: 0.0,
@@ -404,6 +407,7 @@ fn create_heavy_lift_mission() -> MissionPayload {
                 "DRONE-SE".to_string(),
             ],
             emergency_sync_timeout_ms: 5000,
+            partition_tolerance_ms: 3000,   // Ride out short mesh dropouts
         },
         attachment_points: vec![
             AttachmentPoint {
@@ -477,6 +481,7 @@ fn create_heavy_lift_mission() -> MissionPayload {
             east: -73.5600, west: -73.5750,
             min_altitude: 0.0, max_altitude: 120.0,
         }),
+        min_operational_drones: 4,
     });
 
     mission
@@ -794,6 +799,7 @@ fn simulate_mission_execution(mission: &MissionPayload) -> Result<(), Box<dyn st
 
 /// Demonstrate emergency procedures for formation operations
 fn demonstrate_emergency_procedures(mission: &MissionPayload) -> Result<(), Box<dyn std::error::Error>> {
+    let formation = mission.formation_config.as_ref().unwrap();
     println!("
 🚨 Emergency Procedures Demonstration");
     println!("=======================================");
@@ -835,7 +841,16 @@ Scenario: {} - {}", scenario, description);
                 println!("    1. Redundancy activation: Alternate mesh routes ✓");
                 println!("    2. Position estimation: GPS/IMU backup navigation ✓");
                 println!("    3. Formation integrity: Visual proximity monitoring ✓");
-                println!("    4. Emergency landing initiated if connectivity <60% ✓");
+                let lost_links: Vec<MeshLink> = formation.communication_mesh.iter()
+                    .filter(|link| link.from_drone == "DRONE-SE" || link.to_drone == "DRONE-SE")
+                    .cloned()
+                    .collect();
+                match formation.on_partition(&lost_links, Duration::from_secs(5)) {
+                    FormationResponse::Continue => println!("    4. Mesh still connected: Mission continues ✓"),
+                    FormationResponse::Hold { remaining, .. } => println!("    4. Holding position: {:.1}s of partition tolerance left ✓", remaining.as_secs_f32()),
+                    FormationResponse::Reconfigure { dropped_drones } => println!("    4. Reconfiguring without {:?} ✓", dropped_drones),
+                    FormationResponse::TriggerFailSafe { behavior, isolated_drones } => println!("    4. Partition of {:?} exceeded tolerance: {:?} initiated ✓", isolated_drones, behavior),
+                }
             },
             "Battery Critical" => {
                 println!("    1. Power conservation: Minimal thrust adjustments ✓");
@@ -224,6 +224,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize mission transfer interfaces
     let mut station_transfer = MissionStation::new();
     let mut drone_transfer = MissionDrone::new();
+    drone_transfer.trust_station_key(station_transfer.signing_public_key());
 
     // Run the complete workflow
     let transfer_result = execute_mission_transfer_workflow(
@@ -301,6 +302,8 @@ fn create_sample_mission() -> MissionPayload {
         drone_fingerprint: Some([0xBB; 32]),
         priority: MissionPriority::High,
         tags: vec!["surveillance".to_string(), "urban".to_string(), "thermal".to_string()],
+        revision: 0,
+        supersedes: None,
     };
 
     // Define flight path with waypoints
@@ -341,6 +344,7 @@ fn create_sample_mission() -> MissionPayload {
                 min_altitude: 50.0,
                 max_altitude: 120.0,
             }),
+            navigation_mode: NavigationMode::Gps,
         }],
         home_location: GeoCoordinate {
             latitude: 45.5017,
@@ -504,6 +508,7 @@ fn create_sample_mission() -> MissionPayload {
                 "RTL_to_home".to_string(),
                 "descend_to_safe_altitude".to_string(),
             ],
+            populated_areas: Vec::new(),
         },
         environmental: EnvironmentalConstraints {
             max_temperature_c: 35.0,
@@ -547,6 +552,7 @@ fn create_sample_mission() -> MissionPayload {
                 contact_info: Some("emergency@control-center.com".to_string()),
             },
         ],
+        compliance_frameworks: Vec::new(),
     };
 
     mission.weather_snapshot = Some(WeatherSnapshot {
@@ -619,6 +625,8 @@ fn create_sample_drone_specs() -> DroneCapabilities {
             "Precision emergency landing".to_string(),
             "Data preservation on crash".to_string(),
         ],
+        battery_derating: BatteryDeratingCurve::default(),
+        navigation: NavigationCapabilities::default(),
     }
 }
 
@@ -634,5 +642,6 @@ fn convert_drone_specs(caps: &DroneCapabilities) -> DroneSpecifications {
         sensor_types: caps.supported_sensors.iter()
             .map(|s| s.sensor_type.clone())
             .collect(),
+        battery_derating: caps.battery_derating.clone(),
     }
 }
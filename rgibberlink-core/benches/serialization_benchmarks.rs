@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use gibberlink_core::fixtures::representative_missions;
+use gibberlink_core::mission::MissionPayload;
+
+/// Mission encodings under comparison
+#[derive(Clone, Copy)]
+enum Format {
+    Cbor,
+    Packed,
+    Json,
+}
+
+impl Format {
+    const ALL: [Format; 3] = [Format::Cbor, Format::Packed, Format::Json];
+
+    fn name(self) -> &'static str {
+        match self {
+            Format::Cbor => "cbor",
+            Format::Packed => "packed",
+            Format::Json => "json",
+        }
+    }
+
+    fn encode(self, mission: &MissionPayload) -> Vec<u8> {
+        match self {
+            Format::Cbor => serde_cbor::to_vec(mission).unwrap(),
+            Format::Packed => mission.to_packed().unwrap(),
+            Format::Json => serde_json::to_vec(mission).unwrap(),
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> MissionPayload {
+        match self {
+            Format::Cbor => serde_cbor::from_slice(bytes).unwrap(),
+            Format::Packed => MissionPayload::from_packed(bytes).unwrap(),
+            Format::Json => serde_json::from_slice(bytes).unwrap(),
+        }
+    }
+}
+
+fn serialization_benchmarks(c: &mut Criterion) {
+    for (mission_name, mission) in representative_missions() {
+        let mut group = c.benchmark_group(format!("mission_serialization/{}", mission_name));
+
+        for format in Format::ALL {
+            let encoded = format.encode(&mission);
+            // Criterion measures time only; report the encoded size alongside it
+            println!("{} {}: {} bytes", mission_name, format.name(), encoded.len());
+            group.throughput(Throughput::Bytes(encoded.len() as u64));
+
+            group.bench_function(format!("{}_encode", format.name()), |b| {
+                b.iter(|| black_box(format.encode(black_box(&mission))));
+            });
+            group.bench_function(format!("{}_decode", format.name()), |b| {
+                b.iter(|| black_box(format.decode(black_box(&encoded))));
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, serialization_benchmarks);
+criterion_main!(benches);
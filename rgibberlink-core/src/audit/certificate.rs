@@ -0,0 +1,126 @@
+//! # Completion Certificate Module
+//!
+//! Signed mission-completion certificates summarizing every audit event that
+//! shares a correlation ID, verifiable by anyone holding the signer's public key.
+
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+use crate::crypto::CryptoEngine;
+use super::events::{AuditError, AuditSeverity, ComplianceFlag, ResourceConsumption, SecurityAlert};
+
+/// Signed portion of a completion certificate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CertificateBody {
+    pub correlation_id: String,
+    pub issued_at: SystemTime,
+    pub entry_ids: Vec<String>, // In chronological order
+    pub started_at: SystemTime,
+    pub finished_at: SystemTime,
+    pub all_operations_succeeded: bool,
+    pub failed_operations: Vec<String>,
+    pub highest_severity: AuditSeverity,
+    pub compliance_flags: Vec<ComplianceFlag>, // Distinct flags across all events
+    pub resources: ResourceConsumption, // Summed across all events
+    pub alerts: Vec<SecurityAlert>, // Alerts raised by any of the events
+}
+
+/// Mission-completion certificate with detached Ed25519 signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionCertificate {
+    pub body: CertificateBody,
+    pub signer_public_key: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+impl CompletionCertificate {
+    /// Sign a certificate body
+    pub fn sign(body: CertificateBody, signer: &CryptoEngine) -> Result<Self, AuditError> {
+        let signature = signer.sign_data(&Self::signing_bytes(&body)?)
+            .map_err(|e| AuditError::CertificateError(e.to_string()))?;
+
+        Ok(Self {
+            body,
+            signer_public_key: signer.ed25519_public_key().to_vec(),
+            signature,
+        })
+    }
+
+    /// Verify the certificate was signed by `trusted_public_key` and has not been altered
+    pub fn verify(&self, trusted_public_key: &[u8]) -> Result<(), AuditError> {
+        if self.signer_public_key != trusted_public_key {
+            return Err(AuditError::CertificateError("Certificate signed by an untrusted key".to_string()));
+        }
+
+        CryptoEngine::verify_log_signature(trusted_public_key, &Self::signing_bytes(&self.body)?, &self.signature)
+            .map_err(|_| AuditError::CertificateError("Certificate signature is invalid".to_string()))
+    }
+
+    fn signing_bytes(body: &CertificateBody) -> Result<Vec<u8>, AuditError> {
+        serde_json::to_vec(body).map_err(|e| AuditError::CertificateError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditEventType, AuditSystem};
+    use crate::fixtures::system_audit_entry;
+    use super::super::events::{AlertStatus, AlertType};
+
+    fn formation_entry(correlation_id: &str, operation_name: &str, energy_wh: f32) -> crate::audit::AuditEntry {
+        let mut entry = system_audit_entry(AuditEventType::DroneCommand, AuditSeverity::Medium);
+        entry.entry_id = format!("{}_entry", operation_name);
+        entry.operation.operation_name = operation_name.to_string();
+        entry.operation.resource_consumption.energy_consumption_wh = energy_wh;
+        entry.context.correlation_id = correlation_id.to_string();
+        entry
+    }
+
+    fn alert_from(alert_id: &str, source_entry_id: &str, description: &str) -> SecurityAlert {
+        SecurityAlert {
+            alert_id: alert_id.to_string(),
+            timestamp: SystemTime::now(),
+            severity: AuditSeverity::Medium,
+            alert_type: AlertType::ComplianceDeviation,
+            title: "Test".to_string(),
+            description: description.to_string(),
+            affected_systems: vec![],
+            recommended_actions: vec![],
+            evidence: vec![],
+            status: AlertStatus::Active,
+            source_entry_id: Some(source_entry_id.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_formation_completion_certificate_verifies() {
+        let mut audit_system = AuditSystem::new(100);
+        for (correlation_id, operation_name) in [
+            ("FORMATION_a1b2", "formation_authorization"),
+            ("FORMATION_a1b2_ASSEMBLY", "formation_assembly"),
+            ("FORMATION_a1b2_TRANSPORT", "formation_transport"),
+            ("FORMATION_a1b2_COMPLETE", "formation_complete"),
+        ] {
+            audit_system.record_event(formation_entry(correlation_id, operation_name, 12.5)).unwrap();
+        }
+        audit_system.record_event(formation_entry("FORMATION_ffff", "other_mission", 99.0)).unwrap();
+        audit_system.raise_alert(alert_from("alert_assembly", "formation_assembly_entry", "Assembly drifted")).unwrap();
+        // Mentioning an entry in the text does not tie the alert to it
+        audit_system.raise_alert(alert_from("alert_other", "other_mission_entry", "Unlike formation_assembly_entry")).unwrap();
+
+        let signer = CryptoEngine::new();
+        let certificate = audit_system.generate_completion_certificate("FORMATION_a1b2", &signer).unwrap();
+
+        assert_eq!(certificate.body.entry_ids.len(), 4);
+        let alert_ids: Vec<&str> = certificate.body.alerts.iter().map(|alert| alert.alert_id.as_str()).collect();
+        assert_eq!(alert_ids, vec!["alert_assembly"]);
+        assert!(certificate.body.all_operations_succeeded);
+        assert_eq!(certificate.body.resources.energy_consumption_wh, 50.0);
+        assert!(certificate.verify(signer.ed25519_public_key()).is_ok());
+
+        let mut tampered = certificate.clone();
+        tampered.body.all_operations_succeeded = false;
+        assert!(tampered.verify(signer.ed25519_public_key()).is_err());
+        assert!(certificate.verify(CryptoEngine::new().ed25519_public_key()).is_err());
+    }
+}
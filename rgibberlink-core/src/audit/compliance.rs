@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 use std::collections::HashMap;
 
-use super::events::{AuditEntry, AuditEventType, SecurityAlert, AlertType, AlertStatus, AuditSeverity};
+use super::events::{AuditEntry, AuditEventType, SecurityAlert, AlertType, AlertStatus, AuditSeverity, ComplianceFlag};
 use crate::weather::ViolationSeverity;
 
 /// Compliance engine for regulatory and policy validation
@@ -20,6 +20,42 @@ pub struct ComplianceEngine {
     regulatory_frameworks: Vec<RegulatoryFramework>,
     internal_policies: Vec<InternalPolicy>,
     compliance_rules: Vec<ComplianceRule>,
+    exemptions: Vec<ComplianceExemption>,
+    exemption_sequence: u64,
+}
+
+/// Events a compliance exemption applies to; empty fields match everything
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExemptionScope {
+    pub event_types: Vec<AuditEventType>,
+    pub correlation_id: Option<String>,
+}
+
+impl ExemptionScope {
+    /// Whether the entry falls inside this scope
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        (self.event_types.is_empty() || self.event_types.contains(&entry.event_type))
+            && self.correlation_id.as_ref().map_or(true, |id| *id == entry.context.correlation_id)
+    }
+}
+
+/// Time-boxed waiver turning violations of one rule code into exemptions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceExemption {
+    pub exemption_id: String,
+    pub rule_code: String, // Violation code waived, e.g. "CRITICAL_SEVERITY_REQUIRED"
+    pub scope: ExemptionScope,
+    pub justification: String,
+    pub approver: String,
+    pub granted_at: SystemTime,
+    pub expires_at: SystemTime,
+}
+
+impl ComplianceExemption {
+    /// Whether the exemption is still in force at `now`
+    pub fn is_active(&self, now: SystemTime) -> bool {
+        now < self.expires_at
+    }
 }
 
 /// Regulatory compliance framework
@@ -87,6 +123,24 @@ pub struct ComplianceRule {
     pub conditions: Vec<String>,
     pub actions: Vec<ComplianceAction>,
     pub priority: CompliancePriority,
+    #[serde(default)]
+    pub framework_id: Option<String>, // Rule belongs to this regulatory framework and only applies to missions declaring it; None applies to every event
+}
+
+/// Framework rule that fired for one of a mission's audit entries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TriggeredRule {
+    pub framework_id: String,
+    pub rule_id: String,
+    pub entry_id: String,
+}
+
+/// Result of evaluating a mission's audit trail against its declared frameworks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MissionComplianceReport {
+    pub evaluated_frameworks: Vec<String>,
+    pub triggered: Vec<TriggeredRule>,
+    pub warnings: Vec<ComplianceFlag>, // One per declared framework that is not registered
 }
 
 /// Compliance action types
@@ -114,6 +168,8 @@ impl ComplianceEngine {
         Self {
             regulatory_frameworks: Vec::new(),
             internal_policies: Vec::new(),
+            exemptions: Vec::new(),
+            exemption_sequence: 0,
             compliance_rules: vec![
                 ComplianceRule {
                     rule_id: "critical_operation_audit".to_string(),
@@ -126,6 +182,7 @@ impl ComplianceEngine {
                         ComplianceAction::GenerateReport { report_type: "critical_operation_audit".to_string() },
                     ],
                     priority: CompliancePriority::High,
+                    framework_id: None,
                 },
             ],
         }
@@ -146,12 +203,77 @@ impl ComplianceEngine {
         self.compliance_rules.push(rule);
     }
 
+    /// Waive violations coded `rule_code` for events in `scope` until `expires_at`.
+    /// Returns the exemption ID.
+    pub fn grant_exemption(&mut self, rule_code: &str, scope: ExemptionScope, justification: &str, approver: &str, expires_at: SystemTime) -> String {
+        self.exemption_sequence += 1;
+        let exemption_id = format!("exemption_{}", self.exemption_sequence);
+        self.exemptions.push(ComplianceExemption {
+            exemption_id: exemption_id.clone(),
+            rule_code: rule_code.to_string(),
+            scope,
+            justification: justification.to_string(),
+            approver: approver.to_string(),
+            granted_at: SystemTime::now(),
+            expires_at,
+        });
+        exemption_id
+    }
+
+    /// Exemption by ID, whether or not it has lapsed
+    pub fn exemption(&self, exemption_id: &str) -> Option<&ComplianceExemption> {
+        self.exemptions.iter().find(|exemption| exemption.exemption_id == exemption_id)
+    }
+
+    /// Reinstate an exemption granted earlier, e.g. when replaying a journal
+    pub fn restore_exemption(&mut self, exemption: ComplianceExemption) {
+        self.exemption_sequence += 1;
+        self.exemptions.push(exemption);
+    }
+
+    /// Exemptions still in force at `now`
+    pub fn active_exemptions(&self, now: SystemTime) -> Vec<&ComplianceExemption> {
+        self.exemptions.iter().filter(|exemption| exemption.is_active(now)).collect()
+    }
+
+    /// Remove and return the exemptions that have lapsed by `now`, earliest expiry first
+    pub fn expire_exemptions(&mut self, now: SystemTime) -> Vec<ComplianceExemption> {
+        let (mut expired, active): (Vec<_>, Vec<_>) = self.exemptions.drain(..)
+            .partition(|exemption| !exemption.is_active(now));
+        self.exemptions = active;
+        expired.sort_by_key(|exemption| exemption.expires_at);
+        expired
+    }
+
+    /// Compliance flags for an entry, with violations covered by an active exemption
+    /// reported as `Exemption` instead
+    pub fn evaluate_entry(&self, entry: &AuditEntry, now: SystemTime) -> Vec<ComplianceFlag> {
+        self.validate_event_compliance(&entry.event_type, &entry.severity)
+            .into_iter()
+            .map(|flag| {
+                let ComplianceFlag::Violation { code, .. } = &flag else {
+                    return flag;
+                };
+                match self.exemptions.iter().find(|exemption| {
+                    exemption.rule_code == *code && exemption.is_active(now) && exemption.scope.matches(entry)
+                }) {
+                    Some(exemption) => ComplianceFlag::Exemption {
+                        justification: exemption.justification.clone(),
+                        approver: exemption.approver.clone(),
+                    },
+                    None => flag,
+                }
+            })
+            .collect()
+    }
+
     /// Check compliance for audit entry
     pub fn check_compliance(&self, entry: &AuditEntry, alerts: &mut Vec<SecurityAlert>) -> Result<(), AuditError> {
-        for rule in &self.compliance_rules {
+        for rule in self.global_rules() {
             if rule.trigger_events.contains(&entry.event_type) {
                 // Evaluate conditions (simplified - in production would use proper expression evaluation)
-                let should_trigger = self.evaluate_conditions(entry, &rule.conditions);
+                let should_trigger = self.evaluate_conditions(entry, &rule.conditions)
+                    .map_err(|e| AuditError::ComplianceError(format!("Rule {}: {}", rule.rule_id, e)))?;
 
                 if should_trigger {
                     // Execute compliance actions
@@ -164,19 +286,94 @@ impl ComplianceEngine {
         Ok(())
     }
 
+    /// Rules not tied to a framework, checked on every recorded event
+    fn global_rules(&self) -> impl Iterator<Item = &ComplianceRule> {
+        self.compliance_rules.iter().filter(|rule| rule.framework_id.is_none())
+    }
+
+    /// Evaluate `entries` against the rules of exactly the frameworks in `framework_ids`,
+    /// executing the actions of every rule that triggers.
+    ///
+    /// Declared frameworks that are not registered are skipped with a warning rather than
+    /// failing the evaluation, so a mission naming a framework this station does not know
+    /// still gets checked against the ones it does.
+    pub fn evaluate_frameworks<'a>(
+        &self,
+        framework_ids: &[String],
+        entries: impl IntoIterator<Item = &'a AuditEntry>,
+        alerts: &mut Vec<SecurityAlert>,
+    ) -> Result<MissionComplianceReport, AuditError> {
+        let mut report = MissionComplianceReport::default();
+        for framework_id in framework_ids {
+            if report.evaluated_frameworks.contains(framework_id) {
+                continue;
+            }
+            if self.regulatory_frameworks.iter().any(|framework| framework.framework_id == *framework_id) {
+                report.evaluated_frameworks.push(framework_id.clone());
+            } else {
+                report.warnings.push(ComplianceFlag::Warning {
+                    message: format!("Unknown compliance framework '{}'; its rules were not evaluated", framework_id),
+                });
+            }
+        }
+
+        let rules: Vec<(&String, &ComplianceRule)> = self.compliance_rules.iter()
+            .filter_map(|rule| rule.framework_id.as_ref().map(|framework_id| (framework_id, rule)))
+            .filter(|(framework_id, _)| report.evaluated_frameworks.contains(framework_id))
+            .collect();
+        for entry in entries {
+            for (framework_id, rule) in &rules {
+                if !rule.trigger_events.contains(&entry.event_type) {
+                    continue;
+                }
+                let triggered = self.evaluate_conditions(entry, &rule.conditions)
+                    .map_err(|e| AuditError::ComplianceError(format!("Rule {}: {}", rule.rule_id, e)))?;
+                if triggered {
+                    for action in &rule.actions {
+                        self.execute_action(action, entry, alerts)?;
+                    }
+                    report.triggered.push(TriggeredRule {
+                        framework_id: framework_id.to_string(),
+                        rule_id: rule.rule_id.clone(),
+                        entry_id: entry.entry_id.clone(),
+                    });
+                }
+            }
+        }
+        Ok(report)
+    }
+
     /// Evaluate compliance rule conditions (simplified implementation)
-    fn evaluate_conditions(&self, entry: &AuditEntry, conditions: &[String]) -> bool {
+    ///
+    /// `params.<name> == '<value>'` compares an operation parameter; a rule naming a
+    /// parameter the entry does not carry is an error rather than a silent mismatch.
+    fn evaluate_conditions(&self, entry: &AuditEntry, conditions: &[String]) -> Result<bool, String> {
         for condition in conditions {
+            if let Some((name, expected)) = Self::parameter_condition(condition) {
+                let actual = entry.operation.parameters.get(name)
+                    .ok_or_else(|| format!("condition references missing parameter '{}'", name))?;
+                if actual.as_str().map_or(actual.to_string(), str::to_string) != expected {
+                    return Ok(false);
+                }
+                continue;
+            }
+
             match condition.as_str() {
                 "severity == 'Critical'" => {
                     if !matches!(entry.severity, AuditSeverity::Critical) {
-                        return false;
+                        return Ok(false);
                     }
                 }
                 _ => {} // Unknown conditions are ignored
             }
         }
-        true
+        Ok(true)
+    }
+
+    /// Split `params.<name> == '<value>'` into name and value
+    fn parameter_condition(condition: &str) -> Option<(&str, &str)> {
+        let (left, right) = condition.strip_prefix("params.")?.split_once("==")?;
+        Some((left.trim(), right.trim().trim_matches('\'')))
     }
 
     /// Execute compliance action
@@ -194,6 +391,7 @@ impl ComplianceEngine {
                     recommended_actions: vec!["Review audit entry details".to_string(), "Assess compliance impact".to_string()],
                     evidence: entry.evidence.clone(),
                     status: AlertStatus::Active,
+                    source_entry_id: Some(entry.entry_id.clone()),
                 };
                 alerts.push(alert);
             }
@@ -236,14 +434,14 @@ impl ComplianceEngine {
     pub fn validate_event_compliance(&self, event_type: &AuditEventType, severity: &AuditSeverity) -> Vec<ComplianceFlag> {
         let mut flags = Vec::new();
 
-        for rule in &self.compliance_rules {
+        for rule in self.global_rules() {
             if rule.trigger_events.contains(event_type) {
                 // Check if rule applies based on severity
                 match rule.priority {
                     CompliancePriority::Critical => {
                         if !matches!(severity, AuditSeverity::Critical) {
                             flags.push(ComplianceFlag::Violation {
-                                severity: ViolationSeverity::High,
+                                severity: ViolationSeverity::Critical,
                                 code: "CRITICAL_SEVERITY_REQUIRED".to_string(),
                                 message: "Critical operations require critical severity".to_string(),
                             });
@@ -279,15 +477,6 @@ pub enum AuditError {
     ComplianceError(String),
 }
 
-/// Compliance validation flags
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum ComplianceFlag {
-    Compliant,
-    Warning { message: String },
-    Violation { severity: ViolationSeverity, code: String, message: String },
-    Exemption { justification: String, approver: String },
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,10 +4,17 @@
 
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, Duration};
-use std::collections::HashMap;
-use crate::mission::{MissionId, MissionPriority};
+use std::collections::{HashMap, HashSet};
+use crate::mission::{MissionId, MissionPayload, MissionPriority};
 use crate::weather::{RiskLevel, ViolationSeverity};
-use super::compliance::{ComplianceEngine, SecurityAlert};
+use super::compliance::{ComplianceEngine, ComplianceExemption, ComplianceRule, ExemptionScope, MissionComplianceReport, RegulatoryFramework};
+use super::routing::AlertRouter;
+use super::journal::{AuditJournal, JournalOperation};
+use super::storage::EncryptedAuditStore;
+use super::certificate::{CertificateBody, CompletionCertificate};
+use super::risk::ThreatSummary;
+use super::query_cache::{QueryCache, QueryCacheConfig, QueryCacheStats};
+use crate::crypto::CryptoEngine;
 
 /// Comprehensive audit system for drone mission operations
 pub struct AuditSystem {
@@ -17,6 +24,56 @@ pub struct AuditSystem {
     compliance_engine: ComplianceEngine,
     report_generator: AuditReportGenerator,
     alerts: Vec<SecurityAlert>,
+    alert_router: Option<AlertRouter>,
+    sampling_policy: SamplingPolicy,
+    informational_seen: u64,
+    sampled_out: u64,
+    journal: Option<AuditJournal>,
+    max_entry_bytes: Option<usize>,
+    encrypted_store: Option<EncryptedAuditStore>,
+    capacity_warning_ratio: f32, // Fraction of max_entries that raises a capacity alert
+    capacity_warning_latched: bool,
+    max_alerts: usize,
+    suppression_alert_id: Option<String>, // Meta-alert reporting evictions, while it is held
+    evidence_blobs: Vec<StoredEvidence>,
+    compliance_failure_stance: ComplianceFailureStance,
+    clock_anomaly_mode: ClockAnomalyMode,
+    latest_timestamp: Option<SystemTime>, // Newest timestamp recorded, survives retention
+    entry_id_scheme: EntryIdScheme,
+    entry_sequence: u64,
+    idempotency_keys: HashMap<String, (String, SystemTime)>, // Idempotency key -> entry ID and timestamp, kept for the retention age
+    legal_holds: Vec<LegalHold>,
+    legal_hold_sequence: u64,
+    query_cache: Option<QueryCache>,
+}
+
+/// Preservation order over a fixed set of entries; retention never removes a held entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalHold {
+    pub hold_id: String,
+    pub placed_at: SystemTime,
+    pub entry_ids: HashSet<String>, // Entries matching the hold's query when it was placed
+}
+
+/// Evidence bytes held alongside the `DataBlob` artifact that describes them
+#[derive(Debug, Clone)]
+struct StoredEvidence {
+    entry_id: String,
+    hash: String, // SHA-256 recorded at attach time, hex encoded
+    bytes: Vec<u8>,
+}
+
+/// Aggregate view of the audit store, used to compare stores
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditSummary {
+    pub total_entries: usize,
+    pub high_severity_entries: usize, // High and Critical
+    pub total_alerts: usize,
+    pub active_alerts: usize,
+    pub oldest_entry: Option<SystemTime>,
+    pub newest_entry: Option<SystemTime>,
+    pub held_entries: usize, // Entries under at least one legal hold
+    pub active_legal_holds: usize,
 }
 
 /// Individual audit entry with full context
@@ -34,6 +91,20 @@ pub struct AuditEntry {
     pub compliance_flags: Vec<ComplianceFlag>,
     pub security_metadata: SecurityMetadata,
     pub evidence: Vec<EvidenceArtifact>,
+    #[serde(default)]
+    pub oversized: bool, // Evidence or parameters were truncated to fit the size limit
+    #[serde(default)]
+    pub clock_anomaly: bool, // Stamped earlier than an entry already recorded
+    #[serde(default)]
+    pub idempotency_key: Option<String>, // Caller-chosen key; retries with the same key are recorded once
+}
+
+impl AuditEntry {
+    /// Tag the entry so a retried `record_event` does not record it twice
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
 }
 
 /// Types of auditable events
@@ -254,6 +325,44 @@ pub struct RetentionPolicy {
     pub prioritized_events: Vec<AuditEventType>,
 }
 
+/// What `record_event` does when a compliance check itself fails
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ComplianceFailureStance {
+    FailClosed, // Reject the event
+    FailOpen,   // Record the event with a warning flag and raise a configuration alert
+}
+
+/// What `record_event` does with an entry stamped earlier than the newest recorded one
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ClockAnomalyMode {
+    Monotonic, // Clamp the timestamp to just after the newest recorded entry
+    Flag,      // Keep the timestamp and set `clock_anomaly` on the entry
+}
+
+/// How `record_event` generates IDs for entries that arrive without one
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum EntryIdScheme {
+    /// `audit_[<node>_]<millis>_<sequence>`; the per-system sequence keeps IDs unique within a millisecond
+    TimestampSequence { node_id: Option<String> },
+    /// Random 128-bit ID in UUID v4 format, for stores merged from several stations
+    Random,
+}
+
+/// Sampling applied to high-volume `Informational` events
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum SamplingPolicy {
+    RecordAll,
+    OneIn(u32), // Keep the first of every N informational events
+}
+
+/// Event types that are always recorded in full regardless of sampling
+const UNSAMPLED_EVENT_TYPES: [AuditEventType; 4] = [
+    AuditEventType::SecurityAuthentication,
+    AuditEventType::AuthorizationCheck,
+    AuditEventType::PolicyViolation,
+    AuditEventType::EmergencyAction,
+];
+
 /// Archival strategies for audit data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ArchivalStrategy {
@@ -276,10 +385,12 @@ pub struct SecurityAlert {
     pub recommended_actions: Vec<String>,
     pub evidence: Vec<EvidenceArtifact>,
     pub status: AlertStatus,
+    #[serde(default)]
+    pub source_entry_id: Option<String>, // Audit entry that raised the alert, if any
 }
 
 /// Types of security alerts
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AlertType {
     PolicyViolation,
     UnauthorizedAccess,
@@ -323,17 +434,608 @@ impl AuditSystem {
             compliance_engine: ComplianceEngine::new(),
             report_generator: AuditReportGenerator::new(),
             alerts: Vec::new(),
+            alert_router: None,
+            sampling_policy: SamplingPolicy::RecordAll,
+            informational_seen: 0,
+            sampled_out: 0,
+            journal: None,
+            max_entry_bytes: None,
+            encrypted_store: None,
+            capacity_warning_ratio: 0.9,
+            capacity_warning_latched: false,
+            max_alerts: 10_000,
+            suppression_alert_id: None,
+            evidence_blobs: Vec::new(),
+            compliance_failure_stance: ComplianceFailureStance::FailClosed,
+            clock_anomaly_mode: ClockAnomalyMode::Monotonic,
+            latest_timestamp: None,
+            entry_id_scheme: EntryIdScheme::TimestampSequence { node_id: None },
+            entry_sequence: 0,
+            idempotency_keys: HashMap::new(),
+            legal_holds: Vec::new(),
+            legal_hold_sequence: 0,
+            query_cache: None,
+        }
+    }
+
+    /// Choose how IDs are generated for entries recorded without one
+    pub fn set_entry_id_scheme(&mut self, scheme: EntryIdScheme) {
+        self.entry_id_scheme = scheme;
+    }
+
+    fn next_entry_id(&mut self) -> String {
+        match &self.entry_id_scheme {
+            EntryIdScheme::TimestampSequence { node_id } => {
+                let millis = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis();
+                self.entry_sequence += 1;
+                match node_id {
+                    Some(node_id) => format!("audit_{}_{}_{}", node_id, millis, self.entry_sequence),
+                    None => format!("audit_{}_{}", millis, self.entry_sequence),
+                }
+            }
+            EntryIdScheme::Random => {
+                let mut bytes: [u8; 16] = rand::random();
+                bytes[6] = (bytes[6] & 0x0f) | 0x40; // Version 4
+                bytes[8] = (bytes[8] & 0x3f) | 0x80; // RFC 4122 variant
+                let hex = hex::encode(bytes);
+                format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+            }
+        }
+    }
+
+    /// Choose how entries stamped before the newest recorded entry are handled
+    pub fn set_clock_anomaly_mode(&mut self, mode: ClockAnomalyMode) {
+        self.clock_anomaly_mode = mode;
+    }
+
+    /// Keep timestamps ordered when the station clock jumps backwards (e.g. an NTP correction)
+    fn handle_clock_anomaly(&mut self, entry: &mut AuditEntry) {
+        let Some(latest) = self.latest_timestamp else {
+            return;
+        };
+        if entry.timestamp >= latest {
+            return;
+        }
+
+        let jump = latest.duration_since(entry.timestamp).unwrap_or_default();
+        match self.clock_anomaly_mode {
+            ClockAnomalyMode::Monotonic => entry.timestamp = latest + Duration::from_millis(1),
+            ClockAnomalyMode::Flag => entry.clock_anomaly = true,
+        }
+        self.alerts.push(SecurityAlert {
+            alert_id: format!("alert_clock_{}_{}", entry.entry_id, SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()),
+            timestamp: SystemTime::now(),
+            severity: AuditSeverity::Medium,
+            alert_type: AlertType::ConfigurationError,
+            title: "Audit Clock Went Backwards".to_string(),
+            description: format!("Entry {} is stamped {} ms before the newest recorded entry ({:?} mode)",
+                entry.entry_id, jump.as_millis(), self.clock_anomaly_mode),
+            affected_systems: vec!["audit_system".to_string()],
+            recommended_actions: vec!["Check the station time source".to_string(), "Verify NTP synchronization".to_string()],
+            evidence: vec![],
+            status: AlertStatus::Active,
+            source_entry_id: Some(entry.entry_id.clone()),
+        });
+    }
+
+    /// Choose whether a failing compliance check rejects the event or lets it through flagged
+    pub fn set_compliance_failure_stance(&mut self, stance: ComplianceFailureStance) {
+        self.compliance_failure_stance = stance;
+    }
+
+    /// Add a compliance rule evaluated on every recorded event
+    pub fn add_compliance_rule(&mut self, rule: ComplianceRule) {
+        self.compliance_engine.add_compliance_rule(rule);
+    }
+
+    /// Register a regulatory framework that missions can declare in their policies
+    pub fn add_regulatory_framework(&mut self, framework: RegulatoryFramework) {
+        self.compliance_engine.add_regulatory_framework(framework);
+    }
+
+    /// Evaluate the entries recorded against `mission` under the compliance frameworks
+    /// its policies declare, raising the alerts the triggered rules' actions produce
+    pub fn evaluate_mission_compliance(&mut self, mission: &MissionPayload) -> Result<MissionComplianceReport, AuditError> {
+        let entries = self.audit_store.iter().filter(|entry| {
+            matches!(&entry.target, Some(AuditTarget::Mission { mission_id, .. }) if *mission_id == mission.header.id)
+        });
+        let mut alerts = Vec::new();
+        let report = self.compliance_engine.evaluate_frameworks(&mission.policies.compliance_frameworks, entries, &mut alerts)?;
+        for alert in alerts {
+            self.raise_alert(alert)?;
+        }
+        Ok(report)
+    }
+
+    /// Waive violations coded `rule_code` for events in `scope` until `expires_at`, recording
+    /// the grant. Returns the exemption ID.
+    pub fn grant_compliance_exemption(&mut self, rule_code: &str, scope: ExemptionScope, justification: &str, approver: &str, expires_at: SystemTime) -> Result<String, AuditError> {
+        let exemption_id = self.compliance_engine.grant_exemption(rule_code, scope, justification, approver, expires_at);
+        if let Some(exemption) = self.compliance_engine.exemption(&exemption_id).cloned() {
+            self.journal_operation(JournalOperation::ExemptionGranted { exemption: exemption.clone() })?;
+            self.record_event(Self::exemption_entry("compliance_exemption_granted", &exemption, exemption.granted_at))?;
+        }
+        Ok(exemption_id)
+    }
+
+    /// Compliance exemptions still in force at `now`
+    pub fn active_compliance_exemptions(&self, now: SystemTime) -> Vec<&ComplianceExemption> {
+        self.compliance_engine.active_exemptions(now)
+    }
+
+    /// Drop exemptions that have lapsed by `now`, recording each expiry at its expiry time.
+    /// Returns the expired exemption IDs.
+    pub fn expire_compliance_exemptions(&mut self, now: SystemTime) -> Result<Vec<String>, AuditError> {
+        let expired = self.compliance_engine.expire_exemptions(now);
+        if expired.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.journal_operation(JournalOperation::ExemptionsExpired { now })?;
+
+        // Lifecycle records go straight to the store: they are not subject to compliance
+        // checks or sampling, and must not trigger another expiry pass
+        for exemption in &expired {
+            let mut entry = Self::exemption_entry("compliance_exemption_expired", exemption, exemption.expires_at);
+            entry.entry_id = self.next_entry_id();
+            let existing_alerts = self.alerts.len();
+            self.store_entry(entry, existing_alerts)?;
+        }
+        Ok(expired.into_iter().map(|exemption| exemption.exemption_id).collect())
+    }
+
+    fn exemption_entry(operation_name: &str, exemption: &ComplianceExemption, timestamp: SystemTime) -> AuditEntry {
+        let seconds = |time: SystemTime| time.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_secs();
+        let parameters = HashMap::from([
+            ("exemption_id".to_string(), serde_json::json!(exemption.exemption_id)),
+            ("rule_code".to_string(), serde_json::json!(exemption.rule_code)),
+            ("justification".to_string(), serde_json::json!(exemption.justification)),
+            ("approver".to_string(), serde_json::json!(exemption.approver)),
+            ("expires_at".to_string(), serde_json::json!(seconds(exemption.expires_at))),
+        ]);
+        let mut entry = create_audit_entry(
+            AuditEventType::ComplianceAudit,
+            AuditSeverity::Medium,
+            AuditActor::System {
+                component: "compliance_engine".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                subsystem: "exemptions".to_string(),
+            },
+            AuditOperation {
+                operation_type: "compliance".to_string(),
+                operation_name: operation_name.to_string(),
+                parameters,
+                execution_context: OperationContext::default(),
+                expected_duration: None,
+                resource_consumption: ResourceConsumption::default(),
+            },
+            OperationResult {
+                success: true,
+                error_code: None,
+                error_message: None,
+                duration_ms: 0,
+                performance_metrics: PerformanceMetrics::default(),
+                side_effects: vec![],
+            },
+            AuditContext::default(),
+        );
+        entry.timestamp = timestamp;
+        entry
+    }
+
+    /// Apply the failure stance to a compliance check error
+    fn handle_compliance_failure(&mut self, entry: &mut AuditEntry, error: String) -> Result<(), AuditError> {
+        if self.compliance_failure_stance == ComplianceFailureStance::FailClosed {
+            return Err(AuditError::ComplianceCheckFailed(error));
+        }
+
+        entry.compliance_flags.push(ComplianceFlag::Warning {
+            message: format!("Compliance check failed, event recorded unchecked: {}", error),
+        });
+        self.alerts.push(SecurityAlert {
+            alert_id: format!("alert_compliance_{}_{}", entry.entry_id, SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()),
+            timestamp: SystemTime::now(),
+            severity: AuditSeverity::High,
+            alert_type: AlertType::ConfigurationError,
+            title: "Compliance Check Failed".to_string(),
+            description: format!("Compliance check for entry {} failed: {}", entry.entry_id, error),
+            affected_systems: vec!["audit_system".to_string(), "compliance_engine".to_string()],
+            recommended_actions: vec!["Fix the failing compliance rule".to_string(), "Re-review the unchecked entry".to_string()],
+            evidence: vec![],
+            status: AlertStatus::Active,
+            source_entry_id: Some(entry.entry_id.clone()),
+        });
+        Ok(())
+    }
+
+    /// Cap the number of held alerts; see `enforce_alert_cap` for the eviction order
+    pub fn set_max_alerts(&mut self, max_alerts: usize) -> Result<(), AuditError> {
+        self.max_alerts = max_alerts;
+        self.enforce_alert_cap()
+    }
+
+    /// Evict alerts beyond the cap: resolved and false-positive alerts first (oldest first), then
+    /// the oldest lowest-severity active ones. Critical alerts that are still open are never
+    /// evicted, so the cap can be exceeded when only those remain.
+    ///
+    /// The evictions and the suppression meta-alert are journaled, so a replay restores them as
+    /// they were rather than re-deriving them.
+    fn enforce_alert_cap(&mut self) -> Result<(), AuditError> {
+        let alert_ids = self.alerts_over_cap();
+        if alert_ids.is_empty() {
+            return Ok(());
+        }
+
+        let suppression = self.suppression_alert(&alert_ids);
+        self.journal_operation(JournalOperation::AlertsEvicted { alert_ids: alert_ids.clone(), suppression: suppression.clone() })?;
+        let raised = !self.alerts.iter().any(|alert| alert.alert_id == suppression.alert_id);
+        if let (true, Some(router)) = (raised, self.alert_router.as_mut()) {
+            router.route(&suppression);
+        }
+        self.apply_alert_eviction(&alert_ids, suppression);
+        Ok(())
+    }
+
+    /// IDs of the alerts beyond the cap, in eviction order
+    fn alerts_over_cap(&self) -> Vec<String> {
+        let is_closed = |alert: &SecurityAlert| matches!(alert.status, AlertStatus::Resolved | AlertStatus::FalsePositive);
+        let excess = self.alerts.len().saturating_sub(self.max_alerts);
+        let mut evicted: Vec<&SecurityAlert> = self.alerts.iter().filter(|alert| is_closed(alert)).take(excess).collect();
+
+        let suppression_alert_id = self.suppression_alert_id.as_deref();
+        let mut candidates: Vec<&SecurityAlert> = self.alerts.iter()
+            .filter(|alert| !is_closed(alert)
+                && alert.severity != AuditSeverity::Critical
+                && Some(alert.alert_id.as_str()) != suppression_alert_id)
+            .collect();
+        candidates.sort_by(|a, b| {
+            a.severity.partial_cmp(&b.severity)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.timestamp.cmp(&b.timestamp))
+        });
+        let remaining = excess - evicted.len();
+        evicted.extend(candidates.into_iter().take(remaining));
+        evicted.into_iter().map(|alert| alert.alert_id.clone()).collect()
+    }
+
+    /// Remove the `evicted` alerts and hold `suppression`, replacing its earlier version if held
+    fn apply_alert_eviction(&mut self, evicted: &[String], suppression: SecurityAlert) {
+        let evicted: HashSet<&str> = evicted.iter().map(String::as_str).collect();
+        self.alerts.retain(|alert| !evicted.contains(alert.alert_id.as_str()));
+        self.suppression_alert_id = Some(suppression.alert_id.clone());
+        match self.alerts.iter_mut().find(|alert| alert.alert_id == suppression.alert_id) {
+            Some(held) => *held = suppression,
+            None => self.alerts.push(suppression),
+        }
+    }
+
+    /// The single meta-alert noting that alerts are being evicted, updated if it is still held
+    fn suppression_alert(&self, evicted: &[String]) -> SecurityAlert {
+        let existing = self.suppression_alert_id.as_ref()
+            .filter(|id| !evicted.contains(id))
+            .and_then(|id| self.alerts.iter().find(|alert| &alert.alert_id == id));
+        if let Some(alert) = existing {
+            let mut alert = alert.clone();
+            alert.description = format!("{}; {} more evicted", alert.description, evicted.len());
+            alert.timestamp = SystemTime::now();
+            return alert;
+        }
+
+        SecurityAlert {
+            alert_id: format!("alert_suppression_{}", SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()),
+            timestamp: SystemTime::now(),
+            severity: AuditSeverity::High,
+            alert_type: AlertType::PerformanceAnomaly,
+            title: "Alert Suppression Active".to_string(),
+            description: format!("Alert cap of {} reached; {} alerts evicted", self.max_alerts, evicted.len()),
+            affected_systems: vec!["audit_system".to_string()],
+            recommended_actions: vec!["Review the compliance rule raising alerts".to_string(), "Resolve or raise the alert cap".to_string()],
+            evidence: vec![],
+            status: AlertStatus::Active,
+            source_entry_id: None,
+        }
+    }
+
+    /// Raise an alert detected outside the audit pipeline, routing it like any other
+    pub fn raise_alert(&mut self, alert: SecurityAlert) -> Result<(), AuditError> {
+        self.journal_operation(JournalOperation::AlertRaised { alert: alert.clone() })?;
+        if let Some(router) = self.alert_router.as_mut() {
+            router.route(&alert);
+        }
+        self.alerts.push(alert);
+        self.enforce_alert_cap()
+    }
+
+    /// Set the store fill ratio (0.0 to 1.0) at which a capacity alert is raised
+    pub fn set_capacity_warning_threshold(&mut self, ratio: f32) {
+        self.capacity_warning_ratio = ratio.clamp(0.0, 1.0);
+    }
+
+    /// Raise a single alert when the store reaches its high-water mark; re-arms once it drops below
+    fn check_capacity_high_water(&mut self, store_len: usize) {
+        let threshold = (self.max_entries as f32 * self.capacity_warning_ratio).ceil() as usize;
+        if store_len < threshold {
+            self.capacity_warning_latched = false;
+            return;
+        }
+        if self.capacity_warning_latched {
+            return;
+        }
+        self.capacity_warning_latched = true;
+
+        self.alerts.push(SecurityAlert {
+            alert_id: format!("alert_capacity_{}", SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()),
+            timestamp: SystemTime::now(),
+            severity: AuditSeverity::High,
+            alert_type: AlertType::ConfigurationError,
+            title: "Audit Store Near Capacity".to_string(),
+            description: format!("Audit store holds {} of {} entries; retention will start dropping history",
+                store_len, self.max_entries),
+            affected_systems: vec!["audit_system".to_string()],
+            recommended_actions: vec!["Increase audit store capacity".to_string(), "Archive older audit entries".to_string()],
+            evidence: vec![],
+            status: AlertStatus::Active,
+            source_entry_id: None,
+        });
+    }
+
+    /// Also persist every recorded entry to an encrypted at-rest store
+    pub fn enable_encrypted_storage(&mut self, store: EncryptedAuditStore) {
+        self.encrypted_store = Some(store);
+    }
+
+    /// Get the encrypted at-rest store
+    pub fn encrypted_store(&mut self) -> Option<&mut EncryptedAuditStore> {
+        self.encrypted_store.as_mut()
+    }
+
+    /// Cap the serialized size of a single entry; larger entries are truncated, not rejected
+    pub fn set_max_entry_bytes(&mut self, max_bytes: usize) {
+        self.max_entry_bytes = Some(max_bytes);
+    }
+
+    /// Trim evidence, then the largest operation parameters, until the entry fits `max_bytes`.
+    /// Returns true if anything was removed.
+    fn truncate_oversized_entry(entry: &mut AuditEntry, max_bytes: usize) -> bool {
+        let serialized_len = |entry: &AuditEntry| serde_json::to_vec(entry).map(|bytes| bytes.len()).unwrap_or(usize::MAX);
+        if serialized_len(entry) <= max_bytes {
+            return false;
+        }
+
+        entry.oversized = true;
+
+        // Each pass re-adds the marker, so the size checks always include it
+        let original_evidence = entry.evidence.len();
+        let mut kept_evidence = original_evidence;
+        while kept_evidence > 0 && serialized_len(entry) > max_bytes {
+            kept_evidence /= 2;
+            entry.evidence.truncate(kept_evidence);
+            entry.evidence.push(EvidenceArtifact::LogEntry {
+                source: "audit_system".to_string(),
+                level: "warning".to_string(),
+                message: format!("[TRUNCATED] {} of {} evidence artifacts removed to fit {} byte limit",
+                    original_evidence - kept_evidence, original_evidence, max_bytes),
+            });
+        }
+
+        let mut removed_parameters = 0;
+        while serialized_len(entry) > max_bytes {
+            let largest = entry.operation.parameters.iter()
+                .filter(|(key, _)| key.as_str() != "_truncated")
+                .max_by_key(|(_, value)| value.to_string().len())
+                .map(|(key, _)| key.clone());
+            match largest {
+                Some(key) => {
+                    entry.operation.parameters.remove(&key);
+                    removed_parameters += 1;
+                    entry.operation.parameters.insert(
+                        "_truncated".to_string(),
+                        serde_json::Value::String(format!("[TRUNCATED] {} parameters removed", removed_parameters)),
+                    );
+                }
+                None => break,
+            }
+        }
+
+        // The markers are best effort; `oversized` still records the truncation
+        if serialized_len(entry) > max_bytes {
+            entry.operation.parameters.remove("_truncated");
+            entry.evidence.truncate(kept_evidence);
+        }
+        true
+    }
+
+    /// Start journaling every store mutation
+    pub fn enable_journal(&mut self, journal: AuditJournal) {
+        self.journal = Some(journal);
+    }
+
+    /// Get the active journal
+    pub fn journal(&self) -> Option<&AuditJournal> {
+        self.journal.as_ref()
+    }
+
+    /// Rebuild an audit store by applying a journal's operations in order
+    ///
+    /// Alerts are restored from the journal rather than re-derived, so alert IDs match the original.
+    pub fn replay(journal: &AuditJournal) -> Result<AuditSystem, AuditError> {
+        let max_entries = journal.max_entries()
+            .ok_or_else(|| AuditError::JournalError("Journal has no initialization record".to_string()))?;
+        let mut system = AuditSystem::new(max_entries);
+
+        for record in journal.records() {
+            match &record.operation {
+                JournalOperation::Initialized { .. } => {}
+                JournalOperation::RecordEvent { entry, alerts } => {
+                    system.alerts.extend(alerts.iter().cloned());
+                    system.latest_timestamp = system.latest_timestamp.max(Some(entry.timestamp));
+                    if let Some(key) = &entry.idempotency_key {
+                        system.idempotency_keys.insert(key.clone(), (entry.entry_id.clone(), entry.timestamp));
+                    }
+                    system.audit_store.push(entry.clone());
+                }
+                JournalOperation::AlertRaised { alert } => {
+                    system.alerts.push(alert.clone());
+                }
+                JournalOperation::AlertsEvicted { alert_ids, suppression } => {
+                    system.apply_alert_eviction(alert_ids, suppression.clone());
+                }
+                JournalOperation::UpdateAlertStatus { alert_id, status } => {
+                    system.update_alert_status(alert_id, status.clone())?;
+                }
+                JournalOperation::RetentionEnforced { now } => {
+                    system.enforce_retention_policy(*now);
+                }
+                JournalOperation::LegalHoldPlaced { hold } => {
+                    system.legal_hold_sequence += 1;
+                    system.legal_holds.push(hold.clone());
+                }
+                JournalOperation::LegalHoldReleased { hold_id } => {
+                    system.legal_holds.retain(|hold| hold.hold_id != *hold_id);
+                }
+                JournalOperation::ExemptionGranted { exemption } => {
+                    system.compliance_engine.restore_exemption(exemption.clone());
+                }
+                JournalOperation::ExemptionsExpired { now } => {
+                    system.compliance_engine.expire_exemptions(*now);
+                }
+            }
+        }
+
+        Ok(system)
+    }
+
+    /// Summarize the current store contents
+    pub fn summary(&self) -> AuditSummary {
+        AuditSummary {
+            total_entries: self.audit_store.len(),
+            high_severity_entries: self.audit_store.iter()
+                .filter(|entry| entry.severity >= AuditSeverity::High)
+                .count(),
+            total_alerts: self.alerts.len(),
+            active_alerts: self.get_active_alerts().len(),
+            oldest_entry: self.audit_store.iter().map(|entry| entry.timestamp).min(),
+            newest_entry: self.audit_store.iter().map(|entry| entry.timestamp).max(),
+            held_entries: self.audit_store.iter()
+                .filter(|entry| self.is_held(&entry.entry_id))
+                .count(),
+            active_legal_holds: self.legal_holds.len(),
+        }
+    }
+
+    /// Preserve every entry currently matching `query` until the hold is released, whatever
+    /// its age or the pressure on capacity. Returns the hold ID.
+    pub fn place_legal_hold(&mut self, query: AuditQuery) -> Result<String, AuditError> {
+        let hold = LegalHold {
+            hold_id: format!("hold_{}", self.legal_hold_sequence + 1),
+            placed_at: SystemTime::now(),
+            entry_ids: self.query_iter(query).map(|entry| entry.entry_id.clone()).collect(),
+        };
+        let hold_id = hold.hold_id.clone();
+        // Journal first: a failed append must not consume a hold ID replay would reuse
+        self.journal_operation(JournalOperation::LegalHoldPlaced { hold: hold.clone() })?;
+        self.legal_hold_sequence += 1;
+        self.legal_holds.push(hold);
+        Ok(hold_id)
+    }
+
+    /// Lift a legal hold; its entries fall back to normal retention unless another hold covers them
+    pub fn release_legal_hold(&mut self, hold_id: &str) -> Result<(), AuditError> {
+        if !self.legal_holds.iter().any(|hold| hold.hold_id == hold_id) {
+            return Err(AuditError::LegalHoldNotFound(hold_id.to_string()));
+        }
+        self.journal_operation(JournalOperation::LegalHoldReleased { hold_id: hold_id.to_string() })?;
+        self.legal_holds.retain(|hold| hold.hold_id != hold_id);
+        Ok(())
+    }
+
+    /// Legal holds in force
+    pub fn legal_holds(&self) -> &[LegalHold] {
+        &self.legal_holds
+    }
+
+    /// Whether any legal hold covers the entry
+    pub fn is_held(&self, entry_id: &str) -> bool {
+        self.legal_holds.iter().any(|hold| hold.entry_ids.contains(entry_id))
+    }
+
+    /// Append to the journal if one is enabled
+    fn journal_operation(&mut self, operation: JournalOperation) -> Result<(), AuditError> {
+        match self.journal.as_mut() {
+            Some(journal) => journal.append(operation),
+            None => Ok(()),
         }
     }
 
+    /// Count threat vectors and mitigations across the store, legacy strings included
+    pub fn threat_summary(&self) -> ThreatSummary {
+        ThreatSummary::from_entries(&self.audit_store)
+    }
+
+    /// Set the sampling policy for informational events
+    pub fn set_sampling_policy(&mut self, policy: SamplingPolicy) {
+        self.sampling_policy = policy;
+    }
+
+    /// Number of informational events dropped by sampling
+    pub fn sampled_out_count(&self) -> u64 {
+        self.sampled_out
+    }
+
+    /// Decide whether an entry is kept under the sampling policy
+    fn should_sample_in(&mut self, entry: &AuditEntry) -> bool {
+        let one_in = match self.sampling_policy {
+            SamplingPolicy::OneIn(n) if n > 1 => n as u64,
+            _ => return true,
+        };
+        if entry.severity != AuditSeverity::Informational || UNSAMPLED_EVENT_TYPES.contains(&entry.event_type) {
+            return true;
+        }
+
+        let keep = self.informational_seen % one_in == 0;
+        self.informational_seen += 1;
+        if !keep {
+            self.sampled_out += 1;
+        }
+        keep
+    }
+
+    /// Register the routing table used to deliver newly created alerts
+    pub fn set_alert_router(&mut self, router: AlertRouter) {
+        self.alert_router = Some(router);
+    }
+
+    /// Get the registered alert router
+    pub fn alert_router(&mut self) -> Option<&mut AlertRouter> {
+        self.alert_router.as_mut()
+    }
+
     /// Record audit event
+    ///
+    /// Informational events dropped by the sampling policy still receive an ID but are not stored.
     pub fn record_event(&mut self, entry: AuditEntry) -> Result<String, AuditError> {
+        // A retry of an event already stored returns the original ID
+        if let Some((existing_id, _)) = entry.idempotency_key.as_ref().and_then(|key| self.idempotency_keys.get(key)) {
+            return Ok(existing_id.clone());
+        }
+
         // Generate unique entry ID if not provided
         let entry_id = if entry.entry_id.is_empty() {
-            format!("audit_{}", SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_millis())
+            self.next_entry_id()
         } else {
             entry.entry_id.clone()
         };
@@ -341,32 +1043,126 @@ impl AuditSystem {
         let mut entry = entry;
         entry.entry_id = entry_id.clone();
 
+        if !self.should_sample_in(&entry) {
+            return Ok(entry_id);
+        }
+
         // Add timestamp if not set
         if entry.timestamp == SystemTime::UNIX_EPOCH {
             entry.timestamp = SystemTime::now();
         }
 
+        // Lapsed exemptions are recorded ahead of the entry they stop covering. Expiry follows
+        // the station clock: an entry's own timestamp is caller-supplied and may be skewed.
+        self.expire_compliance_exemptions(SystemTime::now())?;
+
         // Check compliance and generate alerts first (before moving entry)
-        self.compliance_engine.check_compliance(&entry, &mut self.alerts)?;
+        let existing_alerts = self.alerts.len();
+        self.handle_clock_anomaly(&mut entry);
+        for flag in self.compliance_engine.evaluate_entry(&entry, entry.timestamp) {
+            if flag != ComplianceFlag::Compliant && !entry.compliance_flags.contains(&flag) {
+                entry.compliance_flags.push(flag);
+            }
+        }
+        if let Err(error) = self.compliance_engine.check_compliance(&entry, &mut self.alerts) {
+            if let Err(rejected) = self.handle_compliance_failure(&mut entry, error.to_string()) {
+                // A rejected event leaves no alerts behind
+                self.alerts.truncate(existing_alerts);
+                return Err(rejected);
+            }
+        }
+        self.check_capacity_high_water(self.audit_store.len() + 1);
+
+        // Deliver newly created alerts to their channels only once they are on record
+        let new_alerts = self.alerts[existing_alerts..].to_vec();
+        self.store_entry(entry, existing_alerts)?;
+        if let Some(router) = self.alert_router.as_mut() {
+            for alert in &new_alerts {
+                router.route(alert);
+            }
+        }
+        Ok(entry_id)
+    }
+
+    /// Persist a fully evaluated entry along with the alerts raised since `existing_alerts`,
+    /// then apply the size limits
+    fn store_entry(&mut self, mut entry: AuditEntry, existing_alerts: usize) -> Result<(), AuditError> {
+        // Truncate once the entry is complete, before anything hashes or stores it
+        if let Some(max_bytes) = self.max_entry_bytes {
+            Self::truncate_oversized_entry(&mut entry, max_bytes);
+        }
+
+        // Journal before anything is stored, so a failed append leaves no trace of the entry
+        if self.journal.is_some() {
+            let alerts = self.alerts[existing_alerts..].to_vec();
+            let operation = JournalOperation::RecordEvent { entry: entry.clone(), alerts };
+            if let Err(error) = self.journal_operation(operation) {
+                self.alerts.truncate(existing_alerts);
+                return Err(error);
+            }
+        }
 
         // Store the entry
-        self.audit_store.push(entry.clone());
+        if let Some(store) = self.encrypted_store.as_mut() {
+            store.store(&entry)?;
+        }
+        self.latest_timestamp = self.latest_timestamp.max(Some(entry.timestamp));
+        if let Some(key) = &entry.idempotency_key {
+            self.idempotency_keys.insert(key.clone(), (entry.entry_id.clone(), entry.timestamp));
+        }
+        if let Some(cache) = self.query_cache.as_mut() {
+            cache.invalidate_where(|query, _| Self::matches_query(&entry, query));
+        }
+        self.audit_store.push(entry);
+        self.enforce_alert_cap()?;
 
         // Maintain size limits
         if self.audit_store.len() > self.max_entries {
             // Remove oldest entries, but keep prioritized events longer
-            self.enforce_retention_policy();
+            let now = SystemTime::now();
+            self.journal_operation(JournalOperation::RetentionEnforced { now })?;
+            self.enforce_retention_policy(now);
         }
 
-        Ok(entry_id)
+        Ok(())
     }
 
     /// Query audit trail with filters
     pub fn query_audit(&self, query: AuditQuery) -> Vec<AuditEntry> {
+        self.query_iter(query).cloned().collect()
+    }
+
+    /// Serve repeated identical queries from a cache, invalidated by every store mutation
+    pub fn enable_query_cache(&mut self, config: QueryCacheConfig) {
+        self.query_cache = Some(QueryCache::new(config));
+    }
+
+    /// Cache hit and miss counts, if the query cache is enabled
+    pub fn query_cache_stats(&self) -> Option<QueryCacheStats> {
+        self.query_cache.as_ref().map(QueryCache::stats)
+    }
+
+    /// Query audit trail, answering from the query cache when it holds a fresh result
+    ///
+    /// Identical to `query_audit` when the cache is not enabled.
+    pub fn query_audit_cached(&mut self, query: AuditQuery) -> Vec<AuditEntry> {
+        let now = SystemTime::now();
+        let key = QueryCache::normalize(&query);
+        if let Some(results) = self.query_cache.as_mut().and_then(|cache| cache.get(&key, now)) {
+            return results;
+        }
+
+        let results = self.query_audit(query.clone());
+        if let Some(cache) = self.query_cache.as_mut() {
+            cache.insert(key, query, results.clone(), now);
+        }
+        results
+    }
+
+    /// Lazily iterate over entries matching a query without cloning them
+    pub fn query_iter<'a>(&'a self, query: AuditQuery) -> impl Iterator<Item = &'a AuditEntry> + 'a {
         self.audit_store.iter()
-            .filter(|entry| self.matches_query(entry, &query))
-            .cloned()
-            .collect()
+            .filter(move |entry| Self::matches_query(entry, &query))
     }
 
     /// Generate audit report
@@ -374,6 +1170,104 @@ impl AuditSystem {
         self.report_generator.generate_report(request, &self.audit_store)
     }
 
+    /// Generate an audit report signed by `signer`, returning its metadata and content
+    pub fn generate_signed_report(&mut self, request: ReportRequest, signer: &CryptoEngine) -> Result<(GeneratedReport, String), AuditError> {
+        self.report_generator.render_report(request, &self.audit_store, Some(signer))
+    }
+
+    /// Build and sign a completion certificate for every event in a correlation.
+    ///
+    /// Events whose correlation ID is `correlation_id` or a phase of it (`{correlation_id}_PHASE`) are included.
+    pub fn generate_completion_certificate(&self, correlation_id: &str, signer: &CryptoEngine) -> Result<CompletionCertificate, AuditError> {
+        let entries = self.correlated_entries(correlation_id);
+        if entries.is_empty() {
+            return Err(AuditError::CertificateError(format!("No audit events for correlation {}", correlation_id)));
+        }
+
+        let mut compliance_flags: Vec<ComplianceFlag> = Vec::new();
+        let mut resources = ResourceConsumption::default();
+        for entry in &entries {
+            for flag in &entry.compliance_flags {
+                if !compliance_flags.contains(flag) {
+                    compliance_flags.push(flag.clone());
+                }
+            }
+            let consumed = &entry.operation.resource_consumption;
+            resources.cpu_seconds += consumed.cpu_seconds;
+            resources.memory_mb += consumed.memory_mb;
+            resources.network_bytes += consumed.network_bytes;
+            resources.storage_bytes += consumed.storage_bytes;
+            resources.energy_consumption_wh += consumed.energy_consumption_wh;
+        }
+
+        let entry_ids: Vec<String> = entries.iter().map(|entry| entry.entry_id.clone()).collect();
+        let body = CertificateBody {
+            correlation_id: correlation_id.to_string(),
+            issued_at: SystemTime::now(),
+            started_at: entries[0].timestamp,
+            finished_at: entries[entries.len() - 1].timestamp,
+            all_operations_succeeded: entries.iter().all(|entry| entry.result.success),
+            failed_operations: entries.iter()
+                .filter(|entry| !entry.result.success)
+                .map(|entry| entry.operation.operation_name.clone())
+                .collect(),
+            highest_severity: entries.iter()
+                .map(|entry| entry.severity.clone())
+                .fold(AuditSeverity::Informational, |highest, severity| if severity > highest { severity } else { highest }),
+            compliance_flags,
+            resources,
+            alerts: self.alerts.iter()
+                .filter(|alert| alert.source_entry_id.as_ref().map_or(false, |source| entry_ids.contains(source)))
+                .cloned()
+                .collect(),
+            entry_ids,
+        };
+
+        CompletionCertificate::sign(body, signer)
+    }
+
+    /// Store an evidence blob, hash it and link it to a recorded entry
+    pub fn attach_evidence(&mut self, entry_id: &str, artifact_bytes: &[u8], content_type: &str) -> Result<EvidenceArtifact, AuditError> {
+        let entry = self.audit_store.iter_mut()
+            .find(|entry| entry.entry_id == entry_id)
+            .ok_or_else(|| AuditError::InvalidEntry(format!("Unknown entry {}", entry_id)))?;
+
+        let hash = hex::encode(CryptoEngine::generate_device_fingerprint(artifact_bytes));
+        let artifact = EvidenceArtifact::DataBlob {
+            content_type: content_type.to_string(),
+            size_bytes: artifact_bytes.len(),
+            hash: hash.clone(),
+        };
+        entry.evidence.push(artifact.clone());
+        self.evidence_blobs.push(StoredEvidence {
+            entry_id: entry_id.to_string(),
+            hash,
+            bytes: artifact_bytes.to_vec(),
+        });
+        if let Some(cache) = self.query_cache.as_mut() {
+            cache.invalidate_where(|_, results| results.iter().any(|cached| cached.entry_id == entry_id));
+        }
+
+        Ok(artifact)
+    }
+
+    /// Re-hash every blob attached to an entry; returns how many were verified
+    pub fn verify_evidence(&self, entry_id: &str) -> Result<usize, AuditError> {
+        let mut verified = 0;
+        for blob in self.evidence_blobs.iter().filter(|blob| blob.entry_id == entry_id) {
+            let actual = hex::encode(CryptoEngine::generate_device_fingerprint(&blob.bytes));
+            if actual != blob.hash {
+                return Err(AuditError::EvidenceIntegrityFailure {
+                    entry_id: entry_id.to_string(),
+                    expected: blob.hash.clone(),
+                    actual,
+                });
+            }
+            verified += 1;
+        }
+        Ok(verified)
+    }
+
     /// Get active security alerts
     pub fn get_active_alerts(&self) -> Vec<&SecurityAlert> {
         self.alerts.iter()
@@ -384,36 +1278,61 @@ impl AuditSystem {
     /// Update alert status
     pub fn update_alert_status(&mut self, alert_id: &str, new_status: AlertStatus) -> Result<(), AuditError> {
         if let Some(alert) = self.alerts.iter_mut().find(|a| a.alert_id == alert_id) {
-            alert.status = new_status;
-            Ok(())
+            alert.status = new_status.clone();
+            self.journal_operation(JournalOperation::UpdateAlertStatus {
+                alert_id: alert_id.to_string(),
+                status: new_status,
+            })
         } else {
             Err(AuditError::AlertNotFound)
         }
     }
 
-    /// Enforce retention policy
-    fn enforce_retention_policy(&mut self) {
+    /// Enforce retention policy relative to `now`
+    fn enforce_retention_policy(&mut self, now: SystemTime) {
         let max_age = Duration::from_secs(self.retention_policy.max_age_days as u64 * 86400);
-        let cutoff_time = SystemTime::now() - max_age;
+        let cutoff_time = now - max_age;
+
+        let legal_holds = &self.legal_holds;
+        let is_held = |entry: &AuditEntry| legal_holds.iter().any(|hold| hold.entry_ids.contains(&entry.entry_id));
 
-        // Remove old entries, but preserve high-severity events longer
+        // Remove old entries, but preserve high-severity events longer and held entries indefinitely
         self.audit_store.retain(|entry| {
             let is_recent = entry.timestamp > cutoff_time;
             let is_high_severity = matches!(entry.severity, AuditSeverity::High | AuditSeverity::Critical);
             let is_prioritized = self.retention_policy.prioritized_events.contains(&entry.event_type);
 
-            is_recent || (is_high_severity && is_prioritized)
+            is_recent || (is_high_severity && is_prioritized) || is_held(entry)
         });
 
-        // If still over limit, remove oldest entries regardless
+        // If still over limit, remove oldest entries regardless of priority, never held ones
         if self.audit_store.len() > self.max_entries {
             self.audit_store.sort_by_key(|e| e.timestamp);
-            self.audit_store.truncate(self.max_entries);
+            let mut excess = self.audit_store.len() - self.max_entries;
+            self.audit_store.retain(|entry| {
+                if excess == 0 || is_held(entry) {
+                    return true;
+                }
+                excess -= 1;
+                false
+            });
+        }
+
+        // Keys outlive capacity pruning so late retries still deduplicate, but not the retention age
+        self.idempotency_keys.retain(|_, (_, timestamp)| *timestamp > cutoff_time);
+
+        // Drop evidence blobs whose entries are gone
+        let entry_ids: HashSet<&str> = self.audit_store.iter().map(|entry| entry.entry_id.as_str()).collect();
+        self.evidence_blobs.retain(|blob| entry_ids.contains(blob.entry_id.as_str()));
+
+        // Removal and reordering can change any cached result
+        if let Some(cache) = self.query_cache.as_mut() {
+            cache.clear();
         }
     }
 
     /// Check if entry matches query filter
-    fn matches_query(&self, entry: &AuditEntry, query: &AuditQuery) -> bool {
+    pub(super) fn matches_query(entry: &AuditEntry, query: &AuditQuery) -> bool {
         // Time range filter
         if let Some(start_time) = query.start_time {
             if entry.timestamp < start_time {
@@ -440,7 +1359,7 @@ impl AuditSystem {
 
         // Actor filter
         if let Some(actor_filter) = &query.actor_filter {
-            if !self.actor_matches(entry, actor_filter) {
+            if !Self::actor_matches(entry, actor_filter) {
                 return false;
             }
         }
@@ -455,8 +1374,53 @@ impl AuditSystem {
         true
     }
 
+    /// Entries sharing a correlation ID, including per-phase IDs (`<id>_ASSEMBLY`), oldest first
+    fn correlated_entries(&self, correlation_id: &str) -> Vec<&AuditEntry> {
+        let phase_prefix = format!("{}_", correlation_id);
+        let mut entries: Vec<&AuditEntry> = self.audit_store.iter()
+            .filter(|entry| {
+                let id = &entry.context.correlation_id;
+                id == correlation_id || id.starts_with(&phase_prefix)
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+
+    /// Human-readable timeline of a correlated workflow, one line per step in order, e.g.
+    /// `T+2m: formation assembly by operator FORMATION_LEAD_001 succeeded`
+    pub fn narrate(&self, correlation_id: &str) -> String {
+        let entries = self.correlated_entries(correlation_id);
+        let Some(first) = entries.first() else {
+            return format!("No audit events for correlation {}", correlation_id);
+        };
+
+        let mut narrative = format!("Timeline for {}:", correlation_id);
+        for entry in &entries {
+            let offset = entry.timestamp.duration_since(first.timestamp).unwrap_or_default();
+            let actor = match &entry.actor {
+                AuditActor::HumanOperator { operator_id, .. } => format!("operator {}", operator_id),
+                AuditActor::Drone { drone_id, .. } => format!("drone {}", drone_id),
+                AuditActor::Station { station_id, .. } => format!("station {}", station_id),
+                AuditActor::System { component, .. } => component.replace('_', " "),
+                AuditActor::External { source_ip, .. } => format!("external source {}", source_ip.as_deref().unwrap_or("unknown")),
+            };
+            let outcome = if entry.result.success {
+                "succeeded".to_string()
+            } else {
+                match &entry.result.error_message {
+                    Some(message) => format!("failed ({})", message),
+                    None => "failed".to_string(),
+                }
+            };
+            narrative.push_str(&format!("\n{}: {} by {} {}",
+                format_elapsed(offset), entry.operation.operation_name.replace('_', " "), actor, outcome));
+        }
+        narrative
+    }
+
     /// Check if actor matches filter
-    fn actor_matches(&self, entry: &AuditEntry, filter: &ActorFilter) -> bool {
+    fn actor_matches(entry: &AuditEntry, filter: &ActorFilter) -> bool {
         match (&entry.actor, filter) {
             (AuditActor::HumanOperator { operator_id, .. }, ActorFilter::OperatorId(id)) => operator_id == id,
             (AuditActor::Drone { drone_id, .. }, ActorFilter::DroneId(id)) => drone_id == id,
@@ -510,6 +1474,33 @@ pub enum AuditError {
     AlertNotFound,
     #[error("Query execution failed")]
     QueryError,
+    #[error("Audit journal error: {0}")]
+    JournalError(String),
+    #[error("Audit storage key unavailable")]
+    StorageKeyUnavailable,
+    #[error("Audit storage error: {0}")]
+    StorageError(String),
+    #[error("Completion certificate error: {0}")]
+    CertificateError(String),
+    #[error("Compliance check failed: {0}")]
+    ComplianceCheckFailed(String),
+    #[error(transparent)]
+    Compliance(#[from] super::compliance::AuditError), // Compliance engine error, kept as raised
+    #[error("Evidence for entry {entry_id} failed integrity check: expected {expected}, found {actual}")]
+    EvidenceIntegrityFailure { entry_id: String, expected: String, actual: String },
+    #[error("Legal hold not found: {0}")]
+    LegalHoldNotFound(String),
+}
+
+/// Mission-relative time such as `T+0s`, `T+2m`, `T+2m30s` or `T+1h05m`
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    match (secs / 3600, secs % 3600 / 60, secs % 60) {
+        (0, 0, seconds) => format!("T+{}s", seconds),
+        (0, minutes, 0) => format!("T+{}m", minutes),
+        (0, minutes, seconds) => format!("T+{}m{:02}s", minutes, seconds),
+        (hours, minutes, _) => format!("T+{}h{:02}m", hours, minutes),
+    }
 }
 
 /// Quick audit entry creation helper
@@ -541,6 +1532,9 @@ pub fn create_audit_entry(
             integrity_hash: "placeholder".to_string(),
         },
         evidence: Vec::new(),
+        oversized: false,
+        clock_anomaly: false,
+        idempotency_key: None,
     }
 }
 
@@ -615,9 +1609,13 @@ pub struct GeneratedReport {
     pub generated_at: SystemTime,
     pub parameters_used: HashMap<String, serde_json::Value>,
     pub file_path: String,
-    pub file_hash: String,
+    pub file_hash: String, // Hex SHA-256 of the rendered content
     pub generation_duration_ms: u64,
     pub status: ReportStatus,
+    #[serde(default)]
+    pub signer_public_key: Option<Vec<u8>>,
+    #[serde(default)]
+    pub signature: Option<Vec<u8>>, // Ed25519 over the report ID, template ID and content hash
 }
 
 /// Report generation status
@@ -652,6 +1650,13 @@ impl AuditReportGenerator {
 
     /// Generate audit report
     pub fn generate_report(&mut self, request: ReportRequest, audit_entries: &[AuditEntry]) -> Result<String, AuditError> {
+        self.render_report(request, audit_entries, None).map(|(report, _)| report.report_id)
+    }
+
+    /// Generate a report and return its metadata with the rendered content; when a
+    /// signer is given the content hash is signed so the report can be verified later
+    pub fn render_report(&mut self, request: ReportRequest, audit_entries: &[AuditEntry], signer: Option<&CryptoEngine>) -> Result<(GeneratedReport, String), AuditError> {
+        let started = std::time::Instant::now();
         let template = self.report_templates.get(&request.template_id)
             .ok_or(AuditError::ReportGenerationError("Template not found".to_string()))?;
 
@@ -670,27 +1675,64 @@ impl AuditReportGenerator {
             .collect();
 
         // Generate report based on format
-        let _report_content = match template.format {
+        let report_content = match template.format {
             ReportFormat::JSON => self.generate_json_report(template, &filtered_entries)?,
             ReportFormat::CSV => self.generate_csv_report(template, &filtered_entries)?,
             _ => return Err(AuditError::ReportGenerationError("Unsupported format".to_string())),
         };
 
         // Create report metadata
-        let report = GeneratedReport {
+        let mut report = GeneratedReport {
             report_id: format!("report_{}", SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_millis()),
             template_id: request.template_id.clone(),
             generated_at: SystemTime::now(),
             parameters_used: request.parameters.clone(),
             file_path: format!("/audit_reports/{}_{}.json", request.template_id, SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs()),
-            file_hash: "placeholder_hash".to_string(), // In production, would compute actual hash
-            generation_duration_ms: 1000, // Placeholder
+            file_hash: hex::encode(CryptoEngine::generate_device_fingerprint(report_content.as_bytes())),
+            generation_duration_ms: started.elapsed().as_millis() as u64,
             status: ReportStatus::Success,
+            signer_public_key: None,
+            signature: None,
         };
 
+        if let Some(signer) = signer {
+            let signature = signer.sign_data(&Self::report_signing_bytes(&report))
+                .map_err(|e| AuditError::ReportGenerationError(e.to_string()))?;
+            report.signer_public_key = Some(signer.ed25519_public_key().to_vec());
+            report.signature = Some(signature);
+        }
+
         self.generated_reports.push(report.clone());
 
-        Ok(report.report_id)
+        Ok((report, report_content))
+    }
+
+    /// Check `content` matches the report's hash and the signature over it verifies against
+    /// the embedded signer key. Unsigned reports never verify; callers should also check
+    /// `signer_public_key` is a key they trust.
+    pub fn verify_report(report: &GeneratedReport, content: &str) -> bool {
+        let (Some(public_key), Some(signature)) = (&report.signer_public_key, &report.signature) else {
+            return false;
+        };
+        if hex::encode(CryptoEngine::generate_device_fingerprint(content.as_bytes())) != report.file_hash {
+            return false;
+        }
+        CryptoEngine::verify_log_signature(public_key, &Self::report_signing_bytes(report), signature).is_ok()
+    }
+
+    /// Reports generated so far, oldest first
+    pub fn generated_reports(&self) -> &[GeneratedReport] {
+        &self.generated_reports
+    }
+
+    /// Each field is length-prefixed, so moving bytes between fields changes what is signed
+    fn report_signing_bytes(report: &GeneratedReport) -> Vec<u8> {
+        let mut bytes = b"gibberlink-audit-report-v2".to_vec();
+        for field in [&report.report_id, &report.template_id, &report.file_hash] {
+            bytes.extend_from_slice(&(field.len() as u64).to_be_bytes());
+            bytes.extend_from_slice(field.as_bytes());
+        }
+        bytes
     }
 
     /// Generate JSON audit report
@@ -770,6 +1812,8 @@ impl AuditReportGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::audit::compliance::{ComplianceAction, CompliancePriority};
+    use crate::fixtures::system_audit_entry;
 
     #[tokio::test]
     async fn test_audit_system_creation() {
@@ -892,6 +1936,45 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_signed_report_verifies_and_detects_altered_content() {
+        let mut report_generator = AuditReportGenerator::new();
+        report_generator.add_template(ReportTemplate {
+            template_id: "compliance".to_string(),
+            name: "Compliance Report".to_string(),
+            description: "Signed compliance export".to_string(),
+            sections: vec![],
+            filters: vec![],
+            format: ReportFormat::CSV,
+        });
+        let request = ReportRequest {
+            template_id: "compliance".to_string(),
+            parameters: HashMap::new(),
+            time_range: None,
+            filters: vec![],
+        };
+        let entries = vec![system_audit_entry(AuditEventType::MissionTransfer, AuditSeverity::High)];
+
+        let signer = CryptoEngine::new();
+        let (report, content) = report_generator.render_report(request, &entries, Some(&signer)).unwrap();
+        assert_ne!(report.file_hash, "placeholder_hash");
+        assert!(AuditReportGenerator::verify_report(&report, &content));
+
+        let altered = content.replace("true", "false");
+        assert_ne!(altered, content);
+        assert!(!AuditReportGenerator::verify_report(&report, &altered));
+
+        let mut unsigned = report.clone();
+        unsigned.signature = None;
+        assert!(!AuditReportGenerator::verify_report(&unsigned, &content));
+
+        // Shifting the boundary between the report and template IDs breaks the signature
+        let mut shifted = report.clone();
+        shifted.report_id.push('c');
+        shifted.template_id.remove(0);
+        assert!(!AuditReportGenerator::verify_report(&shifted, &content));
+    }
+
     #[tokio::test]
     async fn test_audit_alerts() {
         let mut audit_system = AuditSystem::new(1000);
@@ -933,4 +2016,568 @@ mod tests {
         let active_alerts = audit_system.get_active_alerts();
         assert!(!active_alerts.is_empty());
     }
+
+    #[test]
+    fn test_failing_compliance_rule_under_both_stances() {
+        let broken_rule = ComplianceRule {
+            rule_id: "operator_recorded".to_string(),
+            name: "Operator Recorded".to_string(),
+            description: "Transfers by the night operator need review".to_string(),
+            trigger_events: vec![AuditEventType::MissionTransfer],
+            conditions: vec!["params.operator_id == 'night_shift'".to_string()],
+            actions: vec![ComplianceAction::FlagForReview { reviewer: "security_team".to_string() }],
+            priority: CompliancePriority::Medium,
+            framework_id: None,
+        };
+
+        // A rule ahead of the broken one raises an alert before the check fails
+        let mut closed = AuditSystem::new(100);
+        closed.add_compliance_rule(ComplianceRule {
+            rule_id: "transfer_review".to_string(),
+            conditions: vec![],
+            ..broken_rule.clone()
+        });
+        closed.add_compliance_rule(broken_rule.clone());
+        let result = closed.record_event(system_audit_entry(AuditEventType::MissionTransfer, AuditSeverity::Medium));
+        assert!(matches!(result, Err(AuditError::ComplianceCheckFailed(_))));
+        assert_eq!(closed.summary().total_entries, 0);
+        assert_eq!(closed.summary().total_alerts, 0);
+
+        let mut open = AuditSystem::new(100);
+        open.add_compliance_rule(broken_rule);
+        open.set_compliance_failure_stance(ComplianceFailureStance::FailOpen);
+        let entry_id = open.record_event(system_audit_entry(AuditEventType::MissionTransfer, AuditSeverity::Medium)).unwrap();
+        let stored = open.audit_store.iter().find(|entry| entry.entry_id == entry_id).unwrap();
+        assert!(stored.compliance_flags.iter().any(|flag| matches!(flag, ComplianceFlag::Warning { .. })));
+        assert!(open.get_active_alerts().iter().any(|alert| matches!(alert.alert_type, AlertType::ConfigurationError)));
+    }
+
+    #[test]
+    fn test_attached_evidence_detects_corruption() {
+        let mut audit_system = AuditSystem::new(100);
+        let entry_id = audit_system.record_event(system_audit_entry(AuditEventType::MissionTransfer, AuditSeverity::Medium)).unwrap();
+
+        let artifact = audit_system.attach_evidence(&entry_id, b"flight log bytes", "application/octet-stream").unwrap();
+        assert!(matches!(&artifact, EvidenceArtifact::DataBlob { size_bytes: 16, hash, .. } if hash.len() == 64));
+        assert!(audit_system.query_iter(AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        }).any(|entry| entry.entry_id == entry_id && entry.evidence.len() == 1));
+        assert_eq!(audit_system.verify_evidence(&entry_id).unwrap(), 1);
+
+        audit_system.evidence_blobs[0].bytes[0] ^= 0xff;
+        assert!(matches!(audit_system.verify_evidence(&entry_id), Err(AuditError::EvidenceIntegrityFailure { .. })));
+        assert!(audit_system.attach_evidence("missing", b"x", "text/plain").is_err());
+    }
+
+    #[test]
+    fn test_informational_sampling_keeps_one_in_ten() {
+        let mut audit_system = AuditSystem::new(1000);
+        audit_system.set_sampling_policy(SamplingPolicy::OneIn(10));
+
+        for i in 0..10 {
+            let mut entry = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Informational);
+            entry.entry_id = format!("info_{}", i);
+            audit_system.record_event(entry).unwrap();
+        }
+        audit_system.record_event(system_audit_entry(AuditEventType::SystemHealthEvent, AuditSeverity::Critical)).unwrap();
+
+        assert_eq!(audit_system.sampled_out_count(), 9);
+        assert_eq!(audit_system.audit_store.len(), 2);
+        assert!(audit_system.audit_store.iter().any(|e| e.severity == AuditSeverity::Critical));
+    }
+
+    fn alert(alert_id: &str, severity: AuditSeverity, status: AlertStatus) -> SecurityAlert {
+        SecurityAlert {
+            alert_id: alert_id.to_string(),
+            timestamp: SystemTime::now(),
+            severity,
+            alert_type: AlertType::ComplianceDeviation,
+            title: "Test".to_string(),
+            description: String::new(),
+            affected_systems: vec![],
+            recommended_actions: vec![],
+            evidence: vec![],
+            status,
+            source_entry_id: None,
+        }
+    }
+
+    #[test]
+    fn test_alert_flood_evicts_resolved_first_and_keeps_criticals() {
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.set_max_alerts(6).unwrap();
+
+        audit_system.alerts.push(alert("resolved_0", AuditSeverity::High, AlertStatus::Resolved));
+        audit_system.alerts.push(alert("false_positive_0", AuditSeverity::Medium, AlertStatus::FalsePositive));
+        for i in 0..3 {
+            audit_system.alerts.push(alert(&format!("critical_{}", i), AuditSeverity::Critical, AlertStatus::Active));
+        }
+        audit_system.alerts.push(alert("low_0", AuditSeverity::Low, AlertStatus::Active));
+        for i in 0..6 {
+            audit_system.alerts.push(alert(&format!("flood_{}", i), AuditSeverity::Medium, AlertStatus::Active));
+            audit_system.enforce_alert_cap().unwrap();
+        }
+
+        let ids: Vec<&str> = audit_system.alerts.iter().map(|a| a.alert_id.as_str()).collect();
+        assert!(!ids.contains(&"resolved_0"));
+        assert!(!ids.contains(&"false_positive_0"));
+        assert!(!ids.contains(&"low_0"));
+        assert_eq!(ids.iter().filter(|id| id.starts_with("critical_")).count(), 3);
+        assert_eq!(ids.iter().filter(|id| id.starts_with("alert_suppression_")).count(), 1);
+    }
+
+    #[test]
+    fn test_replay_restores_evicted_and_suppression_alerts() {
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_journal(AuditJournal::in_memory(100));
+        audit_system.set_max_alerts(3).unwrap();
+        for i in 0..5 {
+            audit_system.raise_alert(alert(&format!("flood_{}", i), AuditSeverity::Medium, AlertStatus::Active)).unwrap();
+        }
+
+        let replayed = AuditSystem::replay(audit_system.journal().unwrap()).unwrap();
+        let held = |system: &AuditSystem| system.alerts.iter()
+            .map(|alert| (alert.alert_id.clone(), alert.timestamp, alert.description.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(held(&replayed), held(&audit_system));
+        assert!(replayed.alerts.iter().any(|alert| alert.alert_id.starts_with("alert_suppression_")));
+    }
+
+    #[test]
+    fn test_capacity_alert_raised_once_at_high_water_mark() {
+        let mut audit_system = AuditSystem::new(20);
+        let is_capacity_alert = |alert: &&SecurityAlert| alert.alert_type == AlertType::ConfigurationError;
+
+        for i in 0..17 {
+            let mut entry = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            entry.entry_id = format!("entry_{}", i);
+            audit_system.record_event(entry).unwrap();
+        }
+        assert_eq!(audit_system.alerts.iter().filter(is_capacity_alert).count(), 0);
+
+        for i in 17..20 {
+            let mut entry = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            entry.entry_id = format!("entry_{}", i);
+            audit_system.record_event(entry).unwrap();
+        }
+        assert_eq!(audit_system.alerts.iter().filter(is_capacity_alert).count(), 1);
+    }
+
+    #[test]
+    fn test_oversized_evidence_is_truncated_not_rejected() {
+        let mut audit_system = AuditSystem::new(1000);
+        audit_system.set_max_entry_bytes(4096);
+
+        let mut entry = system_audit_entry(AuditEventType::SystemHealthEvent, AuditSeverity::Low);
+        entry.evidence = (0..500)
+            .map(|i| EvidenceArtifact::LogEntry {
+                source: "sensor_bus".to_string(),
+                level: "debug".to_string(),
+                message: format!("reading {} nominal", i),
+            })
+            .collect();
+
+        audit_system.record_event(entry).unwrap();
+
+        let stored = &audit_system.audit_store[0];
+        assert!(stored.oversized);
+        assert!(stored.evidence.len() < 500);
+        assert!(serde_json::to_vec(stored).unwrap().len() <= 4096);
+        assert!(matches!(stored.evidence.last(),
+            Some(EvidenceArtifact::LogEntry { message, .. }) if message.starts_with("[TRUNCATED]")));
+    }
+
+    #[test]
+    fn test_query_iter_matches_query_audit() {
+        let mut audit_system = AuditSystem::new(1000);
+        for (i, severity) in [AuditSeverity::Low, AuditSeverity::High, AuditSeverity::Critical].into_iter().enumerate() {
+            let mut entry = system_audit_entry(AuditEventType::StationOperation, severity);
+            entry.entry_id = format!("entry_{}", i);
+            audit_system.record_event(entry).unwrap();
+        }
+        let query = || AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![AuditEventType::StationOperation],
+            min_severity: Some(AuditSeverity::High),
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        };
+
+        let collected: Vec<String> = audit_system.query_audit(query()).into_iter().map(|e| e.entry_id).collect();
+        let streamed: Vec<String> = audit_system.query_iter(query()).map(|e| e.entry_id.clone()).collect();
+
+        assert_eq!(streamed, collected);
+        assert_eq!(streamed, vec!["entry_1".to_string(), "entry_2".to_string()]);
+    }
+
+    #[test]
+    fn test_journal_replay_matches_summary() {
+        let path = std::env::temp_dir().join(format!("audit_journal_{}.jsonl", std::process::id()));
+        let mut audit_system = AuditSystem::new(3);
+        audit_system.enable_journal(AuditJournal::create(&path, 3).unwrap());
+
+        for i in 0..4 {
+            let mut entry = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            entry.entry_id = format!("op_{}", i);
+            audit_system.record_event(entry).unwrap();
+        }
+        audit_system.record_event(system_audit_entry(AuditEventType::EmergencyAction, AuditSeverity::Critical)).unwrap();
+        if let Some(alert_id) = audit_system.get_active_alerts().first().map(|a| a.alert_id.clone()) {
+            audit_system.update_alert_status(&alert_id, AlertStatus::Resolved).unwrap();
+        }
+
+        let journal = AuditJournal::load(&path).unwrap();
+        let replayed = AuditSystem::replay(&journal).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(journal.records().len(), audit_system.journal().unwrap().records().len());
+        assert_eq!(replayed.summary(), audit_system.summary());
+    }
+
+    #[test]
+    fn test_failed_journal_append_stores_nothing() {
+        let path = std::env::temp_dir().join(format!("audit_journal_lost_{}.jsonl", std::process::id()));
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_journal(AuditJournal::create(&path, 100).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let result = audit_system.record_event(system_audit_entry(AuditEventType::EmergencyAction, AuditSeverity::Critical));
+        assert!(matches!(result, Err(AuditError::JournalError(_))));
+        assert_eq!(audit_system.summary().total_entries, 0);
+        assert_eq!(audit_system.summary().total_alerts, 0);
+    }
+
+    #[test]
+    fn test_alerts_are_routed_only_once_their_entry_is_stored() {
+        let router = || {
+            let mut router = AlertRouter::new();
+            for alert_type in [AlertType::PolicyViolation, AlertType::UnauthorizedAccess, AlertType::SuspiciousActivity,
+                AlertType::SystemCompromise, AlertType::ConfigurationError, AlertType::PerformanceAnomaly,
+                AlertType::ComplianceDeviation, AlertType::EmergencyCondition] {
+                router.add_route(alert_type, AuditSeverity::Informational, &["all"]);
+            }
+            router
+        };
+
+        let mut stored = AuditSystem::new(100);
+        stored.set_alert_router(router());
+        stored.record_event(system_audit_entry(AuditEventType::EmergencyAction, AuditSeverity::Critical)).unwrap();
+        let raised = stored.summary().total_alerts;
+        assert!(raised > 0);
+        assert_eq!(stored.alert_router().unwrap().pending("all").len(), raised);
+
+        let path = std::env::temp_dir().join(format!("audit_journal_unrouted_{}.jsonl", std::process::id()));
+        let mut lost = AuditSystem::new(100);
+        lost.set_alert_router(router());
+        lost.enable_journal(AuditJournal::create(&path, 100).unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(lost.record_event(system_audit_entry(AuditEventType::EmergencyAction, AuditSeverity::Critical)).is_err());
+        assert!(lost.alert_router().unwrap().pending("all").is_empty());
+    }
+
+    #[test]
+    fn test_generated_entry_ids_are_unique_under_load() {
+        for scheme in [EntryIdScheme::TimestampSequence { node_id: Some("station_7".to_string()) }, EntryIdScheme::Random] {
+            let mut audit_system = AuditSystem::new(2000);
+            audit_system.set_entry_id_scheme(scheme);
+
+            let ids: std::collections::HashSet<String> = (0..1000)
+                .map(|_| audit_system.record_event(system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)).unwrap())
+                .collect();
+            assert_eq!(ids.len(), 1000);
+
+            let mut provided = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            provided.entry_id = "external_42".to_string();
+            assert_eq!(audit_system.record_event(provided).unwrap(), "external_42");
+        }
+    }
+
+    #[test]
+    fn test_retried_event_with_same_idempotency_key_recorded_once() {
+        let mut audit_system = AuditSystem::new(100);
+        let forwarded = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)
+            .with_idempotency_key("relay_7:seq_1042");
+
+        let first_id = audit_system.record_event(forwarded.clone()).unwrap();
+        let retry_id = audit_system.record_event(forwarded).unwrap();
+
+        assert_eq!(first_id, retry_id);
+        assert_eq!(audit_system.audit_store.len(), 1);
+
+        // Events without a key are never deduplicated
+        audit_system.record_event(system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)).unwrap();
+        audit_system.record_event(system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)).unwrap();
+        assert_eq!(audit_system.audit_store.len(), 3);
+
+        // Keys expire with the retention age instead of accumulating forever
+        let mut aged = AuditSystem::new(100);
+        let mut stale = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)
+            .with_idempotency_key("relay_7:seq_0001");
+        stale.timestamp = SystemTime::now() - Duration::from_secs(400 * 86400);
+        aged.record_event(stale).unwrap();
+        aged.record_event(system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low)
+            .with_idempotency_key("relay_7:seq_1042")).unwrap();
+        assert_eq!(aged.idempotency_keys.len(), 2);
+        aged.enforce_retention_policy(SystemTime::now());
+        assert_eq!(aged.idempotency_keys.keys().collect::<Vec<_>>(), vec!["relay_7:seq_1042"]);
+    }
+
+    #[test]
+    fn test_narrative_lists_correlated_steps_in_order() {
+        let mut audit_system = AuditSystem::new(100);
+        let start = SystemTime::now() - Duration::from_secs(600);
+        let steps = [
+            ("FORMATION_a1b2", "formation_authorization", 0),
+            ("FORMATION_a1b2_ASSEMBLY", "formation_assembly", 120),
+            ("FORMATION_a1b2_TRANSPORT", "formation_transport", 150),
+            ("FORMATION_ffff", "other_mission", 160),
+            ("FORMATION_a1b2_COMPLETE", "formation_complete", 300),
+        ];
+        for (correlation_id, operation_name, offset_secs) in steps.iter() {
+            let mut entry = system_audit_entry(AuditEventType::DroneCommand, AuditSeverity::Low);
+            entry.timestamp = start + Duration::from_secs(*offset_secs);
+            entry.context.correlation_id = correlation_id.to_string();
+            entry.operation.operation_name = operation_name.to_string();
+            if *operation_name == "formation_authorization" {
+                entry.actor = AuditActor::HumanOperator {
+                    operator_id: "FORMATION_LEAD_001".to_string(),
+                    clearance_level: "supervisor".to_string(),
+                    department: None,
+                };
+            }
+            audit_system.record_event(entry).unwrap();
+        }
+
+        let narrative = audit_system.narrate("FORMATION_a1b2");
+        let lines: Vec<&str> = narrative.lines().skip(1).collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("T+0s: formation authorization by operator FORMATION_LEAD_001 succeeded"));
+        assert!(lines[1].starts_with("T+2m: formation assembly"));
+        assert!(lines[2].starts_with("T+2m30s: formation transport"));
+        assert!(lines[3].starts_with("T+5m: formation complete"));
+        assert!(!narrative.contains("other mission"));
+    }
+
+    #[test]
+    fn test_backwards_timestamp_is_clamped_or_flagged() {
+        let is_clock_alert = |alert: &&SecurityAlert| alert.alert_id.starts_with("alert_clock_");
+        let now = SystemTime::now();
+
+        for mode in [ClockAnomalyMode::Monotonic, ClockAnomalyMode::Flag] {
+            let mut audit_system = AuditSystem::new(100);
+            audit_system.set_clock_anomaly_mode(mode);
+
+            let mut first = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            first.timestamp = now;
+            audit_system.record_event(first).unwrap();
+            let mut late = system_audit_entry(AuditEventType::StationOperation, AuditSeverity::Low);
+            late.timestamp = now - Duration::from_secs(30);
+            audit_system.record_event(late).unwrap();
+
+            let stored = &audit_system.audit_store[1];
+            match mode {
+                ClockAnomalyMode::Monotonic => {
+                    assert_eq!(stored.timestamp, now + Duration::from_millis(1));
+                    assert!(!stored.clock_anomaly);
+                }
+                ClockAnomalyMode::Flag => {
+                    assert_eq!(stored.timestamp, now - Duration::from_secs(30));
+                    assert!(stored.clock_anomaly);
+                }
+            }
+            let clock_alerts: Vec<&SecurityAlert> = audit_system.alerts.iter().filter(is_clock_alert).collect();
+            assert_eq!(clock_alerts.len(), 1);
+            assert_eq!(clock_alerts[0].alert_type, AlertType::ConfigurationError);
+        }
+    }
+
+    #[test]
+    fn test_legal_hold_survives_retention_sweep() {
+        let mut audit_system = AuditSystem::new(1000);
+        let old = SystemTime::now() - Duration::from_secs(400 * 86400);
+        for (i, event_type) in [AuditEventType::StationOperation, AuditEventType::EmergencyAction, AuditEventType::StationOperation].into_iter().enumerate() {
+            let mut entry = system_audit_entry(event_type, AuditSeverity::Medium);
+            entry.entry_id = format!("old_{}", i);
+            entry.timestamp = old + Duration::from_secs(i as u64);
+            audit_system.record_event(entry).unwrap();
+        }
+        let hold_id = audit_system.place_legal_hold(AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![AuditEventType::EmergencyAction],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        }).unwrap();
+        assert_eq!(audit_system.summary().held_entries, 1);
+
+        audit_system.enforce_retention_policy(SystemTime::now());
+        let remaining: Vec<&str> = audit_system.audit_store.iter().map(|entry| entry.entry_id.as_str()).collect();
+        assert_eq!(remaining, vec!["old_1"]);
+        assert_eq!(audit_system.summary().active_legal_holds, 1);
+
+        audit_system.release_legal_hold(&hold_id).unwrap();
+        audit_system.enforce_retention_policy(SystemTime::now());
+        assert!(audit_system.audit_store.is_empty());
+        assert!(matches!(audit_system.release_legal_hold(&hold_id), Err(AuditError::LegalHoldNotFound(_))));
+    }
+
+    #[test]
+    fn test_failed_journal_append_leaves_legal_holds_unchanged() {
+        let path = std::env::temp_dir().join(format!("audit_journal_holds_{}.jsonl", std::process::id()));
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_journal(AuditJournal::create(&path, 100).unwrap());
+        let everything = || AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        };
+        let hold_id = audit_system.place_legal_hold(everything()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(audit_system.place_legal_hold(everything()), Err(AuditError::JournalError(_))));
+        assert!(matches!(audit_system.release_legal_hold(&hold_id), Err(AuditError::JournalError(_))));
+        assert_eq!(audit_system.legal_holds().len(), 1);
+        assert_eq!(audit_system.legal_hold_sequence, 1);
+    }
+
+    #[test]
+    fn test_compliance_exemption_waives_violation_until_expiry() {
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_journal(AuditJournal::in_memory(100));
+        audit_system.add_compliance_rule(ComplianceRule {
+            rule_id: "emergency_severity".to_string(),
+            name: "Emergency Severity".to_string(),
+            description: "Emergency actions must be logged as critical".to_string(),
+            trigger_events: vec![AuditEventType::EmergencyAction],
+            conditions: vec![],
+            actions: vec![],
+            priority: CompliancePriority::Critical,
+            framework_id: None,
+        });
+        let is_violation = |flag: &ComplianceFlag| matches!(flag, ComplianceFlag::Violation { code, .. } if code == "CRITICAL_SEVERITY_REQUIRED");
+        let flags_of = |audit_system: &AuditSystem, id: &str| audit_system.audit_store.iter()
+            .find(|entry| entry.entry_id == id)
+            .map(|entry| entry.compliance_flags.clone())
+            .unwrap();
+        let start = SystemTime::now();
+        let stamped = |offset_s: u64| {
+            let mut entry = system_audit_entry(AuditEventType::EmergencyAction, AuditSeverity::High);
+            entry.timestamp = start + Duration::from_secs(offset_s);
+            entry
+        };
+
+        let id = audit_system.record_event(stamped(0)).unwrap();
+        assert!(flags_of(&audit_system, &id).iter().any(is_violation));
+
+        let exemption_id = audit_system.grant_compliance_exemption(
+            "CRITICAL_SEVERITY_REQUIRED",
+            ExemptionScope { event_types: vec![AuditEventType::EmergencyAction], correlation_id: None },
+            "Severity mapping fix pending field rollout",
+            "safety_officer",
+            start + Duration::from_secs(60),
+        ).unwrap();
+        assert_eq!(audit_system.active_compliance_exemptions(start + Duration::from_secs(30)).len(), 1);
+
+        let id = audit_system.record_event(stamped(30)).unwrap();
+        let flags = flags_of(&audit_system, &id);
+        assert!(!flags.iter().any(is_violation));
+        assert!(flags.contains(&ComplianceFlag::Exemption {
+            justification: "Severity mapping fix pending field rollout".to_string(),
+            approver: "safety_officer".to_string(),
+        }));
+
+        let id = audit_system.record_event(stamped(120)).unwrap();
+        assert!(flags_of(&audit_system, &id).iter().any(is_violation));
+
+        // Expiry runs off the station clock, not the timestamps on recorded entries
+        assert_eq!(audit_system.active_compliance_exemptions(start + Duration::from_secs(30)).len(), 1);
+        assert_eq!(audit_system.expire_compliance_exemptions(start + Duration::from_secs(120)).unwrap(), vec![exemption_id.clone()]);
+        assert!(audit_system.active_compliance_exemptions(start + Duration::from_secs(30)).is_empty());
+
+        let lifecycle: Vec<String> = audit_system.audit_store.iter()
+            .filter(|entry| entry.operation.parameters.get("exemption_id") == Some(&serde_json::json!(exemption_id)))
+            .map(|entry| entry.operation.operation_name.clone())
+            .collect();
+        assert_eq!(lifecycle, vec!["compliance_exemption_granted", "compliance_exemption_expired"]);
+
+        let replayed = AuditSystem::replay(audit_system.journal().unwrap()).unwrap();
+        assert!(replayed.active_compliance_exemptions(start + Duration::from_secs(30)).is_empty());
+        assert_eq!(replayed.audit_store.len(), audit_system.audit_store.len());
+    }
+
+    #[test]
+    fn test_mission_evaluated_against_declared_frameworks_only() {
+        let framework = |framework_id: &str| RegulatoryFramework {
+            framework_id: framework_id.to_string(),
+            name: framework_id.to_uppercase(),
+            jurisdiction: "test".to_string(),
+            applicable_domains: vec!["uas".to_string()],
+            requirements: vec![],
+            audit_frequency: "annual".to_string(),
+            last_audit_date: None,
+        };
+        let rule = |rule_id: &str, framework_id: &str, event_type: AuditEventType| ComplianceRule {
+            rule_id: rule_id.to_string(),
+            name: rule_id.to_string(),
+            description: format!("{} rule", framework_id),
+            trigger_events: vec![event_type],
+            conditions: vec![],
+            actions: vec![],
+            priority: CompliancePriority::Medium,
+            framework_id: Some(framework_id.to_string()),
+        };
+
+        let mut audit_system = AuditSystem::new(100);
+        for framework_id in ["faa_part_107", "easa_specific", "caa_uk"] {
+            audit_system.add_regulatory_framework(framework(framework_id));
+        }
+        audit_system.add_compliance_rule(ComplianceRule {
+            actions: vec![ComplianceAction::FlagForReview { reviewer: "faa_liaison".to_string() }],
+            ..rule("faa_transfer_logged", "faa_part_107", AuditEventType::MissionTransfer)
+        });
+        audit_system.add_compliance_rule(rule("easa_emergency_reported", "easa_specific", AuditEventType::EmergencyAction));
+        audit_system.add_compliance_rule(rule("caa_transfer_logged", "caa_uk", AuditEventType::MissionTransfer));
+
+        let mut mission = MissionPayload::default();
+        mission.header.id = [7; 16];
+        mission.policies.compliance_frameworks = vec!["faa_part_107".to_string(), "easa_specific".to_string(), "jcab_level_4".to_string()];
+        let targeted = |event_type: AuditEventType, mission_id: MissionId| {
+            let mut entry = system_audit_entry(event_type, AuditSeverity::Medium);
+            entry.target = Some(AuditTarget::Mission { mission_id, priority: MissionPriority::High });
+            entry
+        };
+        audit_system.record_event(targeted(AuditEventType::MissionTransfer, mission.header.id)).unwrap();
+        audit_system.record_event(targeted(AuditEventType::EmergencyAction, mission.header.id)).unwrap();
+        audit_system.record_event(targeted(AuditEventType::MissionTransfer, [8; 16])).unwrap();
+
+        let report = audit_system.evaluate_mission_compliance(&mission).unwrap();
+        assert_eq!(report.evaluated_frameworks, vec!["faa_part_107", "easa_specific"]);
+        let tripped: Vec<(&str, &str)> = report.triggered.iter()
+            .map(|triggered| (triggered.framework_id.as_str(), triggered.rule_id.as_str()))
+            .collect();
+        assert_eq!(tripped, vec![("faa_part_107", "faa_transfer_logged"), ("easa_specific", "easa_emergency_reported")]);
+        assert!(matches!(report.warnings.as_slice(), [ComplianceFlag::Warning { message }] if message.contains("jcab_level_4")));
+
+        // The triggered FAA rule's review action ran against the mission's transfer entry
+        let reviews: Vec<&SecurityAlert> = audit_system.get_active_alerts().into_iter()
+            .filter(|alert| alert.title == "Compliance Review Required")
+            .collect();
+        assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].source_entry_id.as_ref(), Some(&report.triggered[0].entry_id));
+    }
 }
\ No newline at end of file
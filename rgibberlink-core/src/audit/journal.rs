@@ -0,0 +1,118 @@
+//! # Audit Journal Module
+//!
+//! Ordered log of every operation applied to an `AuditSystem`, allowing the store
+//! to be rebuilt step by step for debugging and compliance reconstruction.
+
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use super::compliance::ComplianceExemption;
+use super::events::{AuditEntry, AuditError, AlertStatus, LegalHold, SecurityAlert};
+
+/// Operation applied to the audit store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JournalOperation {
+    /// Journal start; records the store configuration needed for replay
+    Initialized { max_entries: usize },
+    /// Entry stored, together with the alerts its compliance check raised
+    RecordEvent { entry: AuditEntry, alerts: Vec<SecurityAlert> },
+    /// Alert raised outside the audit pipeline
+    AlertRaised { alert: SecurityAlert },
+    /// Alerts evicted over the cap, and the suppression meta-alert as raised or updated
+    AlertsEvicted { alert_ids: Vec<String>, suppression: SecurityAlert },
+    UpdateAlertStatus { alert_id: String, status: AlertStatus },
+    /// Retention enforced using `now` as the age reference
+    RetentionEnforced { now: SystemTime },
+    LegalHoldPlaced { hold: LegalHold },
+    LegalHoldReleased { hold_id: String },
+    ExemptionGranted { exemption: ComplianceExemption },
+    /// Exemptions lapsed by `now` removed
+    ExemptionsExpired { now: SystemTime },
+}
+
+/// Single journal line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub sequence: u64,
+    pub recorded_at: SystemTime,
+    pub operation: JournalOperation,
+}
+
+/// Append-only operation journal, optionally mirrored to a JSON-lines file
+#[derive(Debug, Clone)]
+pub struct AuditJournal {
+    path: Option<PathBuf>,
+    records: Vec<JournalRecord>,
+}
+
+impl AuditJournal {
+    /// Journal kept in memory only
+    pub fn in_memory(max_entries: usize) -> Self {
+        let mut journal = Self { path: None, records: Vec::new() };
+        // In-memory appends cannot fail
+        let _ = journal.append(JournalOperation::Initialized { max_entries });
+        journal
+    }
+
+    /// Journal mirrored to `path`, truncating any existing file
+    pub fn create(path: impl AsRef<Path>, max_entries: usize) -> Result<Self, AuditError> {
+        File::create(path.as_ref()).map_err(|e| AuditError::JournalError(e.to_string()))?;
+
+        let mut journal = Self { path: Some(path.as_ref().to_path_buf()), records: Vec::new() };
+        journal.append(JournalOperation::Initialized { max_entries })?;
+        Ok(journal)
+    }
+
+    /// Load a journal previously written with `create`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AuditError> {
+        let file = File::open(path.as_ref()).map_err(|e| AuditError::JournalError(e.to_string()))?;
+
+        let mut records = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| AuditError::JournalError(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: JournalRecord = serde_json::from_str(&line)
+                .map_err(|e| AuditError::JournalError(e.to_string()))?;
+            records.push(record);
+        }
+
+        Ok(Self { path: None, records })
+    }
+
+    /// Append an operation, writing it through to the backing file if any
+    pub fn append(&mut self, operation: JournalOperation) -> Result<(), AuditError> {
+        let record = JournalRecord {
+            sequence: self.records.len() as u64,
+            recorded_at: SystemTime::now(),
+            operation,
+        };
+
+        if let Some(path) = &self.path {
+            let line = serde_json::to_string(&record)
+                .map_err(|e| AuditError::JournalError(e.to_string()))?;
+            let mut file = OpenOptions::new().append(true).open(path)
+                .map_err(|e| AuditError::JournalError(e.to_string()))?;
+            writeln!(file, "{}", line).map_err(|e| AuditError::JournalError(e.to_string()))?;
+        }
+
+        self.records.push(record);
+        Ok(())
+    }
+
+    /// Recorded operations in order
+    pub fn records(&self) -> &[JournalRecord] {
+        &self.records
+    }
+
+    /// Store size limit recorded when the journal was started
+    pub fn max_entries(&self) -> Option<usize> {
+        self.records.iter().find_map(|record| match record.operation {
+            JournalOperation::Initialized { max_entries } => Some(max_entries),
+            _ => None,
+        })
+    }
+}
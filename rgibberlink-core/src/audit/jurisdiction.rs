@@ -0,0 +1,136 @@
+//! # Jurisdiction Resolution Module
+//!
+//! Maps coordinates to jurisdictions and restricted zones from a set of zone
+//! polygons, so audit entries carry consistent geographic context.
+
+use serde::{Deserialize, Serialize};
+use crate::mission::GeoCoordinate;
+use super::events::{AuditEntry, AuditError, GeographicContext};
+
+/// Named zone polygon belonging to a jurisdiction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JurisdictionZone {
+    pub name: String,
+    pub jurisdiction: String,
+    pub restricted: bool,
+    pub polygon: Vec<GeoCoordinate>, // Vertices in order; closing edge is implied
+}
+
+impl JurisdictionZone {
+    /// Ray-casting point-in-polygon test on latitude/longitude
+    pub fn contains(&self, point: &GeoCoordinate) -> bool {
+        if self.polygon.len() < 3 {
+            return false;
+        }
+
+        let (x, y) = (point.longitude, point.latitude);
+        let mut inside = false;
+        let mut j = self.polygon.len() - 1;
+        for i in 0..self.polygon.len() {
+            let (xi, yi) = (self.polygon[i].longitude, self.polygon[i].latitude);
+            let (xj, yj) = (self.polygon[j].longitude, self.polygon[j].latitude);
+            if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+}
+
+/// Resolves coordinates to jurisdiction and restricted-zone status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JurisdictionResolver {
+    pub default_jurisdiction: String, // Used when no zone contains the point
+    pub zones: Vec<JurisdictionZone>,
+}
+
+impl JurisdictionResolver {
+    /// Create a resolver with no zones
+    pub fn new(default_jurisdiction: &str) -> Self {
+        Self {
+            default_jurisdiction: default_jurisdiction.to_string(),
+            zones: Vec::new(),
+        }
+    }
+
+    /// Load a resolver from its JSON representation
+    pub fn from_json(json: &str) -> Result<Self, AuditError> {
+        serde_json::from_str(json).map_err(|e| AuditError::InvalidEntry(format!("Invalid jurisdiction zones: {}", e)))
+    }
+
+    /// Add a zone polygon
+    pub fn add_zone(&mut self, zone: JurisdictionZone) -> &mut Self {
+        self.zones.push(zone);
+        self
+    }
+
+    /// Resolve a coordinate; a restricted containing zone takes precedence over others
+    pub fn resolve(&self, location: &GeoCoordinate) -> GeographicContext {
+        let containing: Vec<&JurisdictionZone> = self.zones.iter()
+            .filter(|zone| zone.contains(location))
+            .collect();
+        let restricted_zone = containing.iter().any(|zone| zone.restricted);
+        let jurisdiction = containing.iter()
+            .find(|zone| zone.restricted)
+            .or_else(|| containing.first())
+            .map(|zone| zone.jurisdiction.clone())
+            .unwrap_or_else(|| self.default_jurisdiction.clone());
+
+        GeographicContext {
+            latitude: location.latitude,
+            longitude: location.longitude,
+            altitude_m: location.altitude_msl,
+            jurisdiction,
+            restricted_zone,
+        }
+    }
+
+    /// Fill an audit entry's geographic context from its location
+    pub fn annotate(&self, entry: &mut AuditEntry, location: &GeoCoordinate) {
+        entry.context.geographic_location = Some(self.resolve(location));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corner(latitude: f64, longitude: f64) -> GeoCoordinate {
+        GeoCoordinate { latitude, longitude, altitude_msl: 0.0 }
+    }
+
+    fn resolver() -> JurisdictionResolver {
+        let mut resolver = JurisdictionResolver::new("FR");
+        resolver
+            .add_zone(JurisdictionZone {
+                name: "lyon_metro".to_string(),
+                jurisdiction: "FR-ARA".to_string(),
+                restricted: false,
+                polygon: vec![corner(45.6, 4.7), corner(45.6, 5.0), corner(45.9, 5.0), corner(45.9, 4.7)],
+            })
+            .add_zone(JurisdictionZone {
+                name: "industrial_zone_a".to_string(),
+                jurisdiction: "FR-ARA-IZA".to_string(),
+                restricted: true,
+                polygon: vec![corner(45.70, 4.80), corner(45.70, 4.85), corner(45.75, 4.85), corner(45.75, 4.80)],
+            });
+        resolver
+    }
+
+    #[test]
+    fn test_point_in_restricted_polygon_resolves_to_zone() {
+        let resolver = resolver();
+
+        let inside = resolver.resolve(&corner(45.72, 4.82));
+        assert_eq!(inside.jurisdiction, "FR-ARA-IZA");
+        assert!(inside.restricted_zone);
+
+        let metro = resolver.resolve(&corner(45.80, 4.90));
+        assert_eq!(metro.jurisdiction, "FR-ARA");
+        assert!(!metro.restricted_zone);
+
+        let outside = resolver.resolve(&corner(48.85, 2.35));
+        assert_eq!(outside.jurisdiction, "FR");
+    }
+}
@@ -4,13 +4,26 @@
 //!
 //! This module provides:
 //! - Audit event recording and querying
-//! - Regulatory compliance validation
-//! - Security alert generation
+//! - Regulatory compliance validation, per mission against its declared frameworks
+//! - Security alert generation and routing
 //! - Report generation and scheduling
 //! - Retention policy management
+//! - Operation journaling and replay
+//! - Encrypted at-rest storage
+//! - Jurisdiction resolution for geographic context
+//! - Signed mission-completion certificates
+//! - Canonical threat and mitigation vocabulary
+//! - Cached results for repeated queries
 
 pub mod events;
 pub mod compliance;
+pub mod routing;
+pub mod journal;
+pub mod storage;
+pub mod jurisdiction;
+pub mod certificate;
+pub mod risk;
+pub mod query_cache;
 
 // Re-export main types for convenience
 pub use events::{
@@ -26,6 +39,12 @@ pub use events::{
     ActorFilter,
     ReportRequest,
     AuditError,
+    SamplingPolicy,
+    ComplianceFailureStance,
+    ClockAnomalyMode,
+    EntryIdScheme,
+    AuditSummary,
+    LegalHold,
 };
 
 pub use compliance::{
@@ -38,4 +57,47 @@ pub use compliance::{
     ComplianceRule,
     ComplianceAction,
     CompliancePriority,
+    ComplianceExemption,
+    ExemptionScope,
+    MissionComplianceReport,
+    TriggeredRule,
+};
+
+pub use routing::{
+    AlertRouter,
+    AlertRoute,
+    ChannelSuppression,
+    DedupKey,
+};
+
+pub use journal::{
+    AuditJournal,
+    JournalOperation,
+    JournalRecord,
+};
+
+pub use storage::{
+    EncryptedAuditStore,
+    SealedAuditRecord,
+};
+
+pub use jurisdiction::{
+    JurisdictionResolver,
+    JurisdictionZone,
+};
+
+pub use certificate::{
+    CompletionCertificate,
+    CertificateBody,
+};
+
+pub use risk::{
+    ThreatVector,
+    Mitigation,
+    ThreatSummary,
+};
+
+pub use query_cache::{
+    QueryCacheConfig,
+    QueryCacheStats,
 };
\ No newline at end of file
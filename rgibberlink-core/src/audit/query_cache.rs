@@ -0,0 +1,163 @@
+//! # Query Cache Module
+//!
+//! Opt-in LRU cache of audit query results for dashboards that poll the same query.
+//! Results are keyed by a normalized form of the query and dropped as soon as a store
+//! mutation could change them, so a hit always equals a fresh scan.
+
+use std::time::{Duration, SystemTime};
+use super::events::{AuditEntry, AuditQuery};
+
+/// Size and freshness bounds of the query cache
+#[derive(Debug, Clone)]
+pub struct QueryCacheConfig {
+    pub capacity: usize, // Distinct queries kept; the least recently used is evicted first
+    pub ttl: Duration,   // Results older than this are rescanned even without a write
+}
+
+impl Default for QueryCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 32,
+            ttl: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Cache effectiveness counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueryCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub invalidations: u64, // Cached results dropped because the store changed
+}
+
+#[derive(Debug, Clone)]
+struct CachedQuery {
+    key: String,
+    query: AuditQuery,
+    results: Vec<AuditEntry>,
+    cached_at: SystemTime,
+}
+
+/// LRU cache of query results, least recently used first
+#[derive(Debug, Clone)]
+pub(super) struct QueryCache {
+    config: QueryCacheConfig,
+    entries: Vec<CachedQuery>,
+    stats: QueryCacheStats,
+}
+
+impl QueryCache {
+    pub(super) fn new(config: QueryCacheConfig) -> Self {
+        Self {
+            config,
+            entries: Vec::new(),
+            stats: QueryCacheStats::default(),
+        }
+    }
+
+    pub(super) fn stats(&self) -> QueryCacheStats {
+        self.stats
+    }
+
+    /// Key equal for queries that select the same entries, whatever their filter order
+    pub(super) fn normalize(query: &AuditQuery) -> String {
+        let sorted = |values: Vec<String>| {
+            let mut values = values;
+            values.sort();
+            values.dedup();
+            values
+        };
+        format!(
+            "{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+            query.start_time,
+            query.end_time,
+            sorted(query.event_types.iter().map(|event_type| format!("{:?}", event_type)).collect()),
+            query.min_severity,
+            query.actor_filter,
+            sorted(query.compliance_flags.iter().map(|flag| format!("{:?}", flag)).collect()),
+            query.limit,
+        )
+    }
+
+    /// Cached results for `key` if present and within the TTL at `now`
+    pub(super) fn get(&mut self, key: &str, now: SystemTime) -> Option<Vec<AuditEntry>> {
+        let ttl = self.config.ttl;
+        self.entries.retain(|cached| now.duration_since(cached.cached_at).map_or(true, |age| age <= ttl));
+
+        match self.entries.iter().position(|cached| cached.key == key) {
+            Some(index) => {
+                let cached = self.entries.remove(index);
+                let results = cached.results.clone();
+                self.entries.push(cached);
+                self.stats.hits += 1;
+                Some(results)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub(super) fn insert(&mut self, key: String, query: AuditQuery, results: Vec<AuditEntry>, now: SystemTime) {
+        if self.config.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|cached| cached.key != key);
+        self.entries.push(CachedQuery { key, query, results, cached_at: now });
+        if self.entries.len() > self.config.capacity {
+            let excess = self.entries.len() - self.config.capacity;
+            self.entries.drain(..excess);
+        }
+    }
+
+    /// Drop every cached result for which `affected` holds
+    pub(super) fn invalidate_where(&mut self, affected: impl Fn(&AuditQuery, &[AuditEntry]) -> bool) {
+        let before = self.entries.len();
+        self.entries.retain(|cached| !affected(&cached.query, &cached.results));
+        self.stats.invalidations += (before - self.entries.len()) as u64;
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.invalidate_where(|_, _| true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditEventType, AuditSeverity, AuditSystem};
+    use crate::fixtures::system_audit_entry;
+
+    fn entry(event_type: AuditEventType) -> AuditEntry {
+        system_audit_entry(event_type, AuditSeverity::Medium)
+    }
+
+    fn mission_transfers() -> AuditQuery {
+        AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![AuditEventType::MissionTransfer],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn test_repeated_query_hits_cache_until_write() {
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_query_cache(QueryCacheConfig::default());
+        audit_system.record_event(entry(AuditEventType::MissionTransfer)).unwrap();
+
+        assert_eq!(audit_system.query_audit_cached(mission_transfers()).len(), 1);
+        assert_eq!(audit_system.query_audit_cached(mission_transfers()).len(), 1);
+        assert_eq!(audit_system.query_cache_stats(), Some(QueryCacheStats { hits: 1, misses: 1, invalidations: 0 }));
+
+        audit_system.record_event(entry(AuditEventType::MissionTransfer)).unwrap();
+        assert_eq!(audit_system.query_audit_cached(mission_transfers()).len(), 2);
+        assert_eq!(audit_system.query_cache_stats(), Some(QueryCacheStats { hits: 1, misses: 2, invalidations: 1 }));
+    }
+}
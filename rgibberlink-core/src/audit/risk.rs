@@ -0,0 +1,222 @@
+//! # Risk Vocabulary Module
+//!
+//! Canonical threat vectors and mitigations for `RiskContext`, so risk data can be
+//! aggregated across missions. Free-form strings recorded before the vocabulary
+//! existed are mapped onto it by `parse`; anything unrecognized is kept as `Other`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::weather::RiskLevel;
+use super::events::{AuditEntry, RiskContext};
+
+/// Canonical threat vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ThreatVector {
+    PayloadDamage,
+    FormationIntegrityFailure,
+    LiftFailure,
+    WeatherDeterioration,
+    NavigationError,
+    CommunicationLoss,
+    GroundCrewSafety,
+    LandingPrecision,
+    CargoHandover,
+    Other(String),
+}
+
+impl ThreatVector {
+    /// Canonical string recorded in `RiskContext::threat_vectors`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ThreatVector::PayloadDamage => "payload_damage",
+            ThreatVector::FormationIntegrityFailure => "formation_integrity_failure",
+            ThreatVector::LiftFailure => "lift_failure",
+            ThreatVector::WeatherDeterioration => "weather_deterioration",
+            ThreatVector::NavigationError => "navigation_error",
+            ThreatVector::CommunicationLoss => "communication_loss",
+            ThreatVector::GroundCrewSafety => "ground_crew_safety",
+            ThreatVector::LandingPrecision => "landing_precision",
+            ThreatVector::CargoHandover => "cargo_handover",
+            ThreatVector::Other(other) => other,
+        }
+    }
+
+    /// Map a canonical or legacy free-form string onto the vocabulary
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "payload_damage" | "payload_damage_risk" => ThreatVector::PayloadDamage,
+            "formation_integrity_failure" | "formation_breakup" | "formation_instability" => ThreatVector::FormationIntegrityFailure,
+            "lift_failure" | "lift_failure_risk" => ThreatVector::LiftFailure,
+            "weather_deterioration" | "weather_disturbance" => ThreatVector::WeatherDeterioration,
+            "navigation_error" | "navigation_errors" => ThreatVector::NavigationError,
+            "communication_loss" | "comms_loss" | "link_loss" => ThreatVector::CommunicationLoss,
+            "ground_crew_safety" | "ground_crew_coordination" => ThreatVector::GroundCrewSafety,
+            "landing_precision" | "landing_precision_errors" => ThreatVector::LandingPrecision,
+            "cargo_handover" | "cargo_handover_issues" => ThreatVector::CargoHandover,
+            _ => ThreatVector::Other(value.to_string()),
+        }
+    }
+}
+
+/// Canonical mitigation
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mitigation {
+    FormationRedundancy,
+    FormationMonitoring,
+    WeatherMonitoring,
+    WeatherCompensation,
+    RedundantNavigation,
+    PrecisionGuidance,
+    EmergencyProcedures,
+    GroundCrewStandby,
+    CommunicationProcedures,
+    Other(String),
+}
+
+impl Mitigation {
+    /// Canonical string recorded in `RiskContext::mitigation_applied`
+    pub fn as_str(&self) -> &str {
+        match self {
+            Mitigation::FormationRedundancy => "formation_redundancy",
+            Mitigation::FormationMonitoring => "formation_monitoring",
+            Mitigation::WeatherMonitoring => "weather_monitoring",
+            Mitigation::WeatherCompensation => "weather_compensation",
+            Mitigation::RedundantNavigation => "redundant_navigation",
+            Mitigation::PrecisionGuidance => "precision_guidance",
+            Mitigation::EmergencyProcedures => "emergency_procedures",
+            Mitigation::GroundCrewStandby => "ground_crew_standby",
+            Mitigation::CommunicationProcedures => "communication_procedures",
+            Mitigation::Other(other) => other,
+        }
+    }
+
+    /// Map a canonical or legacy free-form string onto the vocabulary
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "formation_redundancy" | "redundant_lift_system" => Mitigation::FormationRedundancy,
+            "formation_monitoring" | "formation_control_systems" => Mitigation::FormationMonitoring,
+            "weather_monitoring" | "weather_validation" => Mitigation::WeatherMonitoring,
+            "weather_compensation" => Mitigation::WeatherCompensation,
+            "redundant_navigation" => Mitigation::RedundantNavigation,
+            "precision_guidance" | "precision_guidance_systems" => Mitigation::PrecisionGuidance,
+            "emergency_procedures" | "safety_procedures" => Mitigation::EmergencyProcedures,
+            "ground_crew_standby" | "ground_crew_training" => Mitigation::GroundCrewStandby,
+            "communication_procedures" => Mitigation::CommunicationProcedures,
+            _ => Mitigation::Other(value.to_string()),
+        }
+    }
+}
+
+impl RiskContext {
+    /// Build a risk context from the canonical vocabulary
+    pub fn from_canonical(risk_level: RiskLevel, threats: &[ThreatVector], mitigations: &[Mitigation], residual_risk: f32) -> Self {
+        let mut context = Self {
+            risk_level,
+            threat_vectors: threats.iter().map(|threat| threat.as_str().to_string()).collect(),
+            mitigation_applied: mitigations.iter().map(|mitigation| mitigation.as_str().to_string()).collect(),
+            residual_risk,
+        };
+        context.canonicalize();
+        context
+    }
+
+    /// Distinct threat vectors, legacy strings included
+    pub fn threats(&self) -> Vec<ThreatVector> {
+        let mut threats: Vec<ThreatVector> = Vec::new();
+        for threat in self.threat_vectors.iter().map(|value| ThreatVector::parse(value)) {
+            if !threats.contains(&threat) {
+                threats.push(threat);
+            }
+        }
+        threats
+    }
+
+    /// Distinct mitigations, legacy strings included
+    pub fn mitigations(&self) -> Vec<Mitigation> {
+        let mut mitigations: Vec<Mitigation> = Vec::new();
+        for mitigation in self.mitigation_applied.iter().map(|value| Mitigation::parse(value)) {
+            if !mitigations.contains(&mitigation) {
+                mitigations.push(mitigation);
+            }
+        }
+        mitigations
+    }
+
+    /// Rewrite legacy strings to their canonical form and drop duplicates
+    pub fn canonicalize(&mut self) {
+        self.threat_vectors = self.threats().iter().map(|threat| threat.as_str().to_string()).collect();
+        self.mitigation_applied = self.mitigations().iter().map(|mitigation| mitigation.as_str().to_string()).collect();
+    }
+}
+
+/// Number of entries naming each threat vector and mitigation
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThreatSummary {
+    pub threat_counts: HashMap<ThreatVector, usize>,
+    pub mitigation_counts: HashMap<Mitigation, usize>,
+}
+
+impl ThreatSummary {
+    /// Count each threat and mitigation once per entry
+    pub fn from_entries<'a>(entries: impl IntoIterator<Item = &'a AuditEntry>) -> Self {
+        let mut summary = Self::default();
+        for entry in entries {
+            let risk = &entry.context.risk_context;
+            for threat in risk.threats() {
+                *summary.threat_counts.entry(threat).or_insert(0) += 1;
+            }
+            for mitigation in risk.mitigations() {
+                *summary.mitigation_counts.entry(mitigation).or_insert(0) += 1;
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditEventType, AuditSeverity, AuditSystem};
+    use crate::fixtures::system_audit_entry;
+
+    fn entry(risk_context: RiskContext) -> AuditEntry {
+        let mut entry = system_audit_entry(AuditEventType::DroneCommand, AuditSeverity::Medium);
+        entry.context.risk_context = risk_context;
+        entry
+    }
+
+    #[test]
+    fn test_canonical_threats_aggregate_and_other_stays_distinct() {
+        let mut audit_system = AuditSystem::new(100);
+        let canonical = RiskContext::from_canonical(
+            RiskLevel::Low,
+            &[ThreatVector::FormationIntegrityFailure, ThreatVector::WeatherDeterioration],
+            &[Mitigation::FormationRedundancy],
+            0.08,
+        );
+        audit_system.record_event(entry(canonical.clone())).unwrap();
+        audit_system.record_event(entry(canonical)).unwrap();
+        audit_system.record_event(entry(RiskContext {
+            threat_vectors: vec!["bird_strike".to_string()],
+            ..Default::default()
+        })).unwrap();
+
+        let summary = audit_system.threat_summary();
+        assert_eq!(summary.threat_counts[&ThreatVector::FormationIntegrityFailure], 2);
+        assert_eq!(summary.threat_counts[&ThreatVector::Other("bird_strike".to_string())], 1);
+        assert_eq!(summary.threat_counts.len(), 3);
+    }
+
+    #[test]
+    fn test_legacy_strings_migrate_to_canonical_form() {
+        let mut legacy = RiskContext {
+            threat_vectors: vec!["formation_breakup".to_string(), "formation_instability".to_string(), "lift_failure_risk".to_string()],
+            mitigation_applied: vec!["redundant_lift_system".to_string(), "weather_validation".to_string()],
+            ..Default::default()
+        };
+        legacy.canonicalize();
+
+        assert_eq!(legacy.threat_vectors, vec!["formation_integrity_failure", "lift_failure"]);
+        assert_eq!(legacy.mitigations(), vec![Mitigation::FormationRedundancy, Mitigation::WeatherMonitoring]);
+    }
+}
@@ -0,0 +1,216 @@
+//! # Alert Routing Module
+//!
+//! Delivers security alerts to named channels based on alert type and severity.
+//! Channels can suppress repeats independently, so a pager hears about an alert once
+//! per window while a log channel still receives every occurrence.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use super::events::{SecurityAlert, AlertType, AuditSeverity};
+
+/// Routing rule mapping an alert type to one or more channels
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertRoute {
+    pub alert_type: AlertType,
+    pub min_severity: AuditSeverity, // Alerts below this severity are not routed
+    pub channels: Vec<String>,
+}
+
+/// Alert fields that make two alerts the same notification on a channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DedupKey {
+    AlertType,
+    TypeAndTitle,
+    TypeTitleAndSystems, // Same alert on different systems is still notified
+}
+
+impl DedupKey {
+    fn key_for(&self, alert: &SecurityAlert) -> String {
+        match self {
+            DedupKey::AlertType => format!("{:?}", alert.alert_type),
+            DedupKey::TypeAndTitle => format!("{:?}|{}", alert.alert_type, alert.title),
+            DedupKey::TypeTitleAndSystems => {
+                let mut systems = alert.affected_systems.clone();
+                systems.sort();
+                format!("{:?}|{}|{}", alert.alert_type, alert.title, systems.join(","))
+            }
+        }
+    }
+}
+
+/// Repeat suppression for one channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSuppression {
+    pub window: Duration, // Duplicates within this long of the last delivery are suppressed
+    pub dedup_key: DedupKey,
+}
+
+/// Routing table that delivers alerts to per-channel outboxes
+#[derive(Debug, Clone, Default)]
+pub struct AlertRouter {
+    routes: Vec<AlertRoute>,
+    outboxes: HashMap<String, Vec<SecurityAlert>>,
+    suppression: HashMap<String, ChannelSuppression>,
+    last_delivered: HashMap<(String, String), SystemTime>, // (channel, dedup key) -> last delivery
+    suppressed: HashMap<String, u64>,
+}
+
+impl AlertRouter {
+    /// Create an empty router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a routing rule
+    pub fn add_route(&mut self, alert_type: AlertType, min_severity: AuditSeverity, channels: &[&str]) -> &mut Self {
+        self.routes.push(AlertRoute {
+            alert_type,
+            min_severity,
+            channels: channels.iter().map(|c| c.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Suppress duplicates on `channel` for `window` after each delivery, by `dedup_key`
+    pub fn set_channel_suppression(&mut self, channel: &str, window: Duration, dedup_key: DedupKey) -> &mut Self {
+        self.suppression.insert(channel.to_string(), ChannelSuppression { window, dedup_key });
+        self
+    }
+
+    /// Alerts routed to a channel but held back as duplicates; they remain in the audit record
+    pub fn suppressed_count(&self, channel: &str) -> u64 {
+        self.suppressed.get(channel).copied().unwrap_or(0)
+    }
+
+    /// Whether `channel` already carried a duplicate of `alert` within its window;
+    /// records the delivery when it did not
+    fn suppress(&mut self, channel: &str, alert: &SecurityAlert) -> bool {
+        let suppression = match self.suppression.get(channel) {
+            Some(suppression) => suppression,
+            None => return false,
+        };
+        let key = (channel.to_string(), suppression.dedup_key.key_for(alert));
+
+        // An alert stamped before the last delivery is a duplicate of it
+        let within_window = self.last_delivered.get(&key)
+            .map_or(false, |last| alert.timestamp.duration_since(*last).map_or(true, |elapsed| elapsed < suppression.window));
+        if within_window {
+            *self.suppressed.entry(channel.to_string()).or_insert(0) += 1;
+        } else {
+            self.last_delivered.insert(key, alert.timestamp);
+        }
+        within_window
+    }
+
+    /// Deliver an alert to every matching channel, returning the channels reached.
+    /// Channels suppressing it as a duplicate are not reached.
+    pub fn route(&mut self, alert: &SecurityAlert) -> Vec<String> {
+        let mut delivered: Vec<String> = Vec::new();
+
+        for route in &self.routes {
+            if route.alert_type != alert.alert_type || alert.severity < route.min_severity {
+                continue;
+            }
+            for channel in &route.channels {
+                if !delivered.contains(channel) {
+                    delivered.push(channel.clone());
+                }
+            }
+        }
+
+        delivered.retain(|channel| !self.suppress(channel, alert));
+        for channel in &delivered {
+            self.outboxes.entry(channel.clone()).or_default().push(alert.clone());
+        }
+
+        delivered
+    }
+
+    /// Alerts delivered to a channel and not yet drained
+    pub fn pending(&self, channel: &str) -> &[SecurityAlert] {
+        self.outboxes.get(channel).map(|alerts| alerts.as_slice()).unwrap_or(&[])
+    }
+
+    /// Take all alerts delivered to a channel
+    pub fn drain_channel(&mut self, channel: &str) -> Vec<SecurityAlert> {
+        self.outboxes.remove(channel).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::events::AlertStatus;
+    use std::time::SystemTime;
+
+    fn alert(alert_type: AlertType, severity: AuditSeverity) -> SecurityAlert {
+        SecurityAlert {
+            alert_id: "alert_1".to_string(),
+            timestamp: SystemTime::now(),
+            severity,
+            alert_type,
+            title: "Test alert".to_string(),
+            description: "Test alert".to_string(),
+            affected_systems: Vec::new(),
+            recommended_actions: Vec::new(),
+            evidence: Vec::new(),
+            status: AlertStatus::Active,
+            source_entry_id: None,
+        }
+    }
+
+    fn router() -> AlertRouter {
+        let mut router = AlertRouter::new();
+        router
+            .add_route(AlertType::SystemCompromise, AuditSeverity::Informational, &["security"])
+            .add_route(AlertType::PerformanceAnomaly, AuditSeverity::Informational, &["ops"])
+            .add_route(AlertType::ComplianceDeviation, AuditSeverity::Critical, &["compliance"]);
+        router
+    }
+
+    #[test]
+    fn test_system_compromise_routed_to_security_only() {
+        let mut router = router();
+
+        let channels = router.route(&alert(AlertType::SystemCompromise, AuditSeverity::High));
+
+        assert_eq!(channels, vec!["security".to_string()]);
+        assert_eq!(router.pending("security").len(), 1);
+        assert!(router.pending("ops").is_empty());
+    }
+
+    #[test]
+    fn test_severity_threshold_gates_compliance_channel() {
+        let mut router = router();
+
+        assert!(router.route(&alert(AlertType::ComplianceDeviation, AuditSeverity::High)).is_empty());
+        assert_eq!(router.route(&alert(AlertType::ComplianceDeviation, AuditSeverity::Critical)), vec!["compliance".to_string()]);
+        assert_eq!(router.drain_channel("compliance").len(), 1);
+    }
+
+    #[test]
+    fn test_pager_suppresses_repeats_while_log_records_each() {
+        let mut router = AlertRouter::new();
+        router
+            .add_route(AlertType::SystemCompromise, AuditSeverity::Informational, &["pager", "log"])
+            .set_channel_suppression("pager", Duration::from_secs(3600), DedupKey::TypeAndTitle);
+
+        let first = alert(AlertType::SystemCompromise, AuditSeverity::High);
+        for minutes in [0, 10, 20] {
+            let mut repeat = first.clone();
+            repeat.timestamp = first.timestamp + Duration::from_secs(minutes * 60);
+            router.route(&repeat);
+        }
+
+        assert_eq!(router.pending("pager").len(), 1);
+        assert_eq!(router.pending("log").len(), 3);
+        assert_eq!(router.suppressed_count("pager"), 2);
+        assert_eq!(router.suppressed_count("log"), 0);
+
+        // Once the window has passed the pager hears about it again
+        let mut later = first.clone();
+        later.timestamp = first.timestamp + Duration::from_secs(3600);
+        assert_eq!(router.route(&later), vec!["pager".to_string(), "log".to_string()]);
+    }
+}
@@ -0,0 +1,149 @@
+//! # Audit Storage Module
+//!
+//! Encrypted at-rest storage for audit entries. Each entry is serialized, hashed
+//! and sealed with AES-GCM; queries decrypt and verify entries on the way out.
+
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+use zeroize::Zeroize;
+use crate::crypto::CryptoEngine;
+use super::events::{AuditEntry, AuditError, AuditQuery, AuditSystem};
+
+/// Sealed audit entry as held at rest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealedAuditRecord {
+    pub entry_id: String,
+    pub timestamp: SystemTime,
+    pub ciphertext: Vec<u8>,
+    pub integrity_hash: String, // SHA-256 of the plaintext serialization, hex encoded
+}
+
+/// Audit store that only ever holds encrypted entries
+pub struct EncryptedAuditStore {
+    key: Option<[u8; 32]>,
+    records: Vec<SealedAuditRecord>,
+}
+
+impl EncryptedAuditStore {
+    /// Create a store sealed with `key`
+    pub fn new(key: [u8; 32]) -> Self {
+        Self { key: Some(key), records: Vec::new() }
+    }
+
+    /// Create a store with a fresh key drawn from the crypto engine
+    pub fn with_generated_key(crypto: &mut CryptoEngine) -> Self {
+        Self::new(crypto.next_session_key())
+    }
+
+    /// Forget the key; reads fail until `unlock` is called
+    pub fn lock(&mut self) {
+        if let Some(key) = self.key.as_mut() {
+            key.zeroize();
+        }
+        self.key = None;
+    }
+
+    /// Restore the key after `lock`
+    pub fn unlock(&mut self, key: [u8; 32]) {
+        self.key = Some(key);
+    }
+
+    /// Seal and append an entry
+    pub fn store(&mut self, entry: &AuditEntry) -> Result<(), AuditError> {
+        let key = self.key.as_ref().ok_or(AuditError::StorageKeyUnavailable)?;
+
+        let plaintext = serde_json::to_vec(entry)
+            .map_err(|e| AuditError::StorageError(e.to_string()))?;
+        let integrity_hash = hex::encode(CryptoEngine::generate_device_fingerprint(&plaintext));
+        let ciphertext = CryptoEngine::encrypt_data(key, &plaintext)
+            .map_err(|e| AuditError::StorageError(e.to_string()))?;
+
+        self.records.push(SealedAuditRecord {
+            entry_id: entry.entry_id.clone(),
+            timestamp: entry.timestamp,
+            ciphertext,
+            integrity_hash,
+        });
+        Ok(())
+    }
+
+    /// Decrypt, verify and filter stored entries
+    pub fn query(&self, query: &AuditQuery) -> Result<Vec<AuditEntry>, AuditError> {
+        let key = self.key.as_ref().ok_or(AuditError::StorageKeyUnavailable)?;
+
+        let mut entries = Vec::new();
+        for record in &self.records {
+            let plaintext = CryptoEngine::decrypt_data(key, &record.ciphertext)
+                .map_err(|e| AuditError::StorageError(format!("Entry {}: {}", record.entry_id, e)))?;
+            if hex::encode(CryptoEngine::generate_device_fingerprint(&plaintext)) != record.integrity_hash {
+                return Err(AuditError::StorageError(format!("Entry {} failed integrity check", record.entry_id)));
+            }
+
+            let entry: AuditEntry = serde_json::from_slice(&plaintext)
+                .map_err(|e| AuditError::StorageError(e.to_string()))?;
+            if AuditSystem::matches_query(&entry, query) {
+                entries.push(entry);
+            }
+        }
+
+        if let Some(limit) = query.limit {
+            entries.truncate(limit);
+        }
+        Ok(entries)
+    }
+
+    /// Sealed records as they are held at rest
+    pub fn sealed_records(&self) -> &[SealedAuditRecord] {
+        &self.records
+    }
+}
+
+impl Drop for EncryptedAuditStore {
+    fn drop(&mut self) {
+        self.lock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditEventType, AuditSeverity};
+    use crate::fixtures::system_audit_entry;
+
+    fn entry(operation_name: &str) -> AuditEntry {
+        let mut entry = system_audit_entry(AuditEventType::MissionTransfer, AuditSeverity::Medium);
+        entry.operation.operation_name = operation_name.to_string();
+        entry
+    }
+
+    fn all_entries() -> AuditQuery {
+        AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: vec![],
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn test_entries_sealed_at_rest_and_readable_by_query() {
+        let mut audit_system = AuditSystem::new(100);
+        audit_system.enable_encrypted_storage(EncryptedAuditStore::new([42u8; 32]));
+        audit_system.record_event(entry("classified_route_upload")).unwrap();
+
+        let store = audit_system.encrypted_store().unwrap();
+        let needle = b"classified_route_upload";
+        assert!(store.sealed_records().iter()
+            .all(|record| !record.ciphertext.windows(needle.len()).any(|window| window == needle)));
+
+        let entries = store.query(&all_entries()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].operation.operation_name, "classified_route_upload");
+
+        store.lock();
+        assert!(matches!(store.query(&all_entries()), Err(AuditError::StorageKeyUnavailable)));
+    }
+}
@@ -1,5 +1,5 @@
 use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit, Nonce};
-use rand::RngCore;
+use rand::{CryptoRng, RngCore};
 use x25519_dalek::{EphemeralSecret, PublicKey};
 use ed25519_dalek::{SigningKey, VerifyingKey, Signer, Verifier, Signature};
 use std::time::{Instant, Duration};
@@ -8,6 +8,9 @@ use hkdf::Hkdf;
 use sha2::{Sha256, Digest};
 use hmac::Mac;
 
+#[cfg(all(feature = "deterministic-rng", not(debug_assertions)))]
+compile_error!("the `deterministic-rng` feature is for reproducible tests only and must not be enabled in release builds");
+
 #[cfg(feature = "post-quantum")]
 use crate::post_quantum::{PostQuantumEngine, KyberKEM, DilithiumSign, KyberKeypair, DilithiumKeypair, KyberCiphertextData};
 
@@ -25,6 +28,10 @@ pub enum CryptoError {
     SignatureError,
     #[error("Ed25519 signing error")]
     Ed25519Error,
+    #[error("RNG health check failed: {0}")]
+    RngHealthCheckFailed(String),
+    #[error("Generated key is degenerate")]
+    DegenerateKey,
     #[error("{0}")]
     GenericError(String),
 }
@@ -68,11 +75,24 @@ impl EphemeralKeySession {
     }
 }
 
+/// Bytes drawn by `rng_health_check`: the 20,000-bit FIPS 140-2 sample
+pub const RNG_HEALTH_SAMPLE_BYTES: usize = 2500;
+
+/// Monobit bounds on the number of set bits in the sample (FIPS 140-2)
+const MONOBIT_MIN_ONES: u32 = 9725;
+const MONOBIT_MAX_ONES: u32 = 10275;
+
+/// Randomness source injected for reproducible test runs
+trait InjectedRng: RngCore + CryptoRng + Send + Sync {}
+
+impl<R: RngCore + CryptoRng + Send + Sync> InjectedRng for R {}
+
 pub struct CryptoEngine {
     ecdh_secret: EphemeralSecret,
     ecdh_public: PublicKey,
     ed25519_keypair: SigningKey,
     ed25519_public: VerifyingKey,
+    rng: Option<Box<dyn InjectedRng>>, // None = system randomness
     #[cfg(feature = "post-quantum")]
     pq_engine: Option<PostQuantumEngine>,
 }
@@ -98,11 +118,96 @@ impl CryptoEngine {
             ecdh_public,
             ed25519_keypair,
             ed25519_public,
+            rng: None,
             #[cfg(feature = "post-quantum")]
             pq_engine,
         }
     }
 
+    /// Create an engine whose signing key, nonces and session keys come from the given RNG.
+    /// Only available in tests or with the `deterministic-rng` feature (debug builds only).
+    #[cfg(any(test, feature = "deterministic-rng"))]
+    pub fn with_rng<R: RngCore + CryptoRng + Send + Sync + 'static>(rng: R) -> Self {
+        let mut engine = Self::new();
+        let mut rng: Box<dyn InjectedRng> = Box::new(rng);
+
+        let mut secret_key = [0u8; 32];
+        rng.fill_bytes(&mut secret_key);
+        engine.ed25519_keypair = SigningKey::from_bytes(&secret_key);
+        engine.ed25519_public = engine.ed25519_keypair.verifying_key();
+        secret_key.zeroize();
+
+        engine.rng = Some(rng);
+        engine
+    }
+
+    /// Fill a buffer from the injected RNG, or from system randomness by default
+    fn fill_random(&mut self, buf: &mut [u8]) {
+        match self.rng.as_mut() {
+            Some(rng) => rng.fill_bytes(buf),
+            None => rand::thread_rng().fill_bytes(buf),
+        }
+    }
+
+    /// Generate a nonce from this engine's randomness source
+    pub fn next_nonce(&mut self) -> [u8; 16] {
+        let mut nonce = [0u8; 16];
+        self.fill_random(&mut nonce);
+        nonce
+    }
+
+    /// Generate a session key from this engine's randomness source
+    pub fn next_session_key(&mut self) -> [u8; 32] {
+        let mut key = [0u8; 32];
+        self.fill_random(&mut key);
+        key
+    }
+
+    /// `next_session_key`, rejecting a degenerate key instead of handing it out
+    pub fn next_checked_session_key(&mut self) -> Result<[u8; 32], CryptoError> {
+        let mut key = self.next_session_key();
+        if let Err(error) = Self::check_key_sanity(&key) {
+            key.zeroize();
+            return Err(error);
+        }
+        Ok(key)
+    }
+
+    /// Reject keys no working RNG would plausibly produce (all zero bytes)
+    pub fn check_key_sanity(key: &[u8]) -> Result<(), CryptoError> {
+        if key.iter().all(|byte| *byte == 0) {
+            return Err(CryptoError::DegenerateKey);
+        }
+        Ok(())
+    }
+
+    /// Self-test this engine's randomness source before it is trusted with session keys.
+    ///
+    /// Draws `RNG_HEALTH_SAMPLE_BYTES` and applies the FIPS 140-2 monobit test plus a
+    /// repeated-block test (no 16-byte block may equal the one before it). This catches
+    /// stuck or badly biased generators, not subtle weaknesses.
+    pub fn rng_health_check(&mut self) -> Result<(), CryptoError> {
+        let mut sample = vec![0u8; RNG_HEALTH_SAMPLE_BYTES];
+        self.fill_random(&mut sample);
+
+        let ones: u32 = sample.iter().map(|byte| byte.count_ones()).sum();
+        let repeated_block = sample.chunks_exact(16)
+            .collect::<Vec<_>>()
+            .windows(2)
+            .any(|pair| pair[0] == pair[1]);
+        sample.zeroize();
+
+        if !(MONOBIT_MIN_ONES..=MONOBIT_MAX_ONES).contains(&ones) {
+            return Err(CryptoError::RngHealthCheckFailed(format!(
+                "monobit test counted {} set bits in {}, expected {} to {}",
+                ones, RNG_HEALTH_SAMPLE_BYTES * 8, MONOBIT_MIN_ONES, MONOBIT_MAX_ONES)));
+        }
+        if repeated_block {
+            return Err(CryptoError::RngHealthCheckFailed("consecutive 16-byte blocks repeated".to_string()));
+        }
+        Ok(())
+    }
+
     pub fn ecdh_public_key(&self) -> &[u8] {
         self.ecdh_public.as_bytes()
     }
@@ -142,9 +247,16 @@ impl CryptoEngine {
     }
 
     pub fn encrypt_data(key: &[u8], data: &[u8]) -> Result<Vec<u8>, CryptoError> {
-        let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| CryptoError::InvalidKeyLength)?;
         let nonce_full = Self::generate_nonce();
-        let nonce_bytes = &nonce_full[..12];
+        Self::encrypt_data_with_nonce(key, data, &nonce_full[..12])
+    }
+
+    /// Encrypt with a caller-supplied 12-byte nonce (prepended to the ciphertext)
+    pub fn encrypt_data_with_nonce(key: &[u8], data: &[u8], nonce_bytes: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| CryptoError::InvalidKeyLength)?;
+        if nonce_bytes.len() != 12 {
+            return Err(CryptoError::GenericError("AES-GCM nonce must be 12 bytes".to_string()));
+        }
         let nonce = Nonce::from_slice(nonce_bytes);
 
         let mut ciphertext = cipher.encrypt(nonce, data).map_err(|_| CryptoError::AeadError)?;
@@ -397,3 +509,69 @@ impl CryptoEngine {
         Ok(classical_valid && pq_valid)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_seeded_engines_are_reproducible() {
+        let mut a = CryptoEngine::with_rng(StdRng::seed_from_u64(42));
+        let mut b = CryptoEngine::with_rng(StdRng::seed_from_u64(42));
+
+        assert_eq!(a.next_session_key(), b.next_session_key());
+        assert_eq!(a.next_nonce(), b.next_nonce());
+        assert_eq!(a.ed25519_public_key(), b.ed25519_public_key());
+        assert_eq!(a.sign_data(b"payload").unwrap(), b.sign_data(b"payload").unwrap());
+    }
+
+    /// Generator stuck on one byte value, as a failed hardware RNG might be
+    struct ConstantRng(u8);
+
+    impl RngCore for ConstantRng {
+        fn next_u32(&mut self) -> u32 {
+            u32::from_ne_bytes([self.0; 4])
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            u64::from_ne_bytes([self.0; 8])
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(self.0);
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ConstantRng {}
+
+    #[test]
+    fn test_constant_rng_fails_health_check() {
+        assert!(CryptoEngine::with_rng(StdRng::seed_from_u64(7)).rng_health_check().is_ok());
+
+        // 0x55 has exactly half its bits set, so only the repeated-block test catches it
+        for byte in [0x00, 0xff, 0x55] {
+            let mut engine = CryptoEngine::with_rng(ConstantRng(byte));
+            assert!(matches!(engine.rng_health_check(), Err(CryptoError::RngHealthCheckFailed(_))), "byte {:#04x}", byte);
+        }
+        assert!(matches!(CryptoEngine::with_rng(ConstantRng(0)).next_checked_session_key(), Err(CryptoError::DegenerateKey)));
+    }
+
+    #[test]
+    fn test_engine_with_injected_rng_is_shareable_across_tasks() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        assert_send_sync(&CryptoEngine::with_rng(StdRng::seed_from_u64(7)));
+    }
+
+    #[test]
+    fn test_default_engine_uses_system_randomness() {
+        let mut engine = CryptoEngine::new();
+        assert!(engine.rng.is_none());
+        assert_ne!(engine.next_session_key(), engine.next_session_key());
+    }
+}
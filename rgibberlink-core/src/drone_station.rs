@@ -6,10 +6,11 @@
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, Duration};
 use std::collections::HashMap;
-use crate::mission::{MissionPayload, MissionId, GeoCoordinate, MissionPriority};
-use crate::mission_transfer::{MissionTransferError, EncryptedMissionPayload};
-use crate::weather::{WeatherManager, WeatherData, ConstraintValidationResult};
-use crate::security::{SecurityManager, AuthorizationScope, PermissionGrant};
+use crate::mission::{MissionPayload, MissionId, GeoCoordinate, MissionPriority, NavigationCapabilities, RoleCapabilities};
+use crate::mission_transfer::{ApprovalWorkflow, MissionOperatorInterface, MissionTransferError, TransferReasonCode, EncryptedMissionPayload, MISSION_SCHEMA_VERSION};
+use crate::weather::{WeatherManager, WeatherData, ConstraintValidationResult, BatteryDeratingCurve};
+use crate::mission::AuthorizationScope;
+use crate::security::{SecurityManager, PermissionGrant};
 
 /// Drone operational states
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -51,13 +52,87 @@ pub struct DroneInterface {
     pub current_state: DroneOperationalState,
     pub location: Option<GeoCoordinate>,
     pub battery_soc: f32, // State of charge (0.0-1.0)
+    pub heading_deg: f32,
+    pub ground_speed_mps: f32,
     pub communication_status: CommunicationStatus,
     pub active_mission: Option<MissionId>,
+    pub preflight_checklist: PreFlightChecklist,
+    pub firmware_version: Option<String>,
     pub last_update: SystemTime,
 }
 
+/// Individual pre-flight checks
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum PreflightCheck {
+    BatterySoc,
+    GpsFix,
+    SensorSelfTest,
+    WeatherAcceptable,
+    MissionLoaded,
+    CommsSignal,
+    Endurance, // Temperature-derated flight time covers the mission
+}
+
+/// Check included in a pre-flight checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistEntry {
+    pub check: PreflightCheck,
+    pub mandatory: bool, // Failing a mandatory check blocks ReadyForMission
+}
+
+/// Checks run while the drone is in `PreFlightChecks`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreFlightChecklist {
+    pub entries: Vec<ChecklistEntry>,
+}
+
+impl Default for PreFlightChecklist {
+    fn default() -> Self {
+        let mandatory = |check| ChecklistEntry { check, mandatory: true };
+        Self {
+            entries: vec![
+                mandatory(PreflightCheck::BatterySoc),
+                mandatory(PreflightCheck::GpsFix),
+                mandatory(PreflightCheck::SensorSelfTest),
+                mandatory(PreflightCheck::WeatherAcceptable),
+                mandatory(PreflightCheck::MissionLoaded),
+                mandatory(PreflightCheck::CommsSignal),
+                mandatory(PreflightCheck::Endurance),
+            ],
+        }
+    }
+}
+
+/// Outcome of a single checklist item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckItem {
+    pub check: PreflightCheck,
+    pub mandatory: bool,
+    pub passed: bool,
+    pub message: String,
+}
+
+/// Outcome of a full pre-flight run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreflightResult {
+    pub passed: bool, // All mandatory checks passed
+    pub items: Vec<CheckItem>,
+}
+
+/// Point-in-time drone telemetry consumed by abort, geofence and progress logic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroneTelemetry {
+    pub timestamp: SystemTime,
+    pub position: Option<GeoCoordinate>, // None until the drone reports a position fix
+    pub battery_soc: f32,
+    pub heading_deg: f32,
+    pub ground_speed_mps: f32,
+    pub last_contact: SystemTime,
+    pub signal_strength: f32,
+    pub state: DroneOperationalState,
+}
+
 /// Station interface schema
-#[derive(Debug, Clone)]
 pub struct StationInterface {
     pub station_id: String,
     pub location: GeoCoordinate,
@@ -82,6 +157,81 @@ pub struct DroneCapabilities {
     pub communication_channels: Vec<CommunicationChannel>,
     pub weather_limits: WeatherLimits,
     pub emergency_features: Vec<String>,
+    #[serde(default)]
+    pub battery_derating: BatteryDeratingCurve, // Usable capacity vs. battery temperature
+    #[serde(default)]
+    pub navigation: NavigationCapabilities,
+}
+
+/// Extra power drawn at full rated payload, as a fraction of empty cruise power
+pub const PAYLOAD_POWER_FACTOR: f32 = 0.8;
+
+/// Achievable range for one payload/speed combination
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RangePoint {
+    pub payload_kg: f32,
+    pub speed_mps: f32,         // Ground speed
+    pub endurance_minutes: f32,
+    pub range_km: f32,
+    pub feasible: bool,         // Payload within rating and speed achievable against the wind
+}
+
+impl DroneCapabilities {
+    /// The facts `FormationConfiguration::validate_roles` checks a role assignment against
+    pub fn role_capabilities(&self) -> RoleCapabilities {
+        RoleCapabilities {
+            max_payload_kg: self.max_payload_kg,
+            relay_capable: self.communication_channels.iter().any(|channel| !matches!(channel, CommunicationChannel::GibberLinkShortRange)),
+            sensor_count: self.supported_sensors.len(),
+        }
+    }
+
+    /// Cruise speed at which the rated range is flown in the rated flight time
+    pub fn best_range_speed_mps(&self) -> f32 {
+        self.max_range_km * 1000.0 / (self.max_flight_time_minutes.max(1) as f32 * 60.0)
+    }
+
+    /// Achievable range for every payload/speed combination in still air
+    pub fn range_envelope(&self, payloads_kg: &[f32], speeds_mps: &[f32]) -> Vec<RangePoint> {
+        self.range_envelope_in_weather(payloads_kg, speeds_mps, None)
+    }
+
+    /// Achievable range for every payload/speed combination, flying into a direct
+    /// headwind and with temperature-derated capacity when `weather` is given
+    ///
+    /// Cruise power relative to the rated point is `2/3 + (v/v_best)^3 / 3` (hover-like
+    /// plus parasitic drag, least energy per metre at `v_best`), scaled up linearly with
+    /// payload by `PAYLOAD_POWER_FACTOR` at the rated payload.
+    pub fn range_envelope_in_weather(&self, payloads_kg: &[f32], speeds_mps: &[f32], weather: Option<&WeatherData>) -> Vec<RangePoint> {
+        let best_speed = self.best_range_speed_mps();
+        let headwind = weather.map_or(0.0, |weather| weather.wind_speed_mps);
+        let derating = weather.map_or(1.0, |weather| self.battery_derating.capacity_multiplier(weather.temperature_celsius));
+        let rated_minutes = self.max_flight_time_minutes as f32 * derating;
+
+        payloads_kg.iter()
+            .flat_map(|&payload_kg| speeds_mps.iter().map(move |&speed_mps| (payload_kg, speed_mps)))
+            .map(|(payload_kg, speed_mps)| {
+                let airspeed = speed_mps + headwind;
+                let feasible = payload_kg <= self.max_payload_kg && speed_mps > 0.0 && best_speed > 0.0;
+                if !feasible {
+                    return RangePoint { payload_kg, speed_mps, endurance_minutes: 0.0, range_km: 0.0, feasible };
+                }
+
+                let speed_ratio = airspeed / best_speed;
+                let speed_factor = 2.0 / 3.0 + speed_ratio.powi(3) / 3.0;
+                let payload_factor = 1.0 + PAYLOAD_POWER_FACTOR * payload_kg.max(0.0) / self.max_payload_kg.max(f32::EPSILON);
+                let endurance_minutes = rated_minutes / (speed_factor * payload_factor);
+
+                RangePoint {
+                    payload_kg,
+                    speed_mps,
+                    endurance_minutes,
+                    range_km: speed_mps * endurance_minutes * 60.0 / 1000.0,
+                    feasible,
+                }
+            })
+            .collect()
+    }
 }
 
 /// Station capabilities specification
@@ -138,6 +288,33 @@ pub struct CommunicationStatus {
     pub error_rate: f32,
 }
 
+impl CommunicationStatus {
+    /// Nominal rate of the channel at full signal. `channel_type` is a `CommunicationChannel`
+    /// name or a raw link name ("laser", "ultrasonic"); unknown channels carry nothing.
+    pub fn nominal_rate_bps(&self) -> f64 {
+        let channel: String = self.channel_type.to_lowercase().chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        match channel.as_str() {
+            "laser" | "gibberlinklongrange" => 2_000_000.0,
+            "ultrasonic" | "gibberlinkshortrange" => 16_000.0,
+            "satellite" => 500_000.0,
+            "cellular4g" => 20_000_000.0,
+            "cellular5g" => 100_000_000.0,
+            "wifidirect" => 50_000_000.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Derive `data_rate_bps` and `error_rate` from signal strength using the performance
+    /// monitor's link curves, so the three fields stay consistent
+    pub fn adapt_rate(&mut self) {
+        let signal = self.signal_strength as f64;
+        self.data_rate_bps = crate::performance_monitor::adapted_throughput_bps(self.nominal_rate_bps(), signal) as u32;
+        self.error_rate = crate::performance_monitor::error_rate_for_signal(signal) as f32;
+    }
+}
+
 /// Active session information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionInfo {
@@ -180,6 +357,77 @@ pub struct HumanOperatorInterface {
     pub active_sessions: Vec<String>,
     pub validation_history: Vec<OperatorValidationRecord>,
     pub current_location: Option<GeoCoordinate>,
+    pub scope_policy: MissionScopePolicy,
+}
+
+/// Mission characteristic that triggers a scope requirement
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MissionCharacteristic {
+    Formation,
+    Priority(MissionPriority),
+    HazardousPayload,
+}
+
+/// Scopes an operator must hold to approve missions with a characteristic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScopeRequirement {
+    pub characteristic: MissionCharacteristic,
+    pub scopes: Vec<AuthorizationScope>,
+}
+
+/// Policy table of mandatory authorization scopes per mission kind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionScopePolicy {
+    pub requirements: Vec<ScopeRequirement>,
+}
+
+impl Default for MissionScopePolicy {
+    fn default() -> Self {
+        Self {
+            requirements: vec![
+                ScopeRequirement {
+                    characteristic: MissionCharacteristic::Formation,
+                    scopes: vec![AuthorizationScope::FleetManagement],
+                },
+                ScopeRequirement {
+                    characteristic: MissionCharacteristic::Priority(MissionPriority::Emergency),
+                    scopes: vec![AuthorizationScope::EmergencyOverride],
+                },
+                ScopeRequirement {
+                    characteristic: MissionCharacteristic::HazardousPayload,
+                    scopes: vec![AuthorizationScope::FleetManagement, AuthorizationScope::EmergencyOverride],
+                },
+            ],
+        }
+    }
+}
+
+impl MissionScopePolicy {
+    /// Whether a mission has the given characteristic
+    fn applies(characteristic: &MissionCharacteristic, mission: &MissionPayload) -> bool {
+        match characteristic {
+            MissionCharacteristic::Formation => mission.formation_config.is_some(),
+            MissionCharacteristic::Priority(priority) => mission.header.priority == *priority,
+            MissionCharacteristic::HazardousPayload => mission.formation_config.as_ref().map_or(false, |formation| {
+                matches!(formation.payload_config.payload_type, crate::mission::PayloadType::Hazardous { .. })
+            }),
+        }
+    }
+
+    /// Mandatory scopes for a mission, without duplicates
+    pub fn required_scopes(&self, mission: &MissionPayload) -> Vec<AuthorizationScope> {
+        let mut scopes: Vec<AuthorizationScope> = Vec::new();
+        for requirement in &self.requirements {
+            if Self::applies(&requirement.characteristic, mission) {
+                for scope in &requirement.scopes {
+                    if !scopes.contains(scope) {
+                        scopes.push(scope.clone());
+                    }
+                }
+            }
+        }
+        scopes
+    }
 }
 
 /// Security clearance levels
@@ -245,6 +493,7 @@ pub struct FleetManager {
     pub mission_queue: Vec<MissionQueueItem>,
     pub global_weather_manager: WeatherManager,
     pub security_policies: FleetSecurityPolicies,
+    pub preemption_history: Vec<PreemptionRecord>,
 }
 
 /// Mission assignment for fleet management
@@ -258,6 +507,25 @@ pub struct MissionAssignment {
     pub expected_completion: SystemTime,
     pub status: AssignmentStatus,
     pub progress_percent: f32,
+    pub priority: MissionPriority,
+}
+
+impl MissionAssignment {
+    /// Compare `now` against `expected_completion` plus the grace `policy` allows
+    pub fn deadline_status(&self, now: SystemTime, policy: &TimeoutGracePolicy) -> DeadlineStatus {
+        let overrun = match now.duration_since(self.expected_completion) {
+            Ok(overrun) => overrun,
+            Err(_) => return DeadlineStatus::OnTime,
+        };
+        let planned = self.expected_completion.duration_since(self.assignment_time).unwrap_or(Duration::ZERO);
+        let grace = policy.grace_for(&self.priority, planned);
+
+        if overrun > grace {
+            DeadlineStatus::TimedOut { overrun }
+        } else {
+            DeadlineStatus::Overdue { grace_remaining: grace - overrun }
+        }
+    }
 }
 
 /// Mission assignment status
@@ -301,6 +569,75 @@ pub struct FleetSecurityPolicies {
     pub audit_all_operations: bool,
     pub log_security_events: bool,
     pub session_timeout_minutes: u32,
+    pub preemption_action: PreemptionAction,
+    #[serde(default)]
+    pub timeout_grace: TimeoutGracePolicy,
+}
+
+/// How long an assignment may overrun `expected_completion` before it is timed out
+///
+/// Grace is a fraction of the planned duration, so long missions get proportionally
+/// more slack. Higher priorities get more, as aborting them costs more.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeoutGracePolicy {
+    pub default_grace_fraction: f32,
+    pub priority_grace_fraction: Vec<(MissionPriority, f32)>, // Replaces the default for a priority
+}
+
+impl Default for TimeoutGracePolicy {
+    fn default() -> Self {
+        Self {
+            default_grace_fraction: 0.10,
+            priority_grace_fraction: vec![
+                (MissionPriority::Low, 0.05),
+                (MissionPriority::High, 0.15),
+                (MissionPriority::Critical, 0.20),
+                (MissionPriority::Emergency, 0.25),
+            ],
+        }
+    }
+}
+
+impl TimeoutGracePolicy {
+    /// No grace at all: time out as soon as the deadline passes
+    pub fn none() -> Self {
+        Self { default_grace_fraction: 0.0, priority_grace_fraction: Vec::new() }
+    }
+
+    /// Grace allowed past the deadline for a mission of `priority` planned to take `planned`
+    pub fn grace_for(&self, priority: &MissionPriority, planned: Duration) -> Duration {
+        let fraction = self.priority_grace_fraction.iter()
+            .find(|(p, _)| p == priority)
+            .map_or(self.default_grace_fraction, |(_, fraction)| *fraction);
+        planned.mul_f32(fraction.max(0.0))
+    }
+}
+
+/// Where an assignment stands against its deadline
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeadlineStatus {
+    OnTime,
+    Overdue { grace_remaining: Duration }, // Past the deadline, still within grace
+    TimedOut { overrun: Duration },
+}
+
+/// What happens to an assignment displaced by a higher-priority mission
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum PreemptionAction {
+    Pause, // Preempted mission can be resumed later
+    Abort, // Preempted mission is terminated
+}
+
+/// Audit record of a mission preemption
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreemptionRecord {
+    pub timestamp: SystemTime,
+    pub preempted_mission: MissionId,
+    pub preempted_priority: MissionPriority,
+    pub preempting_mission: MissionId,
+    pub preempting_priority: MissionPriority,
+    pub drone_id: String,
+    pub action: PreemptionAction,
 }
 
 impl DroneInterface {
@@ -313,6 +650,8 @@ impl DroneInterface {
             current_state: DroneOperationalState::Idle,
             location: None,
             battery_soc: 0.0,
+            heading_deg: 0.0,
+            ground_speed_mps: 0.0,
             communication_status: CommunicationStatus {
                 signal_strength: 0.0,
                 channel_type: "none".to_string(),
@@ -321,10 +660,108 @@ impl DroneInterface {
                 error_rate: 0.0,
             },
             active_mission: None,
+            preflight_checklist: PreFlightChecklist::default(),
+            firmware_version: None,
             last_update: SystemTime::now(),
         }
     }
 
+    /// Run the pre-flight checklist against a mission and current weather
+    pub fn run_preflight(&self, mission: &MissionPayload, weather: &WeatherData) -> PreflightResult {
+        let items: Vec<CheckItem> = self.preflight_checklist.entries.iter()
+            .map(|entry| {
+                let (passed, message) = self.evaluate_preflight_check(entry.check, mission, weather);
+                CheckItem { check: entry.check, mandatory: entry.mandatory, passed, message }
+            })
+            .collect();
+
+        let passed = items.iter().all(|item| item.passed || !item.mandatory);
+        PreflightResult { passed, items }
+    }
+
+    /// Enter `PreFlightChecks` and advance to `ReadyForMission` only if every mandatory check passes
+    ///
+    /// A drone that is flying, needs maintenance or is in error cannot start pre-flight: the
+    /// checks still run against its current state, but the result fails and the state is kept.
+    pub fn complete_preflight(&mut self, mission: &MissionPayload, weather: &WeatherData) -> PreflightResult {
+        if !self.can_start_preflight() {
+            let mut result = self.run_preflight(mission, weather);
+            result.passed = false;
+            return result;
+        }
+        self.update_state(DroneOperationalState::PreFlightChecks);
+
+        let result = self.run_preflight(mission, weather);
+        if result.passed {
+            self.update_state(DroneOperationalState::ReadyForMission);
+        }
+        result
+    }
+
+    /// Whether the current state allows entering `PreFlightChecks`
+    fn can_start_preflight(&self) -> bool {
+        matches!(self.current_state,
+            DroneOperationalState::Idle
+            | DroneOperationalState::PreFlightChecks
+            | DroneOperationalState::ReadyForMission
+            | DroneOperationalState::PostMission)
+    }
+
+    fn evaluate_preflight_check(&self, check: PreflightCheck, mission: &MissionPayload, weather: &WeatherData) -> (bool, String) {
+        match check {
+            PreflightCheck::BatterySoc => {
+                let required = mission.constraints.energy.min_soc_start;
+                if self.battery_soc >= required {
+                    (true, format!("Battery at {:.0}%", self.battery_soc * 100.0))
+                } else {
+                    (false, format!("Battery at {:.0}% is below required {:.0}%", self.battery_soc * 100.0, required * 100.0))
+                }
+            }
+            PreflightCheck::GpsFix => match &self.location {
+                Some(_) => (true, "Position fix available".to_string()),
+                None => (false, "No position fix".to_string()),
+            },
+            PreflightCheck::SensorSelfTest => {
+                if matches!(self.current_state, DroneOperationalState::MaintenanceRequired | DroneOperationalState::Error(_)) {
+                    (false, format!("Sensor self-test blocked by state {:?}", self.current_state))
+                } else {
+                    (true, "Sensor self-test passed".to_string())
+                }
+            }
+            PreflightCheck::WeatherAcceptable => {
+                let limits = &self.capabilities.weather_limits;
+                if weather.wind_speed_mps > limits.max_wind_speed_mps {
+                    (false, format!("Wind {:.1} m/s exceeds limit {:.1} m/s", weather.wind_speed_mps, limits.max_wind_speed_mps))
+                } else if weather.gust_speed_mps > limits.max_gust_speed_mps {
+                    (false, format!("Gusts {:.1} m/s exceed limit {:.1} m/s", weather.gust_speed_mps, limits.max_gust_speed_mps))
+                } else if weather.visibility_meters < limits.min_visibility_m {
+                    (false, format!("Visibility {:.0} m below minimum {:.0} m", weather.visibility_meters, limits.min_visibility_m))
+                } else if weather.temperature_celsius < limits.min_temperature_c || weather.temperature_celsius > limits.max_temperature_c {
+                    (false, format!("Temperature {:.1}°C outside operating range", weather.temperature_celsius))
+                } else if weather.precipitation_rate_mmh > limits.max_precipitation_mmh {
+                    (false, format!("Precipitation {:.1} mm/h exceeds limit", weather.precipitation_rate_mmh))
+                } else {
+                    (true, "Weather within drone limits".to_string())
+                }
+            }
+            PreflightCheck::MissionLoaded => match self.validate_mission_compatibility(mission) {
+                Ok(()) => (true, "Mission loaded and compatible".to_string()),
+                Err(reason) => (false, reason),
+            },
+            PreflightCheck::CommsSignal => {
+                if self.communication_status.signal_strength > 0.5 {
+                    (true, format!("Signal strength {:.2}", self.communication_status.signal_strength))
+                } else {
+                    (false, format!("Signal strength {:.2} too weak", self.communication_status.signal_strength))
+                }
+            }
+            PreflightCheck::Endurance => match self.check_endurance(mission, weather.temperature_celsius) {
+                Ok(()) => (true, format!("Endurance covers the mission at {:.0}°C", weather.temperature_celsius)),
+                Err(reason) => (false, reason),
+            },
+        }
+    }
+
     /// Update drone operational state
     pub fn update_state(&mut self, new_state: DroneOperationalState) {
         println!("Drone {} state change: {:?} -> {:?}", self.drone_id, self.current_state, new_state);
@@ -332,15 +769,60 @@ impl DroneInterface {
         self.last_update = SystemTime::now();
     }
 
+    /// Snapshot of the drone's current telemetry
+    pub fn telemetry(&self) -> DroneTelemetry {
+        DroneTelemetry {
+            timestamp: self.last_update,
+            position: self.location.clone(),
+            battery_soc: self.battery_soc,
+            heading_deg: self.heading_deg,
+            ground_speed_mps: self.ground_speed_mps,
+            last_contact: self.communication_status.last_contact,
+            signal_strength: self.communication_status.signal_strength,
+            state: self.current_state.clone(),
+        }
+    }
+
     /// Check if drone is ready for mission assignment
     pub fn is_ready_for_mission(&self) -> bool {
         matches!(self.current_state, DroneOperationalState::Idle | DroneOperationalState::ReadyForMission) &&
         self.battery_soc > 0.2 && // At least 20% battery
-        self.communication_status.signal_strength > 0.5 // Good signal
+        self.communication_status.signal_strength > 0.5 && // Good signal
+        self.communication_status.error_rate as f64 <= crate::performance_monitor::error_rate_for_signal(0.5) // Link not degraded
+    }
+
+    /// Estimated flight time available from the current charge at the given battery temperature
+    pub fn estimated_endurance_minutes(&self, temperature_c: f32, reserve_soc: f32) -> f32 {
+        let usable_soc = (self.battery_soc - reserve_soc).max(0.0);
+        let derating = self.capabilities.battery_derating.capacity_multiplier(temperature_c);
+        self.capabilities.max_flight_time_minutes as f32 * usable_soc * derating
+    }
+
+    /// Check the mission fits within temperature-derated endurance, keeping the mission's reserve
+    pub fn check_endurance(&self, mission: &MissionPayload, temperature_c: f32) -> Result<(), String> {
+        let required_minutes = mission.header.max_execution_duration.as_secs_f32() / 60.0;
+        let available_minutes = self.estimated_endurance_minutes(temperature_c, mission.constraints.energy.reserve_margin_soc);
+
+        if available_minutes < required_minutes {
+            return Err(format!("Endurance {:.1} min at {}°C is below required {:.1} min",
+                available_minutes, temperature_c, required_minutes));
+        }
+        Ok(())
     }
 
     /// Validate mission compatibility with drone capabilities
     pub fn validate_mission_compatibility(&self, mission: &MissionPayload) -> Result<(), String> {
+        if !mission.has_flyable_flight_plan() {
+            return Err("Mission flight plan is empty or has a path with fewer than two waypoints".to_string());
+        }
+
+        crate::mission_transfer::check_firmware_compatibility(mission, self.firmware_version.as_deref())
+            .map_err(|e| e.to_string())?;
+
+        if let Some(issue) = mission.check_navigation(&self.capabilities.navigation).first() {
+            return Err(issue.to_string());
+        }
+
         // Check battery requirements
         let required_energy = mission.constraints.energy.expected_consumption_wh;
         let available_energy = self.capabilities.weather_limits.max_temperature_c as f32; // Placeholder calculation
@@ -402,7 +884,7 @@ impl StationInterface {
     pub async fn prepare_mission_for_drone(&mut self, mission: MissionPayload, drone: &DroneInterface) -> Result<EncryptedMissionPayload, MissionTransferError> {
         // Validate drone compatibility
         drone.validate_mission_compatibility(&mission)
-            .map_err(|e| MissionTransferError::MissionIntegrityError(e))?;
+            .map_err(|e| MissionTransferError::MissionIntegrityError(TransferReasonCode::DroneIncompatible, e))?;
 
         // Update weather data if available
         if let Some(weather) = self.get_current_weather().await {
@@ -421,12 +903,13 @@ impl StationInterface {
                 mass_kg: 2.5,
                 battery_capacity_wh: 100.0,
                 sensor_types: drone.capabilities.supported_sensors.iter().map(|s| s.sensor_type.clone()).collect(),
+                battery_derating: drone.capabilities.battery_derating.clone(),
             }
         );
 
         if let Ok(validation) = weather_ok {
             if !validation.is_valid && validation.risk_assessment.abort_recommended {
-                return Err(MissionTransferError::MissionIntegrityError("Weather conditions unsafe for mission".to_string()));
+                return Err(MissionTransferError::MissionIntegrityError(TransferReasonCode::UnsafeWeather, "Weather conditions unsafe for mission".to_string()));
             }
         }
 
@@ -440,7 +923,13 @@ impl StationInterface {
             signature: vec![4, 5, 6],
             session_nonce: [7; 16],
             validity_timestamp: SystemTime::now() + Duration::from_secs(300),
+            validity_window: Duration::from_secs(300),
+            binding_window: Duration::from_millis(100),
             weather_fingerprint: [8; 32],
+            cleartext_signature: Vec::new(),
+            cleartext_header: None,
+            header_signature: Vec::new(),
+            schema_version: MISSION_SCHEMA_VERSION,
         })
     }
 
@@ -481,31 +970,51 @@ impl HumanOperatorInterface {
             active_sessions: Vec::new(),
             validation_history: Vec::new(),
             current_location: None,
+            scope_policy: MissionScopePolicy::default(),
         }
     }
 
-    /// Check if operator has required clearance for mission
+    /// Check if operator has required clearance for mission, including the
+    /// scopes the policy mandates for this kind of mission
     pub fn has_clearance_for_mission(&self, mission: &MissionPayload, required_scopes: &[AuthorizationScope]) -> bool {
-        // Check clearance level matches mission priority
-        let required_clearance = match mission.header.priority {
-            MissionPriority::Low | MissionPriority::Normal => SecurityClearance::Basic,
-            MissionPriority::High => SecurityClearance::Standard,
-            MissionPriority::Critical => SecurityClearance::Advanced,
-            MissionPriority::Emergency => SecurityClearance::Emergency,
-        };
-
-        if self.clearance_level < required_clearance {
+        if self.clearance_level < Self::required_clearance(&mission.header.priority) {
             return false;
         }
 
-        // Check authorized scopes
-        for scope in required_scopes {
-            if !self.authorized_scopes.contains(scope) {
-                return false;
-            }
+        // Check requested and policy-mandated scopes
+        let mandatory = self.scope_policy.required_scopes(mission);
+        required_scopes.iter()
+            .chain(mandatory.iter())
+            .all(|scope| self.authorized_scopes.contains(scope))
+    }
+
+    /// Start this operator's approval of a mission requesting `requested_scopes`
+    ///
+    /// The operator needs the clearance for the mission's priority to begin; the workflow
+    /// puts the scopes the operator interface's policy mandates on the preview and refuses
+    /// approval unless this operator holds all of them.
+    pub fn begin_approval(
+        &self,
+        operators: &mut MissionOperatorInterface,
+        mission: &MissionPayload,
+        requested_scopes: Vec<AuthorizationScope>,
+        station_fingerprint: [u8; 32],
+    ) -> Result<ApprovalWorkflow, MissionTransferError> {
+        if self.clearance_level < Self::required_clearance(&mission.header.priority) {
+            return Err(MissionTransferError::InsufficientClearance(mission.header.priority.clone()));
         }
+        Ok(operators.begin_approval(mission, requested_scopes, station_fingerprint, &self.operator_id)
+            .with_operator_scopes(self.authorized_scopes.clone()))
+    }
 
-        true
+    /// Minimum clearance to approve a mission of the given priority
+    fn required_clearance(priority: &MissionPriority) -> SecurityClearance {
+        match priority {
+            MissionPriority::Low | MissionPriority::Normal => SecurityClearance::Basic,
+            MissionPriority::High => SecurityClearance::Standard,
+            MissionPriority::Critical => SecurityClearance::Advanced,
+            MissionPriority::Emergency => SecurityClearance::Emergency,
+        }
     }
 
     /// Record validation action for audit trail
@@ -535,51 +1044,140 @@ impl FleetManager {
                 audit_all_operations: true,
                 log_security_events: true,
                 session_timeout_minutes: 60,
+                preemption_action: PreemptionAction::Pause,
+                timeout_grace: TimeoutGracePolicy::default(),
             },
+            preemption_history: Vec::new(),
         }
     }
 
     /// Assign mission to optimal drone and station
-    pub fn assign_mission(&mut self, mission: MissionPayload) -> Result<String, String> {
+    pub async fn assign_mission(&mut self, mission: MissionPayload) -> Result<String, String> {
         // Find suitable station
         let suitable_station = self.find_suitable_station(&mission)?;
-        let station = self.station_interfaces.get_mut(&suitable_station)
-            .ok_or("Selected station not found")?;
 
         // Find suitable drone connected to that station
         let suitable_drone = self.find_suitable_drone(&mission, &suitable_station)?;
+        let station = self.station_interfaces.get_mut(&suitable_station)
+            .ok_or("Selected station not found")?;
         let drone = self.drone_fleet.get(&suitable_drone)
             .ok_or("Selected drone not found")?;
 
         // Validate mission against drone capabilities and weather
-        station.prepare_mission_for_drone(mission.clone(), drone)
+        station.prepare_mission_for_drone(mission.clone(), drone).await
             .map_err(|e| format!("Mission preparation failed: {:?}", e))?;
 
-        // Create assignment
+        self.record_assignment(mission, &suitable_station, &suitable_drone);
+
+        Ok(format!("Mission assigned: Station={}, Drone={}", suitable_station, suitable_drone))
+    }
+
+    /// Assign mission, preempting a lower-priority assignment if every suitable drone is busy
+    ///
+    /// Preemption only frees a drone; any other reason the mission cannot be assigned is
+    /// returned as-is. The freed drone must pass mission preparation and the safety
+    /// validation before the victim is paused or aborted.
+    pub async fn assign_with_preemption(&mut self, mission: MissionPayload) -> Result<String, String> {
+        let has_free_drone = self.drone_fleet.iter().any(|(drone_id, drone)| {
+            !self.is_drone_busy(drone_id) && drone.is_ready_for_mission() && drone.validate_mission_compatibility(&mission).is_ok()
+        });
+        if has_free_drone {
+            return self.assign_mission(mission).await;
+        }
+
+        // Only High and above may displace other missions
+        let incoming_priority = mission.header.priority.clone();
+        if incoming_priority < MissionPriority::High {
+            return Err("No suitable drone found; all compatible drones are busy".to_string());
+        }
+
+        // Pick the lowest-priority active assignment whose drone can fly this mission
+        let victim = self.active_missions.values()
+            .filter(|a| matches!(a.status, AssignmentStatus::Scheduled | AssignmentStatus::InProgress))
+            .filter(|a| a.priority < incoming_priority)
+            .filter(|a| self.drone_fleet.get(&a.assigned_drone)
+                .map(|d| d.validate_mission_compatibility(&mission).is_ok())
+                .unwrap_or(false))
+            .min_by(|a, b| a.priority.partial_cmp(&b.priority).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|a| a.mission_id);
+
+        let victim_id = victim.ok_or("No suitable drone found; no lower-priority mission to preempt")?;
+        let action = self.security_policies.preemption_action.clone();
+        let (drone_id, station_id) = self.active_missions.get(&victim_id)
+            .map(|victim| (victim.assigned_drone.clone(), victim.assigned_station.clone()))
+            .ok_or("Preempted mission not found")?;
+
+        // Validate mission against the freed drone before touching the victim
+        let station = self.station_interfaces.get_mut(&station_id)
+            .ok_or("Preempted mission's station not found")?;
+        let drone = self.drone_fleet.get(&drone_id)
+            .ok_or("Preempted mission's drone not found")?;
+        station.prepare_mission_for_drone(mission.clone(), drone).await
+            .map_err(|e| format!("Mission preparation failed: {:?}", e))?;
+        if let Some(weather) = station.weather_manager.get_current_weather() {
+            let safety = validate_mission_safety(&mission, weather, &drone.capabilities);
+            if !safety.is_safe {
+                return Err(format!("Mission failed safety validation: {}", safety.risk_assessment));
+            }
+        }
+
+        let victim = self.active_missions.get_mut(&victim_id)
+            .ok_or("Preempted mission not found")?;
+        victim.status = match action {
+            PreemptionAction::Pause => AssignmentStatus::Paused,
+            PreemptionAction::Abort => AssignmentStatus::Aborted,
+        };
+
+        self.preemption_history.push(PreemptionRecord {
+            timestamp: SystemTime::now(),
+            preempted_mission: victim_id,
+            preempted_priority: victim.priority.clone(),
+            preempting_mission: mission.header.id,
+            preempting_priority: incoming_priority,
+            drone_id: drone_id.clone(),
+            action,
+        });
+
+        self.record_assignment(mission, &station_id, &drone_id);
+
+        Ok(format!("Mission assigned by preemption: Station={}, Drone={}", station_id, drone_id))
+    }
+
+    /// Whether the drone is committed to a scheduled or in-progress assignment
+    fn is_drone_busy(&self, drone_id: &str) -> bool {
+        self.active_missions.values().any(|assignment| {
+            assignment.assigned_drone == drone_id
+                && matches!(assignment.status, AssignmentStatus::Scheduled | AssignmentStatus::InProgress)
+        })
+    }
+
+    /// Record a new assignment and add the mission to the station inventory
+    fn record_assignment(&mut self, mission: MissionPayload, station_id: &str, drone_id: &str) {
         let assignment = MissionAssignment {
             mission_id: mission.header.id,
-            assigned_drone: suitable_drone.clone(),
-            assigned_station: suitable_station.clone(),
+            assigned_drone: drone_id.to_string(),
+            assigned_station: station_id.to_string(),
             operator_id: None,
             assignment_time: SystemTime::now(),
             expected_completion: SystemTime::now() + mission.header.max_execution_duration,
             status: AssignmentStatus::Scheduled,
             progress_percent: 0.0,
+            priority: mission.header.priority.clone(),
         };
 
         self.active_missions.insert(mission.header.id, assignment);
 
         // Update station mission inventory
-        station.mission_inventory.insert(mission.header.id, MissionInventoryItem {
-            mission,
-            creator_id: "fleet_manager".to_string(),
-            approval_status: ApprovalStatus::Approved,
-            weather_validation: None, // Would be filled from actual validation
-            created_time: SystemTime::now(),
-            expires_time: Some(SystemTime::now() + Duration::from_secs(3600)), // 1 hour
-        });
-
-        Ok(format!("Mission assigned: Station={}, Drone={}", suitable_station, suitable_drone))
+        if let Some(station) = self.station_interfaces.get_mut(station_id) {
+            station.mission_inventory.insert(mission.header.id, MissionInventoryItem {
+                mission,
+                creator_id: "fleet_manager".to_string(),
+                approval_status: ApprovalStatus::Approved,
+                weather_validation: None, // Would be filled from actual validation
+                created_time: SystemTime::now(),
+                expires_time: Some(SystemTime::now() + Duration::from_secs(3600)), // 1 hour
+            });
+        }
     }
 
     /// Find suitable station for mission
@@ -621,16 +1219,29 @@ impl FleetManager {
 
     /// Monitor active missions and handle failures
     pub async fn monitor_missions(&mut self) -> Vec<String> {
+        self.monitor_missions_at(SystemTime::now())
+    }
+
+    /// Monitor active missions as of `now`
+    ///
+    /// A mission past its deadline is warned about while within its grace period and
+    /// only timed out once the grace period has also run out.
+    pub fn monitor_missions_at(&mut self, now: SystemTime) -> Vec<String> {
         let mut events = Vec::new();
 
         // Check for mission timeouts
         let mut completed_missions = Vec::new();
         for (mission_id, assignment) in &self.active_missions {
             if assignment.status == AssignmentStatus::InProgress {
-                let elapsed = assignment.assignment_time.elapsed().unwrap_or(Duration::from_secs(0));
-                if elapsed > assignment.expected_completion.duration_since(assignment.assignment_time).unwrap_or(Duration::from_secs(3600)) {
-                    events.push(format!("Mission {} timed out", mission_id));
-                    // Would trigger abort procedure
+                match assignment.deadline_status(now, &self.security_policies.timeout_grace) {
+                    DeadlineStatus::OnTime => {}
+                    DeadlineStatus::Overdue { grace_remaining } => {
+                        events.push(format!("Mission {:?} overdue, {}s of grace remaining", mission_id, grace_remaining.as_secs()));
+                    }
+                    DeadlineStatus::TimedOut { overrun } => {
+                        events.push(format!("Mission {:?} timed out {}s past its deadline", mission_id, overrun.as_secs()));
+                        // Would trigger abort procedure
+                    }
                 }
             } else if matches!(assignment.status, AssignmentStatus::Completed | AssignmentStatus::Failed | AssignmentStatus::Aborted) {
                 completed_missions.push(*mission_id);
@@ -670,11 +1281,71 @@ pub struct SafetyCheck {
 /// Violation severity levels (re-export from weather module for convenience)
 pub use crate::weather::ViolationSeverity;
 
-/// Automated safety validation for mission authorization
+/// Minimum battery reserve a mission must plan to keep, by risk profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnergyReservePolicy {
+    pub default_min_reserve_soc: f32, // 0.0-1.0
+    pub priority_min_reserve_soc: Vec<(MissionPriority, f32)>, // Replaces the default for a priority
+    pub emergency_override_floor_soc: Option<f32>, // Lower floor for Emergency missions; set only under explicit override
+}
+
+impl Default for EnergyReservePolicy {
+    fn default() -> Self {
+        Self {
+            default_min_reserve_soc: 0.15,
+            priority_min_reserve_soc: Vec::new(),
+            emergency_override_floor_soc: None,
+        }
+    }
+}
+
+impl EnergyReservePolicy {
+    /// Same minimum reserve for every mission
+    pub fn uniform(min_reserve_soc: f32) -> Self {
+        Self { default_min_reserve_soc: min_reserve_soc, ..Default::default() }
+    }
+
+    /// Minimum reserve that applies to a mission
+    pub fn min_reserve_for(&self, mission: &MissionPayload) -> f32 {
+        let priority = &mission.header.priority;
+        let configured = self.priority_min_reserve_soc.iter()
+            .find(|(p, _)| p == priority)
+            .map_or(self.default_min_reserve_soc, |(_, reserve)| *reserve);
+
+        match (priority, self.emergency_override_floor_soc) {
+            (MissionPriority::Emergency, Some(floor)) => floor.min(configured),
+            _ => configured,
+        }
+    }
+}
+
+/// Automated safety validation for mission authorization under the default reserve policy
 pub fn validate_mission_safety(mission: &MissionPayload, weather: &WeatherData, drone_specs: &DroneCapabilities) -> SafetyValidationResult {
+    validate_mission_safety_with_policy(mission, weather, drone_specs, &EnergyReservePolicy::default())
+}
+
+/// Automated safety validation for mission authorization
+pub fn validate_mission_safety_with_policy(
+    mission: &MissionPayload,
+    weather: &WeatherData,
+    drone_specs: &DroneCapabilities,
+    reserve_policy: &EnergyReservePolicy
+) -> SafetyValidationResult {
     let mut safety_checks = Vec::new();
     let mut issues = Vec::new();
 
+    // A mission without a flyable plan cannot be judged safe
+    if !mission.has_flyable_flight_plan() {
+        safety_checks.push(SafetyCheck {
+            check_type: "flight_plan".to_string(),
+            passed: false,
+            severity: ViolationSeverity::Abort,
+            message: "Flight plan is empty or has a path with fewer than two waypoints".to_string(),
+            mitigation_steps: vec!["Add at least one path with two or more waypoints".to_string()],
+        });
+        issues.push("flight_plan".to_string());
+    }
+
     // Altitude safety checks
     for path in &mission.flight_plan.paths {
         for waypoint in &path.waypoints {
@@ -715,13 +1386,15 @@ pub fn validate_mission_safety(mission: &MissionPayload, weather: &WeatherData,
     }
 
     // Energy reserve checks
-    let safe_reserve = mission.constraints.energy.reserve_margin_soc > 0.15; // Minimum 15% reserve
+    let min_reserve = reserve_policy.min_reserve_for(mission);
+    let safe_reserve = mission.constraints.energy.reserve_margin_soc > min_reserve;
     if !safe_reserve {
         safety_checks.push(SafetyCheck {
             check_type: "energy_reserve".to_string(),
             passed: false,
             severity: ViolationSeverity::Warning,
-            message: format!("Energy reserve {}% below recommended minimum 15%", mission.constraints.energy.reserve_margin_soc * 100.0),
+            message: format!("Energy reserve {:.0}% below required minimum {:.0}% for {:?} priority",
+                mission.constraints.energy.reserve_margin_soc * 100.0, min_reserve * 100.0, mission.header.priority),
             mitigation_steps: vec!["Increase battery reserve margin".to_string(), "Shorten mission duration".to_string()],
         });
         issues.push("energy_reserve".to_string());
@@ -747,3 +1420,349 @@ pub fn validate_mission_safety(mission: &MissionPayload, weather: &WeatherData,
         risk_assessment: if is_safe { "Low Risk".to_string() } else { "High Risk - Manual Review Required".to_string() },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mission::{FlightPath, NavigationMode, Waypoint};
+
+    fn test_drone(drone_id: &str) -> DroneInterface {
+        DroneInterface::new(drone_id.to_string(), "quad".to_string(), DroneCapabilities {
+            max_payload_kg: 5.0,
+            max_flight_time_minutes: 30,
+            max_range_km: 10.0,
+            max_altitude_m: 500.0,
+            supported_sensors: Vec::new(),
+            communication_channels: vec![CommunicationChannel::GibberLinkShortRange],
+            weather_limits: WeatherLimits {
+                max_wind_speed_mps: 12.0,
+                max_gust_speed_mps: 15.0,
+                min_visibility_m: 1000.0,
+                max_temperature_c: 45.0,
+                min_temperature_c: -10.0,
+                max_precipitation_mmh: 2.0,
+            },
+            emergency_features: Vec::new(),
+            battery_derating: BatteryDeratingCurve::default(),
+            navigation: NavigationCapabilities::default(),
+        })
+    }
+
+    fn test_mission(id: u8, priority: MissionPriority) -> MissionPayload {
+        let waypoint = |wp_id: u32, latitude: f64| Waypoint {
+            id: wp_id,
+            position: GeoCoordinate { latitude, longitude: 2.0, altitude_msl: 120.0 },
+            position_tolerance_m: 2.0,
+            altitude_tolerance_m: 2.0,
+            loiter_time_seconds: None,
+            loiter_radius_m: None,
+            speed_limit_mps: None,
+            heading_required_degrees: None,
+            heading_tolerance_degrees: 10.0,
+        };
+
+        let mut mission = MissionPayload::default();
+        mission.header.id = [id; 16];
+        mission.header.priority = priority;
+        mission.constraints.energy.expected_consumption_wh = 10.0;
+        mission.flight_plan.paths = vec![FlightPath {
+            id: 1,
+            waypoints: vec![waypoint(1, 45.0), waypoint(2, 45.01)],
+            max_speed_mps: 10.0,
+            min_speed_mps: 2.0,
+            climb_rate_max_mps: 3.0,
+            descent_rate_max_mps: 2.0,
+            max_bank_angle_degrees: None,
+            min_turn_radius_m: None,
+            corridor_bounds: None,
+            navigation_mode: NavigationMode::Gps,
+        }];
+        mission
+    }
+
+    fn fleet_with_busy_drone(busy_priority: MissionPriority) -> FleetManager {
+        let mut fleet = FleetManager::new();
+        let mut station = StationInterface::new(
+            "station-1".to_string(),
+            GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 },
+            StationCapabilities {
+                max_concurrent_transfers: 4,
+                supported_drone_models: vec!["quad".to_string()],
+                weather_integration: true,
+                emergency_override: true,
+                fleet_management: true,
+                offline_capability: true,
+                audit_logging: true,
+            },
+        );
+        station.connected_drones.push("drone-1".to_string());
+        fleet.station_interfaces.insert("station-1".to_string(), station);
+        fleet.drone_fleet.insert("drone-1".to_string(), test_drone("drone-1"));
+        fleet.record_assignment(test_mission(1, busy_priority), "station-1", "drone-1");
+        fleet
+    }
+
+    fn calm_weather() -> WeatherData {
+        WeatherData {
+            timestamp: SystemTime::now(),
+            location: GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 },
+            temperature_celsius: 18.0,
+            humidity_percent: 50.0,
+            wind_speed_mps: 3.0,
+            wind_direction_degrees: 90.0,
+            gust_speed_mps: 5.0,
+            visibility_meters: 10000.0,
+            precipitation_type: None,
+            precipitation_rate_mmh: 0.0,
+            pressure_hpa: 1013.0,
+            cloud_cover_percent: 20.0,
+            lightning_probability: 0.0,
+            source: crate::weather::WeatherSource::LocalSensor,
+            forecast_horizon_hours: None,
+        }
+    }
+
+    #[test]
+    fn test_low_battery_fails_preflight_and_blocks_ready() {
+        let mut drone = test_drone("drone-1");
+        drone.battery_soc = 0.1;
+        drone.location = Some(GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 });
+        drone.communication_status.signal_strength = 0.9;
+        let mission = test_mission(1, MissionPriority::Normal);
+
+        let result = drone.complete_preflight(&mission, &calm_weather());
+
+        assert!(!result.passed);
+        let battery = result.items.iter().find(|item| item.check == PreflightCheck::BatterySoc).unwrap();
+        assert!(!battery.passed);
+        // A low charge also leaves too little endurance; every other check passes
+        assert!(result.items.iter()
+            .filter(|item| !matches!(item.check, PreflightCheck::BatterySoc | PreflightCheck::Endurance))
+            .all(|item| item.passed));
+        assert_eq!(drone.current_state, DroneOperationalState::PreFlightChecks);
+    }
+
+    #[test]
+    fn test_drone_needing_maintenance_cannot_enter_preflight() {
+        let mut drone = test_drone("drone-1");
+        drone.battery_soc = 0.9;
+        drone.location = Some(GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 });
+        drone.communication_status.signal_strength = 0.9;
+        drone.update_state(DroneOperationalState::MaintenanceRequired);
+        let mission = test_mission(1, MissionPriority::Normal);
+
+        let result = drone.complete_preflight(&mission, &calm_weather());
+
+        assert!(!result.passed);
+        let self_test = result.items.iter().find(|item| item.check == PreflightCheck::SensorSelfTest).unwrap();
+        assert!(!self_test.passed);
+        assert_eq!(drone.current_state, DroneOperationalState::MaintenanceRequired);
+    }
+
+    #[test]
+    fn test_telemetry_reflects_current_state() {
+        let mut drone = test_drone("drone-1");
+        drone.battery_soc = 0.65;
+        drone.location = Some(GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 80.0 });
+        drone.update_state(DroneOperationalState::MissionExecution);
+
+        let telemetry = drone.telemetry();
+
+        assert_eq!(telemetry.state, DroneOperationalState::MissionExecution);
+        assert_eq!(telemetry.battery_soc, 0.65);
+        assert_eq!(telemetry.position, drone.location);
+        assert_eq!(telemetry.timestamp, drone.last_update);
+    }
+
+    #[tokio::test]
+    async fn test_emergency_preempts_low_priority_mission() {
+        let mut fleet = fleet_with_busy_drone(MissionPriority::Low);
+
+        let result = fleet.assign_with_preemption(test_mission(2, MissionPriority::Emergency)).await;
+        assert!(result.is_ok());
+
+        assert_eq!(fleet.active_missions[&[1u8; 16]].status, AssignmentStatus::Paused);
+        assert_eq!(fleet.active_missions[&[2u8; 16]].assigned_drone, "drone-1");
+
+        assert_eq!(fleet.preemption_history.len(), 1);
+        let record = &fleet.preemption_history[0];
+        assert_eq!(record.preempted_mission, [1u8; 16]);
+        assert_eq!(record.preempting_mission, [2u8; 16]);
+        assert_eq!(record.action, PreemptionAction::Pause);
+    }
+
+    #[test]
+    fn test_cold_battery_reduces_endurance() {
+        let mut drone = test_drone("drone-1");
+        drone.battery_soc = 0.9;
+
+        let mut mission = test_mission(1, MissionPriority::Normal);
+        mission.header.max_execution_duration = Duration::from_secs(20 * 60);
+
+        let warm = drone.estimated_endurance_minutes(20.0, 0.1);
+        let cold = drone.estimated_endurance_minutes(-10.0, 0.1);
+        assert!(cold < warm);
+
+        assert!(drone.check_endurance(&mission, 20.0).is_ok());
+        assert!(drone.check_endurance(&mission, -10.0).is_err());
+
+        // Pre-flight runs the same check against the forecast temperature
+        let endurance_passes = |temperature_celsius| {
+            let mut weather = calm_weather();
+            weather.temperature_celsius = temperature_celsius;
+            drone.run_preflight(&mission, &weather).items.iter()
+                .find(|item| item.check == PreflightCheck::Endurance)
+                .unwrap()
+                .passed
+        };
+        assert!(endurance_passes(20.0));
+        assert!(!endurance_passes(-10.0));
+    }
+
+    #[test]
+    fn test_heavier_payload_shortens_range_envelope() {
+        let capabilities = test_drone("drone-1").capabilities;
+        let speed = capabilities.best_range_speed_mps();
+
+        let envelope = capabilities.range_envelope(&[0.0, 2.5, 5.0, 6.0], &[speed]);
+        assert_eq!(envelope.len(), 4);
+        assert!((envelope[0].range_km - capabilities.max_range_km).abs() < 0.01);
+        assert!(envelope[0].range_km > envelope[1].range_km);
+        assert!(envelope[1].range_km > envelope[2].range_km);
+        assert!(!envelope[3].feasible); // Above rated payload
+
+        // Flying faster or slower than the best-range speed costs range
+        let sweep = capabilities.range_envelope(&[0.0], &[speed * 0.5, speed, speed * 1.5]);
+        assert!(sweep[1].range_km > sweep[0].range_km && sweep[1].range_km > sweep[2].range_km);
+    }
+
+    #[tokio::test]
+    async fn test_normal_cannot_preempt_high_priority_mission() {
+        let mut fleet = fleet_with_busy_drone(MissionPriority::High);
+
+        let result = fleet.assign_with_preemption(test_mission(2, MissionPriority::Normal)).await;
+        assert!(result.is_err());
+
+        assert_eq!(fleet.active_missions[&[1u8; 16]].status, AssignmentStatus::Scheduled);
+        assert!(fleet.preemption_history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unsafe_weather_does_not_preempt() {
+        let mut fleet = fleet_with_busy_drone(MissionPriority::Low);
+        let storm = WeatherData { wind_speed_mps: 20.0, gust_speed_mps: 28.0, ..calm_weather() };
+        fleet.station_interfaces.get_mut("station-1").unwrap().weather_manager.update_weather(storm).unwrap();
+
+        let result = fleet.assign_with_preemption(test_mission(2, MissionPriority::Emergency)).await;
+        assert!(result.is_err());
+
+        assert_eq!(fleet.active_missions[&[1u8; 16]].status, AssignmentStatus::Scheduled);
+        assert!(fleet.preemption_history.is_empty());
+    }
+
+    #[test]
+    fn test_stricter_reserve_policy_flags_mission_passing_default() {
+        let drone = test_drone("drone-1");
+        let mut mission = test_mission(4, MissionPriority::Normal);
+        mission.constraints.energy.reserve_margin_soc = 0.2;
+        let energy_check = |result: &SafetyValidationResult| result.safety_checks.iter()
+            .find(|check| check.check_type == "energy_reserve")
+            .cloned();
+
+        let default_result = validate_mission_safety(&mission, &calm_weather(), &drone.capabilities);
+        assert!(energy_check(&default_result).is_none());
+
+        let strict = validate_mission_safety_with_policy(&mission, &calm_weather(), &drone.capabilities, &EnergyReservePolicy::uniform(0.25));
+        let check = energy_check(&strict).unwrap();
+        assert!(!check.passed);
+        assert!(check.message.contains("25%"));
+
+        let mut emergency = mission.clone();
+        emergency.header.priority = MissionPriority::Emergency;
+        let override_policy = EnergyReservePolicy { emergency_override_floor_soc: Some(0.1), ..EnergyReservePolicy::uniform(0.25) };
+        let overridden = validate_mission_safety_with_policy(&emergency, &calm_weather(), &drone.capabilities, &override_policy);
+        assert!(energy_check(&overridden).is_none());
+    }
+
+    #[test]
+    fn test_formation_mission_rejected_on_old_firmware() {
+        let mut drone = test_drone("drone-1");
+        let mut mission = crate::fixtures::heavy_lift_formation_mission();
+        mission.min_firmware_version = Some("3.2.0".to_string());
+
+        drone.firmware_version = Some("3.1.9".to_string());
+        let err = drone.validate_mission_compatibility(&mission).unwrap_err();
+        assert!(err.contains("requires firmware 3.2.0"));
+
+        drone.firmware_version = Some("3.2.1".to_string());
+        if let Err(other) = drone.validate_mission_compatibility(&mission) {
+            assert!(!other.contains("firmware"), "{}", other);
+        }
+    }
+
+    #[test]
+    fn test_weaker_signal_lowers_adapted_rate() {
+        let mut status = test_drone("drone-1").communication_status;
+        status.channel_type = "laser".to_string();
+
+        status.signal_strength = 0.9;
+        status.adapt_rate();
+        let (strong_rate, strong_errors) = (status.data_rate_bps, status.error_rate);
+        assert_eq!(strong_rate, 2_000_000);
+
+        status.signal_strength = 0.45;
+        status.adapt_rate();
+        assert!(status.data_rate_bps < strong_rate);
+        assert!(status.error_rate > strong_errors);
+
+        status.signal_strength = 0.05;
+        status.adapt_rate();
+        assert_eq!(status.data_rate_bps, 0);
+    }
+
+    #[tokio::test]
+    async fn test_formation_mission_requires_fleet_management_scope() {
+        let mission = crate::fixtures::heavy_lift_formation_mission();
+        let mut operators = MissionOperatorInterface::new(SecurityManager::new(Default::default()));
+        operators.security().change_pin("", "1234").await.unwrap();
+        let mut operator = HumanOperatorInterface::new("op-7".to_string(), SecurityClearance::Advanced);
+        operator.authorized_scopes = vec![AuthorizationScope::ExecuteMission];
+
+        async fn approve(operator: &HumanOperatorInterface, operators: &mut MissionOperatorInterface, mission: &MissionPayload) -> Result<(), MissionTransferError> {
+            let mut workflow = operator.begin_approval(operators, mission, vec![AuthorizationScope::ExecuteMission], [1u8; 32])?;
+            workflow.enter_pin(operators.security(), "1234").await?;
+            workflow.review_scopes(vec![AuthorizationScope::ExecuteMission])?;
+            workflow.approve()
+        }
+
+        let err = approve(&operator, &mut operators, &mission).await.unwrap_err();
+        assert_eq!(err.reason_code(), "mandatory_scope_not_held");
+
+        operator.authorized_scopes.push(AuthorizationScope::FleetManagement);
+        assert!(approve(&operator, &mut operators, &mission).await.is_ok());
+    }
+
+    #[test]
+    fn test_overrun_within_grace_is_not_timed_out() {
+        let mut fleet = fleet_with_busy_drone(MissionPriority::Normal);
+        let now = SystemTime::now();
+        let assignment = fleet.active_missions.get_mut(&[1; 16]).unwrap();
+        assignment.status = AssignmentStatus::InProgress;
+        // Planned for 1000 s; Normal priority allows 10% (100 s) of grace
+        assignment.assignment_time = now - Duration::from_secs(1050);
+        assignment.expected_completion = now - Duration::from_secs(50);
+
+        let events = fleet.monitor_missions_at(now);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains("overdue"));
+        assert!(!events[0].contains("timed out"));
+
+        let assignment = fleet.active_missions.get_mut(&[1; 16]).unwrap();
+        assignment.assignment_time = now - Duration::from_secs(1150);
+        assignment.expected_completion = now - Duration::from_secs(150);
+
+        let events = fleet.monitor_missions_at(now);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].contains("timed out"));
+    }
+}
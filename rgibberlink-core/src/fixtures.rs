@@ -0,0 +1,400 @@
+//! # Fixtures Module
+//!
+//! Representative missions shared by benchmarks and tests: a single-drone
+//! urban surveillance patrol and a four-drone heavy-lift formation carrying slung cargo.
+//! Timestamps are fixed so encoded sizes are stable from run to run. Smaller building
+//! blocks (paths, formations, MAVLink items, audit entries) back the unit tests and stay
+//! crate-private. Compiled for tests, and for benchmarks through the `bench-fixtures` feature.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+#[cfg(test)]
+use crate::audit::{create_audit_entry, AuditActor, AuditEntry, AuditEventType, AuditOperation, AuditSeverity};
+#[cfg(test)]
+use crate::audit::events::{AuditContext, OperationContext, OperationResult, PerformanceMetrics, ResourceConsumption};
+use crate::mission::*;
+
+/// Fixed issue time for fixture missions
+fn issued_at() -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_790_000_000)
+}
+
+fn waypoint(id: u32, latitude: f64, longitude: f64, altitude_msl: f32, speed_limit_mps: Option<f32>) -> Waypoint {
+    Waypoint {
+        id,
+        position: GeoCoordinate { latitude, longitude, altitude_msl },
+        position_tolerance_m: 3.0,
+        altitude_tolerance_m: 2.0,
+        loiter_time_seconds: None,
+        loiter_radius_m: None,
+        speed_limit_mps,
+        heading_required_degrees: None,
+        heading_tolerance_degrees: 15.0,
+    }
+}
+
+fn path(id: u32, waypoints: Vec<Waypoint>, max_speed_mps: f32) -> FlightPath {
+    FlightPath {
+        id,
+        waypoints,
+        max_speed_mps,
+        min_speed_mps: 1.0,
+        climb_rate_max_mps: 3.0,
+        descent_rate_max_mps: 2.0,
+        max_bank_angle_degrees: Some(25.0),
+        min_turn_radius_m: None,
+        corridor_bounds: None,
+        navigation_mode: NavigationMode::Gps,
+    }
+}
+
+/// Level leg at 100 m through `points` at 10 m/s, for tests that only care about the route
+#[cfg(test)]
+pub(crate) fn path_through(id: u32, points: &[(f64, f64)]) -> FlightPath {
+    let waypoints = points.iter().enumerate()
+        .map(|(index, &(latitude, longitude))| waypoint(index as u32, latitude, longitude, 100.0, None))
+        .collect();
+    path(id, waypoints, 10.0)
+}
+
+fn header(id: MissionId, name: &str, description: &str, priority: MissionPriority, tags: &[&str]) -> MissionHeader {
+    MissionHeader {
+        id,
+        name: name.to_string(),
+        description: Some(description.to_string()),
+        validity_start: issued_at(),
+        validity_end: issued_at() + Duration::from_secs(7200),
+        max_execution_duration: Duration::from_secs(3600),
+        issuing_station_fingerprint: [0xAA; 32],
+        drone_fingerprint: Some([0xBB; 32]),
+        priority,
+        tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        revision: 0,
+        supersedes: None,
+    }
+}
+
+fn base_mission(header: MissionHeader) -> MissionPayload {
+    let mut mission = MissionPayload::default();
+    mission.header = header;
+    mission.flight_plan.home_location = GeoCoordinate { latitude: 45.5017, longitude: -73.5673, altitude_msl: 30.0 };
+    mission.crypto.timestamp = issued_at();
+    mission
+}
+
+/// Single-drone patrol of an urban block: an eight-waypoint loop with imaging, video
+/// and a thermal area scan
+pub fn surveillance_mission() -> MissionPayload {
+    let mut mission = base_mission(header(
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16],
+        "Urban Surveillance Patrol",
+        "Automated patrol of urban area with thermal imaging and data collection",
+        MissionPriority::High,
+        &["surveillance", "urban", "thermal"],
+    ));
+
+    let waypoints = (0..8)
+        .map(|i| {
+            let angle = (i as f64) * std::f64::consts::PI / 4.0;
+            waypoint(i, 45.5017 + 0.002 * angle.cos(), -73.5673 + 0.003 * angle.sin(), 120.0, Some(8.0))
+        })
+        .collect();
+    mission.flight_plan.paths = vec![path(1, waypoints, 12.0)];
+
+    let patrol_area = GeoBounds {
+        north: 45.5040, south: 45.4995,
+        east: -73.5640, west: -73.5705,
+        min_altitude: 60.0, max_altitude: 150.0,
+    };
+    mission.tasks = vec![
+        MissionTask {
+            id: 1,
+            label: "Perimeter imagery".to_string(),
+            sequence_order: 1,
+            control_point: None,
+            actions: vec![MissionAction::CaptureImage { count: 24, interval_seconds: Some(5), target_location: None }],
+            preconditions: Vec::new(),
+            postconditions: Some("perimeter_imaged".to_string()),
+            timeout_seconds: Some(300),
+        },
+        MissionTask {
+            id: 2,
+            label: "Thermal sweep".to_string(),
+            sequence_order: 2,
+            control_point: None,
+            actions: vec![
+                MissionAction::ScanArea { bounds: patrol_area, sensor_type: SensorType::Thermal, resolution_m: 0.5 },
+                MissionAction::RecordVideo { duration_seconds: 180, quality: VideoQuality::High, target_location: None },
+            ],
+            preconditions: vec!["perimeter_imaged".to_string()],
+            postconditions: Some("sweep_complete".to_string()),
+            timeout_seconds: Some(900),
+        },
+        MissionTask {
+            id: 3,
+            label: "Hand off footage".to_string(),
+            sequence_order: 3,
+            control_point: None,
+            actions: vec![MissionAction::Custom {
+                action_type: "upload_summary".to_string(),
+                parameters: HashMap::from([("channel".to_string(), "ground_station".to_string())]),
+            }],
+            preconditions: vec!["sweep_complete".to_string()],
+            postconditions: None,
+            timeout_seconds: Some(120),
+        },
+    ];
+    mission
+}
+
+/// Four-drone square formation lifting 200 kg of rectangular cargo on Kevlar slings
+/// between two industrial sites
+pub fn heavy_lift_formation_mission() -> MissionPayload {
+    let mut mission = base_mission(header(
+        [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0],
+        "Heavy Rectangular Cargo Transport - 4 Drone Formation",
+        "Synchronized 4-drone square formation lifting 200kg rectangular cargo between industrial sites",
+        MissionPriority::High,
+        &["heavy-lift", "formation-flight", "cargo-transport", "precision-landing"],
+    ));
+
+    mission.flight_plan.paths = vec![
+        path(1, vec![
+            waypoint(0, 45.5017, -73.5673, 40.0, Some(2.0)),
+            waypoint(1, 45.5030, -73.5650, 80.0, Some(5.0)),
+        ], 6.0),
+        path(2, vec![
+            waypoint(2, 45.5030, -73.5650, 80.0, Some(5.0)),
+            waypoint(3, 45.5080, -73.5600, 80.0, Some(6.0)),
+            waypoint(4, 45.5090, -73.5590, 40.0, Some(1.5)),
+        ], 6.0),
+    ];
+    mission.tasks = vec![MissionTask {
+        id: 1,
+        label: "Deliver cargo".to_string(),
+        sequence_order: 1,
+        control_point: None,
+        actions: vec![MissionAction::DeployPayload {
+            payload_type: "industrial_crate".to_string(),
+            target_location: GeoCoordinate { latitude: 45.5090, longitude: -73.5590, altitude_msl: 32.0 },
+            deployment_altitude: 2.0,
+        }],
+        preconditions: Vec::new(),
+        postconditions: Some("cargo_delivered".to_string()),
+        timeout_seconds: Some(1800),
+    }];
+
+    let corners = [("DRONE-NW", -3.0, 3.0), ("DRONE-NE", 3.0, 3.0), ("DRONE-SE", 3.0, -3.0), ("DRONE-SW", -3.0, -3.0)];
+    let drones = corners.iter().enumerate()
+        .map(|(index, &(drone_id, x_offset_m, y_offset_m))| FormationDrone {
+            drone_id: drone_id.to_string(),
+            role: if index == 0 { DroneRole::Leader } else { DroneRole::Lift },
+            position: DronePosition { x_offset_m, y_offset_m, z_offset_m: 0.0, heading_offset_degrees: 0.0 },
+            synchronization_offset: SynchronizationOffset {
+                takeoff_delay_ms: index as u32 * 500,
+                target_altitude: 80.0,
+                speed_sync_enabled: true,
+                position_sync_tolerance_m: 0.5,
+            },
+            fail_safe_behavior: FailSafeBehavior::FormationRTL,
+            energy_reserve_required: 0.25,
+        })
+        .collect();
+    let lifting_slings = corners.iter().enumerate()
+        .map(|(index, &(drone_id, _, _))| LiftingSling {
+            sling_id: format!("sling_{}", index + 1),
+            drone_assignment: drone_id.to_string(),
+            attachment_point: GeoCoordinate { latitude: 45.5017, longitude: -73.5673, altitude_msl: 32.0 },
+            sling_type: SlingType::Kevlar { diameter_mm: 8.0 },
+            length_m: 5.0,
+            max_load_kg: 80.0,
+            tension_sensor: None,
+        })
+        .collect();
+    let attachment_points = corners.iter().enumerate()
+        .map(|(index, &(_, x_m, y_m))| AttachmentPoint {
+            point_id: format!("corner_{}", index + 1),
+            location: PayloadCoordinate { x_m: x_m / 3.0, y_m: y_m / 3.0 * 0.6, z_m: 0.6 },
+            sling_connection: Some(format!("sling_{}", index + 1)),
+            stress_limit_kg: 120.0,
+            preferred_drone_angle: 45.0 + 90.0 * index as f32,
+        })
+        .collect();
+    let communication_mesh = (0..corners.len())
+        .map(|index| MeshLink {
+            from_drone: corners[index].0.to_string(),
+            to_drone: corners[(index + 1) % corners.len()].0.to_string(),
+            link_type: CommunicationType::DirectRadio,
+            max_distance_m: 20.0,
+            redundancy_required: true,
+        })
+        .collect();
+
+    mission.formation_config = Some(FormationConfiguration {
+        formation_type: FormationType::Square,
+        drones,
+        payload_config: PayloadConfiguration {
+            payload_type: PayloadType::Equipment { category: "industrial_crate".to_string(), fragility: Fragility::Sensitive },
+            weight_kg: 200.0,
+            dimensions: PayloadDimensions { length_m: 2.0, width_m: 1.2, height_m: 1.2, volume_m3: Some(2.88) },
+            center_of_gravity: CenterOfGravity { x_offset_m: 0.0, y_offset_m: 0.0, z_offset_m: -0.1, uncertainty_m: 0.05 },
+            stability_requirements: StabilityRequirements {
+                max_roll_degrees: 8.0,
+                max_pitch_degrees: 8.0,
+                max_yaw_rate_degrees_per_sec: 10.0,
+                min_bridle_clearance_m: 1.5,
+                wind_stability_factor: 0.7,
+            },
+            release_mechanism: ReleaseMechanism::SequentialRelease,
+            lifting_slings,
+        },
+        synchronization: SynchronizationConfig {
+            clock_sync_interval_ms: 100,
+            position_sync_tolerance_m: 0.5,
+            speed_sync_tolerance_mps: 0.3,
+            altitude_sync_tolerance_m: 0.5,
+            heading_sync_tolerance_deg: 3.0,
+            takeoff_sequence: corners.iter().map(|(drone_id, _, _)| drone_id.to_string()).collect(),
+            landing_sequence: corners.iter().rev().map(|(drone_id, _, _)| drone_id.to_string()).collect(),
+            emergency_sync_timeout_ms: 2000,
+            partition_tolerance_ms: DEFAULT_PARTITION_TOLERANCE_MS,
+        },
+        attachment_points,
+        load_distribution: LoadDistribution {
+            target_load_per_drone_kg: 50.0,
+            max_asymmetry_allowed: 0.1,
+            redistribution_strategy: LoadRedistribution::Redistribute,
+            dynamic_balancing: true,
+            critical_load_threshold: 70.0,
+        },
+        communication_mesh,
+        formation_geofence: Some(GeoBounds {
+            north: 45.5100, south: 45.4900,
+            east: -73.5500, west: -73.5750,
+            min_altitude: 0.0, max_altitude: 120.0,
+        }),
+        min_operational_drones: 4,
+    });
+    mission
+}
+
+/// Every fixture mission with a short name, for benchmarks that sweep all of them
+pub fn representative_missions() -> Vec<(&'static str, MissionPayload)> {
+    vec![
+        ("surveillance", surveillance_mission()),
+        ("heavy_lift_formation", heavy_lift_formation_mission()),
+    ]
+}
+
+/// Formation member at the formation center with neutral sync settings
+#[cfg(test)]
+pub(crate) fn formation_drone(id: &str, role: DroneRole) -> FormationDrone {
+    FormationDrone {
+        drone_id: id.to_string(),
+        role,
+        position: DronePosition { x_offset_m: 0.0, y_offset_m: 0.0, z_offset_m: 0.0, heading_offset_degrees: 0.0 },
+        synchronization_offset: SynchronizationOffset {
+            takeoff_delay_ms: 0,
+            target_altitude: 50.0,
+            speed_sync_enabled: true,
+            position_sync_tolerance_m: 1.0,
+        },
+        fail_safe_behavior: FailSafeBehavior::FormationRTL,
+        energy_reserve_required: 0.1,
+    }
+}
+
+/// Line formation carrying a 1 m water container with no slings, sized for four lifters
+#[cfg(test)]
+pub(crate) fn formation(drones: Vec<FormationDrone>, weight_kg: f32, critical_load_threshold: f32) -> FormationConfiguration {
+    FormationConfiguration {
+        formation_type: FormationType::Line,
+        drones,
+        payload_config: PayloadConfiguration {
+            payload_type: PayloadType::Container { volume_liters: 100.0, contents: "water".to_string() },
+            weight_kg,
+            dimensions: PayloadDimensions { length_m: 1.0, width_m: 1.0, height_m: 1.0, volume_m3: None },
+            center_of_gravity: CenterOfGravity { x_offset_m: 0.0, y_offset_m: 0.0, z_offset_m: 0.0, uncertainty_m: 0.05 },
+            stability_requirements: StabilityRequirements {
+                max_roll_degrees: 10.0,
+                max_pitch_degrees: 10.0,
+                max_yaw_rate_degrees_per_sec: 15.0,
+                min_bridle_clearance_m: 1.0,
+                wind_stability_factor: 0.8,
+            },
+            release_mechanism: ReleaseMechanism::SequentialRelease,
+            lifting_slings: Vec::new(),
+        },
+        synchronization: SynchronizationConfig {
+            clock_sync_interval_ms: 100,
+            position_sync_tolerance_m: 1.0,
+            speed_sync_tolerance_mps: 0.5,
+            altitude_sync_tolerance_m: 1.0,
+            heading_sync_tolerance_deg: 5.0,
+            takeoff_sequence: Vec::new(),
+            landing_sequence: Vec::new(),
+            emergency_sync_timeout_ms: 2000,
+            partition_tolerance_ms: DEFAULT_PARTITION_TOLERANCE_MS,
+        },
+        attachment_points: Vec::new(),
+        load_distribution: LoadDistribution {
+            target_load_per_drone_kg: weight_kg / 4.0,
+            max_asymmetry_allowed: 0.2,
+            redistribution_strategy: LoadRedistribution::Redistribute,
+            dynamic_balancing: true,
+            critical_load_threshold,
+        },
+        communication_mesh: Vec::new(),
+        formation_geofence: None,
+        min_operational_drones: 0,
+    }
+}
+
+/// Mission item in the relative-altitude frame, as ground stations usually export them
+#[cfg(test)]
+pub(crate) fn mavlink_item(seq: u16, command: u16, params: [f32; 4], x: f64, y: f64, z: f32) -> MavlinkMissionItem {
+    MavlinkMissionItem {
+        seq,
+        frame: mav_frame::GLOBAL_RELATIVE_ALT,
+        command,
+        param1: params[0],
+        param2: params[1],
+        param3: params[2],
+        param4: params[3],
+        x,
+        y,
+        z,
+        autocontinue: true,
+    }
+}
+
+/// Successful station operation with default context, for audit tests to adjust
+#[cfg(test)]
+pub(crate) fn system_audit_entry(event_type: AuditEventType, severity: AuditSeverity) -> AuditEntry {
+    create_audit_entry(
+        event_type,
+        severity,
+        AuditActor::System {
+            component: "station".to_string(),
+            version: "1.0".to_string(),
+            subsystem: "fleet".to_string(),
+        },
+        AuditOperation {
+            operation_type: "connection".to_string(),
+            operation_name: "drone_connected".to_string(),
+            parameters: HashMap::new(),
+            execution_context: OperationContext::default(),
+            expected_duration: None,
+            resource_consumption: ResourceConsumption::default(),
+        },
+        OperationResult {
+            success: true,
+            error_code: None,
+            error_message: None,
+            duration_ms: 1,
+            performance_metrics: PerformanceMetrics::default(),
+            side_effects: vec![],
+        },
+        AuditContext::default(),
+    )
+}
@@ -47,9 +47,15 @@ impl LaserEngine {
             range_detector: None,
             current_power_profile: Arc::new(Mutex::new(PowerProfile::default())),
             adaptive_mode: false,
+            retry_policy: RetryPolicy::default(),
         }
     }
 
+    /// Set the retry policy used by `transmit_data_reliable`
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry_policy = policy;
+    }
+
     pub async fn initialize(&mut self) -> Result<(), LaserError> {
         #[cfg(target_os = "android")]
         {
@@ -114,6 +120,13 @@ impl LaserEngine {
         result
     }
 
+    /// Transmit with retries and exponential backoff for transient failures.
+    /// Permanent failures such as safety lockouts are returned immediately.
+    pub async fn transmit_data_reliable(&mut self, data: &[u8]) -> Result<(), LaserError> {
+        let policy = self.retry_policy.clone();
+        policy.run(&mut (self, data), |(engine, data)| Box::pin(engine.transmit_data(data))).await
+    }
+
     /// Receive data using configured reception method
     pub async fn receive_data(&mut self, timeout_ms: u64) -> Result<Vec<u8>, LaserError> {
         if !self.is_active().await {
@@ -1557,6 +1570,113 @@ impl LaserEngine {
             },
         }
     }
+
+    /// Estimate the optical link budget at a given range and set of conditions
+    pub async fn link_budget(&self, range_m: f32, weather: &crate::performance_monitor::EnvironmentalFactors) -> LinkBudget {
+        let transmit_power_mw = {
+            let profile = self.current_power_profile.lock().await;
+            let safe_limit = profile.safe_power_limit(&self.config.laser_type);
+            profile.optimal_power_mw.min(profile.max_power_mw).min(safe_limit)
+        };
+        let transmit_power_dbm = 10.0 * transmit_power_mw.max(1e-6).log10();
+
+        // Geometric loss from beam spreading over the receiver aperture
+        let range_m = range_m.max(0.0);
+        let beam_diameter_m = self.config.beam_diameter_mm / 1000.0 + range_m * self.config.beam_divergence_mrad / 1000.0;
+        let capture_ratio = (LINK_RECEIVER_APERTURE_M / beam_diameter_m).powi(2).min(1.0);
+        let geometric_loss_db = -10.0 * capture_ratio.log10();
+
+        // Atmospheric attenuation: visibility-based extinction plus precipitation
+        let visibility_km = (weather.visibility_meters / 1000.0).max(0.05);
+        let extinction_db_per_km = 4.343 * 3.91 / visibility_km;
+        let precipitation_db_per_km = match weather.weather {
+            WeatherCondition::Clear | WeatherCondition::Cloudy | WeatherCondition::Fog => 0.0,
+            WeatherCondition::LightRain => 1.0,
+            WeatherCondition::Rain => 3.0,
+            WeatherCondition::HeavyRain => 10.0,
+            WeatherCondition::Storm => 15.0,
+            WeatherCondition::Snow => 20.0,
+        };
+        let atmospheric_loss_db = (extinction_db_per_km + precipitation_db_per_km) * range_m / 1000.0;
+
+        let received_power_dbm = transmit_power_dbm - geometric_loss_db - atmospheric_loss_db - LINK_SYSTEM_LOSS_DB;
+        let snr_db = received_power_dbm - LINK_RECEIVER_NOISE_FLOOR_DBM;
+        let margin_db = snr_db - LINK_REQUIRED_SNR_DB;
+
+        LinkBudget {
+            received_power_dbm,
+            snr_db,
+            margin_db,
+            feasible: margin_db >= 0.0,
+        }
+    }
+}
+
+/// Photodiode/camera receiver aperture diameter
+const LINK_RECEIVER_APERTURE_M: f32 = 0.025;
+/// Optics, pointing, and coupling losses
+const LINK_SYSTEM_LOSS_DB: f32 = 3.0;
+/// Receiver noise-equivalent power
+const LINK_RECEIVER_NOISE_FLOOR_DBM: f32 = -60.0;
+/// Minimum SNR for reliable OOK demodulation
+const LINK_REQUIRED_SNR_DB: f32 = 15.0;
+
+/// Optical link budget estimate
+#[derive(Debug, Clone)]
+pub struct LinkBudget {
+    pub received_power_dbm: f32,
+    pub snr_db: f32,
+    pub margin_db: f32,
+    pub feasible: bool,
+}
+
+/// Retry behaviour for laser transmissions
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub multiplier: f32, // Backoff growth per failed attempt
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(50),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff before retrying after failed attempt `attempt` (1-based), or the error to give up with
+    pub fn next_delay(&self, attempt: u32, error: LaserError) -> Result<Duration, LaserError> {
+        if !error.is_transient() {
+            return Err(error);
+        }
+        if attempt >= self.max_attempts {
+            return Err(LaserError::RetriesExhausted { attempts: attempt, last_error: Box::new(error) });
+        }
+        Ok(self.initial_backoff.mul_f32(self.multiplier.powi(attempt as i32 - 1)))
+    }
+
+    /// Run a fallible async operation under this policy, lending it `target` on every attempt
+    pub async fn run<S, T, F>(&self, target: &mut S, mut operation: F) -> Result<T, LaserError>
+    where
+        F: for<'a> FnMut(&'a mut S) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, LaserError>> + Send + 'a>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation(&mut *target).await {
+                Ok(value) => return Ok(value),
+                Err(error) => {
+                    let delay = self.next_delay(attempt, error)?;
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
 }
 
 /// Power budget analysis for operations
@@ -1660,4 +1780,97 @@ mod tests {
         assert!(energy >= 0.0);
         assert_eq!(violations, 0);
     }
+
+    #[tokio::test]
+    async fn test_link_budget_margin_shrinks_with_range() {
+        let engine = LaserEngine::new(LaserConfig::default(), ReceptionConfig::default());
+        let conditions = crate::performance_monitor::EnvironmentalFactors::default();
+
+        let near = engine.link_budget(20.0, &conditions).await;
+        let far = engine.link_budget(200.0, &conditions).await;
+
+        assert!(far.margin_db < near.margin_db);
+        assert!(far.received_power_dbm < near.received_power_dbm);
+    }
+
+    #[tokio::test]
+    async fn test_link_budget_margin_shrinks_with_visibility() {
+        let engine = LaserEngine::new(LaserConfig::default(), ReceptionConfig::default());
+        let clear = crate::performance_monitor::EnvironmentalFactors {
+            visibility_meters: 10_000.0,
+            ..Default::default()
+        };
+        let foggy = crate::performance_monitor::EnvironmentalFactors {
+            weather: WeatherCondition::Fog,
+            visibility_meters: 200.0,
+            ..Default::default()
+        };
+
+        let clear_budget = engine.link_budget(100.0, &clear).await;
+        let foggy_budget = engine.link_budget(100.0, &foggy).await;
+
+        assert!(foggy_budget.margin_db < clear_budget.margin_db);
+        assert!(foggy_budget.snr_db < clear_budget.snr_db);
+    }
+
+    #[tokio::test]
+    async fn test_link_budget_uses_configured_divergence() {
+        let conditions = crate::performance_monitor::EnvironmentalFactors::default();
+        let tight = LaserEngine::new(LaserConfig { beam_divergence_mrad: 0.5, ..LaserConfig::default() }, ReceptionConfig::default());
+        let wide = LaserEngine::new(LaserConfig { beam_divergence_mrad: 3.0, ..LaserConfig::default() }, ReceptionConfig::default());
+
+        let tight_budget = tight.link_budget(100.0, &conditions).await;
+        let wide_budget = wide.link_budget(100.0, &conditions).await;
+
+        assert!(wide_budget.received_power_dbm < tight_budget.received_power_dbm);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_recovers_flaky_channel() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(1),
+            multiplier: 2.0,
+        };
+        let mut attempts = 0;
+
+        let result = policy.run(&mut attempts, |attempts| {
+            *attempts += 1;
+            let outcome = if *attempts < 3 { Err(LaserError::TransmissionFailed) } else { Ok(*attempts) };
+            Box::pin(async move { outcome })
+        }).await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_does_not_retry_safety_lockout() {
+        let policy = RetryPolicy { initial_backoff: Duration::from_millis(1), ..Default::default() };
+        let mut attempts = 0;
+
+        let result: Result<(), LaserError> = policy.run(&mut attempts, |attempts| {
+            *attempts += 1;
+            Box::pin(async { Err(LaserError::SafetyViolation) })
+        }).await;
+
+        assert!(matches!(result, Err(LaserError::SafetyViolation)));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reliable_transmit_does_not_retry_unavailable_hardware() {
+        let mut engine = LaserEngine::new(LaserConfig::default(), ReceptionConfig::default());
+        engine.set_retry_policy(RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+        });
+
+        // The engine was never initialized, a permanent failure
+        let started = Instant::now();
+        let result = engine.transmit_data_reliable(&[1, 2, 3]).await;
+
+        assert!(matches!(result, Err(LaserError::HardwareUnavailable)));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
 }
\ No newline at end of file
@@ -18,6 +18,7 @@ pub struct LaserConfig {
     pub max_power_mw: f32,
     pub wavelength_nm: u32,
     pub beam_diameter_mm: f32,
+    pub beam_divergence_mrad: f32, // Full-angle divergence after the collimator
     pub range_meters: f32,
     pub safety_enabled: bool,
 }
@@ -37,6 +38,7 @@ impl LaserConfig {
             max_power_mw: 5.0,
             wavelength_nm: 650,
             beam_diameter_mm: 2.0,
+            beam_divergence_mrad: 1.0,
             range_meters: 100.0,
             safety_enabled: true,
         }
@@ -50,6 +52,7 @@ impl LaserConfig {
             max_power_mw: 5.0,
             wavelength_nm: 532,
             beam_diameter_mm: 2.0,
+            beam_divergence_mrad: 1.0,
             range_meters: 100.0,
             safety_enabled: true,
         }
@@ -63,6 +66,7 @@ impl LaserConfig {
             max_power_mw: 5.0,
             wavelength_nm: 450,
             beam_diameter_mm: 2.0,
+            beam_divergence_mrad: 1.0,
             range_meters: 100.0,
             safety_enabled: true,
         }
@@ -76,6 +80,7 @@ impl LaserConfig {
             max_power_mw: 10.0, // IR can handle higher power
             wavelength_nm: 980, // Common IR wavelength
             beam_diameter_mm: 2.0,
+            beam_divergence_mrad: 1.0,
             range_meters: 200.0, // IR has better range
             safety_enabled: true,
         }
@@ -89,6 +94,7 @@ impl LaserConfig {
             max_power_mw: 1.0, // UV lasers are typically lower power
             wavelength_nm: 405, // Near UV
             beam_diameter_mm: 1.5,
+            beam_divergence_mrad: 1.0,
             range_meters: 50.0, // UV has shorter range
             safety_enabled: true,
         }
@@ -102,6 +108,7 @@ impl LaserConfig {
             max_power_mw: 5.0,
             wavelength_nm,
             beam_diameter_mm: 2.0,
+            beam_divergence_mrad: 1.0,
             range_meters: 100.0,
             safety_enabled: true,
         }
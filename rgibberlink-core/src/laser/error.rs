@@ -22,4 +22,20 @@ pub enum LaserError {
     Timeout,
     #[error("Visual engine error: {0}")]
     VisualError(#[from] crate::visual::VisualError),
+    #[error("Gave up after {attempts} attempts: {last_error}")]
+    RetriesExhausted { attempts: u32, last_error: Box<LaserError> },
+}
+
+impl LaserError {
+    /// Whether retrying the same operation may succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            LaserError::TransmissionFailed
+                | LaserError::ReceptionFailed
+                | LaserError::AlignmentLost
+                | LaserError::DataCorruption
+                | LaserError::Timeout
+        )
+    }
 }
\ No newline at end of file
@@ -0,0 +1,13 @@
+//! Common laser types shared by the transmit, receive and negotiation paths
+
+use serde::{Deserialize, Serialize};
+
+/// Line codes and framings the optical channel can carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModulationScheme {
+    Ook,          // On-off keying
+    Pwm,          // Pulse width modulation
+    QrProjection, // Projected QR frames read by the drone camera
+    Fsk,          // Frequency shift keying of the carrier
+    Manchester,   // Self-clocking Manchester code
+}
@@ -325,8 +325,13 @@ pub mod channel_validator;
 pub mod security;
 pub mod fallback;
 pub mod performance_monitor;
+pub mod cancellation;
 pub mod mission;
 pub mod weather;
+pub mod mission_transfer;
+pub mod drone_station;
+#[cfg(any(test, feature = "bench-fixtures"))]
+pub mod fixtures;
 pub mod audit;
 pub mod hierarchical;
 
@@ -348,6 +353,7 @@ pub use channel_validator::{ChannelValidator, ValidationError, ValidationPhase,
 pub use security::{SecurityManager, SecurityError, SecurityConfig, SecurityLevel, PermissionType, PermissionGrant, PermissionScope, PeerIdentity, TrustLevel, EnvironmentalConditions, WeatherCondition, TimeOfDay, CommandExecution};
 pub use fallback::{FallbackManager, FallbackError, FallbackConfig, FallbackMode, FallbackStatus, ChannelFailure, ChannelHealth, SessionSnapshot};
 pub use performance_monitor::{PerformanceMonitor, PerformanceError, PerformanceMetrics, PerformanceConfig, PerformancePreset, BenchmarkResult, EnvironmentalFactors};
+pub use cancellation::CancellationToken;
 pub use audit::{AuditSystem, AuditEntry, SecurityAlert, AuditEventType, AuditSeverity, AuditActor, AuditOperation, create_audit_entry};
 pub use hierarchical::{HierarchicalProtocolEngine, MilitaryRank, CommandType, HierarchicalMessage, HierarchicalState, HierarchyPresence};
 
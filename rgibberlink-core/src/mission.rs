@@ -5,12 +5,39 @@
 //! paths, control points, actions, geofencing, energy management, and safety policies.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::time::{SystemTime, Duration};
+use crate::crypto::{CryptoEngine, CryptoError};
+use bincode::Options;
+use crate::weather::ViolationSeverity;
 
 /// Unique mission identifier (UUID-like format)
 pub type MissionId = [u8; 16];
 
+/// Fastest plausible climb or descent rate for a multirotor, in m/s
+pub const MAX_VERTICAL_RATE_MPS: f32 = 15.0;
+
+/// Hazardous payloads must keep this multiple of `max_proximity_to_crowd_m` from populated areas
+pub const HAZMAT_CROWD_MARGIN_FACTOR: f32 = 2.0;
+
+/// Task precondition that declares the hazardous payload's containment was verified
+pub const CONTAINMENT_CHECK_CONDITION: &str = "containment_integrity_verified";
+
+/// Leading byte of the packed mission encoding, bumped on any change to the field layout
+pub const PACKED_FORMAT_VERSION: u8 = 1;
+
+/// Largest packed mission `from_packed` decodes; also caps what a length prefix may claim
+pub const MAX_PACKED_MISSION_BYTES: u64 = 1 << 20;
+
+/// Standard gravity, for the radius of a coordinated banked turn
+const STANDARD_GRAVITY_MPS2: f32 = 9.80665;
+
+/// Course changes smaller than this are flown as straight lines
+const MIN_SMOOTHED_TURN_DEGREES: f64 = 1.0;
+
+/// Largest angle swept between consecutive points of a smoothed turn
+const TURN_ARC_STEP_DEGREES: f64 = 10.0;
+
 /// Geographic coordinate in decimal degrees
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GeoCoordinate {
@@ -43,10 +70,14 @@ pub struct MissionHeader {
     pub drone_fingerprint: Option<[u8; 32]>,
     pub priority: MissionPriority,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub revision: u32, // 0 for the original issue, incremented by each amendment
+    #[serde(default)]
+    pub supersedes: Option<[u8; 32]>, // Fingerprint of the signed revision this one amends
 }
 
 /// Mission priority levels
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
 pub enum MissionPriority {
     Low,
     Normal,
@@ -64,7 +95,7 @@ pub struct Waypoint {
     pub altitude_tolerance_m: f32,
     pub loiter_time_seconds: Option<u32>,
     pub loiter_radius_m: Option<f32>,
-    pub speed_limit_mps: Option<f32>,
+    pub speed_limit_mps: Option<f32>, // Ground speed on the leg into this waypoint
     pub heading_required_degrees: Option<f32>,
     pub heading_tolerance_degrees: f32,
 }
@@ -74,13 +105,34 @@ pub struct Waypoint {
 pub struct FlightPath {
     pub id: u32,
     pub waypoints: Vec<Waypoint>,
-    pub max_speed_mps: f32,
-    pub min_speed_mps: f32,
+    pub max_speed_mps: f32, // Ground speed; required airspeed depends on wind
+    pub min_speed_mps: f32, // Ground speed
     pub climb_rate_max_mps: f32,
     pub descent_rate_max_mps: f32,
     pub max_bank_angle_degrees: Option<f32>,
     pub min_turn_radius_m: Option<f32>,
     pub corridor_bounds: Option<GeoBounds>,
+    #[serde(default)]
+    pub navigation_mode: NavigationMode,
+}
+
+/// How a path is navigated
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum NavigationMode {
+    #[default]
+    Gps,
+    /// Inertial dead reckoning (jamming, indoors, urban canyons); position error must
+    /// stay within `max_drift_m` until GPS is regained
+    GpsDenied { max_drift_m: f32 },
+    /// Visual odometry against terrain or landmarks
+    Visual,
+}
+
+/// Navigation a drone can fly without GPS
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NavigationCapabilities {
+    pub dead_reckoning_drift_mps: Option<f32>, // Inertial position error growth; None if it cannot fly GPS-denied
+    pub visual_navigation: bool,
 }
 
 /// Control point types
@@ -192,6 +244,150 @@ pub enum MissionAction {
     },
 }
 
+/// Value type expected for a custom action parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParamKind {
+    Number,
+    Integer,
+    Boolean,
+    Text,
+    OneOf(Vec<String>),
+}
+
+impl ParamKind {
+    fn accepts(&self, value: &str) -> bool {
+        match self {
+            ParamKind::Number => value.trim().parse::<f64>().map_or(false, |number| number.is_finite()),
+            ParamKind::Integer => value.trim().parse::<i64>().is_ok(),
+            ParamKind::Boolean => value.trim().parse::<bool>().is_ok(),
+            ParamKind::Text => true,
+            ParamKind::OneOf(options) => options.iter().any(|option| option == value),
+        }
+    }
+}
+
+/// Declared parameter of a known custom action type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParamSpec {
+    pub name: String,
+    pub kind: ParamKind,
+    pub required: bool,
+}
+
+impl ParamSpec {
+    pub fn required(name: &str, kind: ParamKind) -> Self {
+        Self { name: name.to_string(), kind, required: true }
+    }
+
+    pub fn optional(name: &str, kind: ParamKind) -> Self {
+        Self { name: name.to_string(), kind, required: false }
+    }
+}
+
+/// Expected parameters per custom action type; unknown action types and undeclared
+/// parameters are not checked
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomActionSchema {
+    pub actions: HashMap<String, Vec<ParamSpec>>,
+}
+
+impl CustomActionSchema {
+    /// Declare (or replace) the parameters of `action_type`
+    pub fn declare(&mut self, action_type: &str, params: Vec<ParamSpec>) -> &mut Self {
+        self.actions.insert(action_type.to_string(), params);
+        self
+    }
+
+    /// Schema for the custom actions understood by the formation controller
+    pub fn builtin() -> Self {
+        let mut schema = Self::default();
+        schema
+            .declare("formation_sync_check", vec![
+                ParamSpec::required("position_tolerance", ParamKind::Number),
+                ParamSpec::required("heading_alignment", ParamKind::Number),
+                ParamSpec::required("altitude_sync", ParamKind::Number),
+            ])
+            .declare("coordinated_lift", vec![
+                ParamSpec::required("lift_rate_mps", ParamKind::Number),
+                ParamSpec::required("target_clearance", ParamKind::Number),
+                ParamSpec::optional("tension_balance_threshold", ParamKind::Number),
+            ])
+            .declare("formation_takeoff_sequence", vec![
+                ParamSpec::optional("sequence", ParamKind::OneOf(vec!["simultaneous".to_string(), "sequential".to_string()])),
+                ParamSpec::required("altitude_target", ParamKind::Number),
+            ])
+            .declare("formation_flight_path", vec![
+                ParamSpec::optional("payload_weight", ParamKind::Number),
+            ])
+            .declare("load_balance_monitoring", vec![
+                ParamSpec::required("update_interval_ms", ParamKind::Integer),
+                ParamSpec::optional("redistribution_tolerance", ParamKind::Number),
+                ParamSpec::optional("emergency_threshold", ParamKind::Number),
+            ])
+            .declare("precision_descent", vec![
+                ParamSpec::optional("ground_clearance_target", ParamKind::Number),
+            ])
+            .declare("hook_release_sequence", vec![
+                ParamSpec::optional("weight_transfer_check", ParamKind::Boolean),
+            ]);
+        schema
+    }
+}
+
+/// Custom action parameter that is missing or cannot be parsed as declared
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParamError {
+    Missing { action_type: String, parameter: String },
+    Malformed { action_type: String, parameter: String, value: String, expected: ParamKind },
+}
+
+impl std::fmt::Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamError::Missing { action_type, parameter } =>
+                write!(f, "{} is missing parameter '{}'", action_type, parameter),
+            ParamError::Malformed { action_type, parameter, value, expected } =>
+                write!(f, "{} parameter '{}' = '{}' is not {:?}", action_type, parameter, value, expected),
+        }
+    }
+}
+
+impl MissionAction {
+    /// Check a `Custom` action's parameters against `schema`; other actions always pass
+    pub fn validate_params(&self, schema: &CustomActionSchema) -> Result<(), Vec<ParamError>> {
+        let (action_type, parameters) = match self {
+            MissionAction::Custom { action_type, parameters } => (action_type, parameters),
+            _ => return Ok(()),
+        };
+        let specs = match schema.actions.get(action_type) {
+            Some(specs) => specs,
+            None => return Ok(()),
+        };
+
+        let errors: Vec<ParamError> = specs.iter()
+            .filter_map(|spec| match parameters.get(&spec.name) {
+                None if spec.required => Some(ParamError::Missing {
+                    action_type: action_type.clone(),
+                    parameter: spec.name.clone(),
+                }),
+                Some(value) if !spec.kind.accepts(value) => Some(ParamError::Malformed {
+                    action_type: action_type.clone(),
+                    parameter: spec.name.clone(),
+                    value: value.clone(),
+                    expected: spec.kind.clone(),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 /// Video quality settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum VideoQuality {
@@ -281,6 +477,8 @@ pub struct SafetyConstraints {
     pub max_proximity_to_crowd_m: f32,
     pub emergency_landing_sites: Vec<EmergencyLandingSite>,
     pub fail_safe_procedures: Vec<String>,
+    #[serde(default)]
+    pub populated_areas: Vec<GeoBounds>, // Areas `max_proximity_to_crowd_m` is measured from
 }
 
 /// Emergency landing site
@@ -333,6 +531,7 @@ pub enum AuthorizationScope {
     EmergencyOverride,
     FleetManagement,
     Maintenance,
+    HazardousMaterials,
 }
 
 /// Time-based limits for authorization
@@ -355,18 +554,69 @@ pub struct MissionPayload {
     pub crypto: MissionCrypto,
     pub weather_snapshot: Option<WeatherSnapshot>,
     pub formation_config: Option<FormationConfiguration>, // NEW: Formation missions
+    #[serde(default)]
+    pub initial_conditions: Vec<String>, // Facts that hold before the first task runs
+    #[serde(default)]
+    pub min_firmware_version: Option<String>, // Oldest airframe firmware able to fly this mission
+}
+
+/// Re-signed revision of a mission, with the revision it replaces kept for the audit trail
+#[derive(Debug, Clone)]
+pub struct AmendedMission {
+    pub previous: MissionPayload,
+    pub new: MissionPayload,
+    pub diff: MissionDiff,
+    pub revision: u32,
+}
+
+/// Fields changed between two revisions of a mission, excluding the crypto section
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MissionDiff {
+    pub changes: Vec<FieldChange>,
+}
+
+/// One changed field; `None` marks a field or list element that was added or removed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub path: String, // e.g. `flight_plan.paths[0].waypoints[1].position.latitude`
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
 }
 
 /// Flight plan container
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FlightPlan {
-    pub paths: Vec<FlightPath>,
+    pub paths: Vec<FlightPath>, // Legs, flown in order
     pub home_location: GeoCoordinate,
     pub takeoff_procedure: Option<String>,
     pub landing_procedure: Option<String>,
     pub contingency_routes: Vec<FlightPath>,
 }
 
+/// Glideslope and clearance targets for a precision approach to a landing point
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApproachProfile {
+    pub glideslope_degrees: f32,
+    pub approach_speed_mps: f32, // Ground speed at the top of descent
+    pub final_speed_mps: f32, // Ground speed into the final waypoint
+    pub ground_clearance_m: f32, // Height above the landing point of the final waypoint
+    pub altitude_step_m: f32, // Altitude lost between consecutive waypoints
+    pub position_tolerance_m: f32,
+}
+
+impl Default for ApproachProfile {
+    fn default() -> Self {
+        Self {
+            glideslope_degrees: 6.0,
+            approach_speed_mps: 8.0,
+            final_speed_mps: 0.5,
+            ground_clearance_m: 2.0,
+            altitude_step_m: 5.0,
+            position_tolerance_m: 1.0,
+        }
+    }
+}
+
 /// Mission constraints container
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MissionConstraints {
@@ -404,6 +654,8 @@ pub struct MissionPolicies {
     pub time_limits: TimeLimits,
     pub approval_requirements: Vec<String>,
     pub emergency_procedures: Vec<EmergencyProcedure>,
+    #[serde(default)]
+    pub compliance_frameworks: Vec<String>, // Regulatory framework IDs the mission's audit trail is evaluated against
 }
 
 /// Emergency procedures
@@ -435,6 +687,8 @@ pub struct FormationConfiguration {
     pub load_distribution: LoadDistribution,
     pub communication_mesh: Vec<MeshLink>,
     pub formation_geofence: Option<GeoBounds>,
+    #[serde(default)]
+    pub min_operational_drones: usize, // Drones needed to complete the mission; 0 leaves it to load feasibility alone
 }
 
 /// Formation/drone swarm types
@@ -628,6 +882,157 @@ pub enum LoadRedistribution {
     AbortMission,       // Complete mission abort
 }
 
+/// Errors raised while reading or writing the packed mission encoding
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PackedMissionError {
+    #[error("Packed mission is empty")]
+    Empty,
+    #[error("Packed mission has format version {found}, expected {expected}")]
+    UnsupportedVersion { found: u8, expected: u8 },
+    #[error("Packed mission codec error: {0}")]
+    Codec(String),
+    #[error("Packed mission exceeds the {limit} byte limit")]
+    TooLarge { limit: u64 },
+}
+
+/// Errors raised while reconfiguring a formation after a loss
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ReconfigError {
+    #[error("Safety margin must be in [0, 1), got {0}")]
+    InvalidMargin(f32),
+    #[error("No load-bearing drones remain in formation")]
+    NoLoadBearingDrones,
+    #[error("Payload cannot be reduced without releasing it entirely")]
+    NothingCarriable,
+    #[error("Each load-bearing drone would carry {per_drone_kg} kg, above the {limit_kg} kg limit")]
+    Overloaded { per_drone_kg: f32, limit_kg: f32 },
+}
+
+/// What a drone reports it can do, as far as formation role assignment is concerned
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoleCapabilities {
+    pub max_payload_kg: f32,
+    pub relay_capable: bool, // Has a channel beyond GibberLink short range
+    pub sensor_count: usize,
+}
+
+/// A formation drone whose capabilities do not meet its assigned role
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RoleMismatch {
+    #[error("No capabilities reported for drone {drone_id}")]
+    MissingCapabilities { drone_id: String },
+    #[error("Drone {drone_id} can lift {available_kg} kg but its role requires {required_kg} kg")]
+    InsufficientPayload { drone_id: String, required_kg: f32, available_kg: f32 },
+    #[error("Drone {drone_id} has no long-range channel to relay on")]
+    NoRelayChannel { drone_id: String },
+    #[error("Drone {drone_id} carries no sensors for scouting")]
+    NoSensors { drone_id: String },
+}
+
+/// MAVLink mission commands understood by `FlightPlan::from_mavlink`
+pub mod mav_cmd {
+    pub const NAV_WAYPOINT: u16 = 16;
+    pub const NAV_LOITER_TIME: u16 = 19;
+    pub const NAV_RETURN_TO_LAUNCH: u16 = 20;
+    pub const NAV_LAND: u16 = 21;
+    pub const NAV_TAKEOFF: u16 = 22;
+    pub const DO_CHANGE_SPEED: u16 = 178;
+}
+
+/// MAVLink frames for item coordinates
+pub mod mav_frame {
+    pub const GLOBAL: u8 = 0;              // Altitude above mean sea level
+    pub const GLOBAL_RELATIVE_ALT: u8 = 3; // Altitude above home
+}
+
+/// One MAVLink mission item, as stored in QGC `.plan` files (coordinates in degrees)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MavlinkMissionItem {
+    pub seq: u16,
+    pub frame: u8,
+    pub command: u16,
+    pub param1: f32,
+    pub param2: f32,
+    pub param3: f32,
+    pub param4: f32,
+    pub x: f64, // Latitude
+    pub y: f64, // Longitude
+    pub z: f32, // Altitude, interpreted per `frame`
+    pub autocontinue: bool,
+}
+
+/// Errors raised while importing a MAVLink mission
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ImportError {
+    #[error("Mission contains no navigation items")]
+    NoWaypoints,
+    #[error("Unsupported commands (seq, command): {0:?}")]
+    UnsupportedCommands(Vec<(u16, u16)>),
+    #[error("Item {seq} uses unsupported frame {frame}")]
+    UnsupportedFrame { seq: u16, frame: u8 },
+    #[error("Mission checksum {actual:#010x} does not match expected {expected:#010x}")]
+    ChecksumMismatch { expected: u32, actual: u32 },
+}
+
+/// Command a formation controller dispatches to one drone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FormationCommand {
+    Ascend { target_altitude: f32 },
+    Land,
+}
+
+/// Command due `offset` after the start of a takeoff or landing phase
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimedCommand {
+    pub offset: Duration,
+    pub drone_id: String,
+    pub command: FormationCommand,
+}
+
+/// Errors raised while building a takeoff or landing schedule
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ScheduleError {
+    #[error("Drone {0} is sequenced but not part of the formation")]
+    UnknownDrone(String),
+    #[error("Drone {0} appears more than once in the sequence")]
+    DuplicateDrone(String),
+}
+
+/// Measured clock synchronization of a formation against its tolerance
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncQuality {
+    pub max_skew: Duration,
+    pub tolerance: Duration,
+    pub within_tolerance: bool, // False means the formation is unsafe to launch
+    pub offenders: Vec<String>, // Drones over tolerance or with no measurement
+}
+
+/// Corrective velocity commanded to one drone to return to its formation slot
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CorrectionVector {
+    pub east_mps: f32,
+    pub north_mps: f32,
+    pub up_mps: f32,
+}
+
+impl CorrectionVector {
+    /// Total correction speed
+    pub fn speed_mps(&self) -> f32 {
+        (self.east_mps.powi(2) + self.north_mps.powi(2) + self.up_mps.powi(2)).sqrt()
+    }
+
+    /// True inside the deadband, where no correction is commanded
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Proportional gain from position error (m) to corrective velocity (m/s)
+pub const FORMATION_CORRECTION_GAIN: f32 = 0.5;
+
+/// Fastest corrective velocity commanded while holding formation
+pub const MAX_FORMATION_CORRECTION_MPS: f32 = 2.0;
+
 /// Communication mesh for formation coordination
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MeshLink {
@@ -659,6 +1064,28 @@ pub struct SynchronizationConfig {
     pub takeoff_sequence: Vec<String>,    // Ordered drone takeoff list
     pub landing_sequence: Vec<String>,    // Ordered drone landing list
     pub emergency_sync_timeout_ms: u32,   // Max time for synchronization recovery
+    #[serde(default = "default_partition_tolerance_ms")]
+    pub partition_tolerance_ms: u32,      // How long a mesh partition is ridden out before acting
+}
+
+/// Partition tolerance assumed for configurations recorded before it was configurable
+pub const DEFAULT_PARTITION_TOLERANCE_MS: u32 = 3000;
+
+fn default_partition_tolerance_ms() -> u32 {
+    DEFAULT_PARTITION_TOLERANCE_MS
+}
+
+/// Formation reaction to lost mesh links
+#[derive(Debug, Clone)]
+pub enum FormationResponse {
+    /// Every drone is still reachable through the remaining links
+    Continue,
+    /// Drones are cut off but the partition is still within tolerance
+    Hold { isolated_drones: Vec<String>, remaining: Duration },
+    /// Only non-load-bearing drones are cut off; fly on without them
+    Reconfigure { dropped_drones: Vec<String> },
+    /// A load-bearing drone is cut off beyond tolerance
+    TriggerFailSafe { behavior: FailSafeBehavior, isolated_drones: Vec<String> },
 }
 
 /// Formation-specific geofencing for coordinated operations
@@ -697,6 +1124,75 @@ pub struct WeatherSnapshot {
     pub source: String,
 }
 
+/// Semantic firmware version; ordering follows semver precedence, build metadata is ignored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FirmwareVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre_release: Vec<String>, // Dot-separated identifiers after '-'
+}
+
+impl FirmwareVersion {
+    /// Parse `1.2.3`, `v1.2`, `1.2.3-rc.1+build.7`; missing minor/patch default to 0
+    pub fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches('v');
+        let version = version.split('+').next()?;
+        let (core, pre_release) = match version.split_once('-') {
+            Some((core, pre)) => (core, pre.split('.').map(str::to_string).collect()),
+            None => (version, Vec::new()),
+        };
+
+        let mut numbers = core.split('.').map(|part| part.parse::<u64>());
+        let major = numbers.next()?.ok()?;
+        let minor = numbers.next().unwrap_or(Ok(0)).ok()?;
+        let patch = numbers.next().unwrap_or(Ok(0)).ok()?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        Some(Self { major, minor, patch, pre_release })
+    }
+}
+
+impl Ord for FirmwareVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        let core = (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch));
+        if core != Ordering::Equal {
+            return core;
+        }
+
+        // A pre-release sorts before the release it precedes
+        match (self.pre_release.is_empty(), other.pre_release.is_empty()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => {}
+        }
+
+        for (ours, theirs) in self.pre_release.iter().zip(&other.pre_release) {
+            let ordering = match (ours.parse::<u64>(), theirs.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less, // Numeric identifiers sort first
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => ours.cmp(theirs),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        self.pre_release.len().cmp(&other.pre_release.len())
+    }
+}
+
+impl PartialOrd for FirmwareVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Default for MissionPayload {
     fn default() -> Self {
         Self {
@@ -711,6 +1207,8 @@ impl Default for MissionPayload {
                 drone_fingerprint: None,
                 priority: MissionPriority::Normal,
                 tags: Vec::new(),
+                revision: 0,
+                supersedes: None,
             },
             flight_plan: FlightPlan {
                 paths: Vec::new(),
@@ -740,6 +1238,7 @@ impl Default for MissionPayload {
                     max_proximity_to_crowd_m: 50.0,
                     emergency_landing_sites: Vec::new(),
                     fail_safe_procedures: vec!["RTL".to_string()],
+                    populated_areas: Vec::new(),
                 },
                 environmental: EnvironmentalConstraints {
                     max_temperature_c: 40.0,
@@ -762,6 +1261,7 @@ impl Default for MissionPayload {
                 },
                 approval_requirements: Vec::new(),
                 emergency_procedures: Vec::new(),
+                compliance_frameworks: Vec::new(),
             },
             crypto: MissionCrypto {
                 payload_signature: Vec::new(),
@@ -772,6 +1272,2940 @@ impl Default for MissionPayload {
             },
             weather_snapshot: None,
             formation_config: None, // NEW: No formation by default
+            initial_conditions: Vec::new(),
+            min_firmware_version: None,
+        }
+    }
+}
+
+impl GeoCoordinate {
+    /// Point displaced `east_m` and `north_m` meters from this one, using a local flat projection
+    pub fn offset_by(&self, east_m: f64, north_m: f64) -> GeoCoordinate {
+        const METERS_PER_DEGREE: f64 = 111_320.0;
+        let meters_per_degree_lon = METERS_PER_DEGREE * self.latitude.to_radians().cos().max(1e-6);
+        GeoCoordinate {
+            latitude: self.latitude + north_m / METERS_PER_DEGREE,
+            longitude: self.longitude + east_m / meters_per_degree_lon,
+            altitude_msl: self.altitude_msl,
+        }
+    }
+
+    /// Initial great-circle bearing to `other` in degrees (0 = north, clockwise)
+    pub fn bearing_to(&self, other: &GeoCoordinate) -> f32 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+        (y.atan2(x).to_degrees().rem_euclid(360.0)) as f32
+    }
+
+    /// Haversine ground distance to `other` in meters, ignoring altitude
+    pub fn distance_to(&self, other: &GeoCoordinate) -> f32 {
+        const EARTH_RADIUS_M: f64 = 6_371_000.0;
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        (2.0 * EARTH_RADIUS_M * a.sqrt().asin()) as f32
+    }
+
+    /// Point `fraction` (0.0 to 1.0) of the way to `other` along the great circle,
+    /// with altitude interpolated linearly
+    pub fn intermediate(&self, other: &GeoCoordinate, fraction: f64) -> GeoCoordinate {
+        let altitude_msl = self.altitude_msl + (other.altitude_msl - self.altitude_msl) * fraction as f32;
+        let (lat1, lon1) = (self.latitude.to_radians(), self.longitude.to_radians());
+        let (lat2, lon2) = (other.latitude.to_radians(), other.longitude.to_radians());
+        let angular_distance = self.distance_to(other) as f64 / 6_371_000.0;
+
+        // Practically coincident points: the slerp weights are numerically unstable
+        if angular_distance < 1e-9 {
+            return GeoCoordinate { latitude: self.latitude, longitude: self.longitude, altitude_msl };
+        }
+
+        let a = ((1.0 - fraction) * angular_distance).sin() / angular_distance.sin();
+        let b = (fraction * angular_distance).sin() / angular_distance.sin();
+        let x = a * lat1.cos() * lon1.cos() + b * lat2.cos() * lon2.cos();
+        let y = a * lat1.cos() * lon1.sin() + b * lat2.cos() * lon2.sin();
+        let z = a * lat1.sin() + b * lat2.sin();
+
+        GeoCoordinate {
+            latitude: z.atan2((x * x + y * y).sqrt()).to_degrees(),
+            longitude: y.atan2(x).to_degrees(),
+            altitude_msl,
+        }
+    }
+
+    /// Approximate distance in meters to the segment `start`-`end`, using a local flat projection
+    fn distance_to_segment_m(&self, start: &GeoCoordinate, end: &GeoCoordinate) -> f32 {
+        const METERS_PER_DEGREE: f64 = 111_320.0;
+        let scale_x = METERS_PER_DEGREE * start.latitude.to_radians().cos();
+        // Longitude differences wrap so a segment across the antimeridian stays short
+        let project = |coord: &GeoCoordinate| (
+            normalize_longitude(coord.longitude - start.longitude) * scale_x,
+            (coord.latitude - start.latitude) * METERS_PER_DEGREE,
+        );
+        let (px, py) = project(self);
+        let (ex, ey) = project(end);
+
+        let length_sq = ex * ex + ey * ey;
+        let t = if length_sq > 0.0 { ((px * ex + py * ey) / length_sq).clamp(0.0, 1.0) } else { 0.0 };
+        ((px - t * ex).powi(2) + (py - t * ey).powi(2)).sqrt() as f32
+    }
+}
+
+/// Corner too tight to fly at the path's turn radius, found by `FlightPath::infeasible_turns`
+#[derive(Debug, Clone, PartialEq)]
+pub struct InfeasibleTurn {
+    pub waypoint_id: u32,
+    pub turn_degrees: f32,    // Course change at the waypoint
+    pub turn_radius_m: f32,
+    pub required_leg_m: f32,  // Straight leg the arc consumes on each side of the waypoint
+    pub available_leg_m: f32, // Half the shorter adjacent leg; the rest belongs to the neighbouring turn
+}
+
+/// Local geometry of the turn at an interior waypoint, in meters east/north of it
+struct TurnGeometry {
+    turn_rad: f64,
+    radius_m: f64,
+    tangent_m: f64, // Distance from the waypoint to where the arc meets each leg
+    available_m: f64,
+    back: (f64, f64),  // Unit vector towards the previous waypoint
+    ahead: (f64, f64), // Unit vector towards the next waypoint
+}
+
+impl FlightPath {
+    /// Tightest radius the path can turn at `speed_mps`: `min_turn_radius_m`, or the radius of a
+    /// coordinated turn at `max_bank_angle_degrees` if that is larger. None when neither is set.
+    pub fn turn_radius_m(&self, speed_mps: f32) -> Option<f32> {
+        let banked = self.max_bank_angle_degrees
+            .filter(|bank| *bank > 0.0 && *bank < 90.0)
+            .map(|bank| speed_mps.powi(2) / (STANDARD_GRAVITY_MPS2 * bank.to_radians().tan()));
+        match (self.min_turn_radius_m, banked) {
+            (Some(minimum), Some(banked)) => Some(minimum.max(banked)),
+            (minimum, banked) => minimum.or(banked),
+        }
+    }
+
+    /// Turn at interior waypoint `index`, if the path has a turn radius and the course changes
+    fn turn_at(&self, index: usize) -> Option<TurnGeometry> {
+        let (previous, corner, next) = (&self.waypoints[index - 1], &self.waypoints[index], &self.waypoints[index + 1]);
+        let speed_mps = corner.speed_limit_mps.unwrap_or(self.max_speed_mps).max(0.0);
+        let radius_m = self.turn_radius_m(speed_mps)? as f64;
+
+        let unit = |bearing: f32| {
+            let bearing = (bearing as f64).to_radians();
+            (bearing.sin(), bearing.cos())
+        };
+        let back = unit(corner.position.bearing_to(&previous.position));
+        let ahead = unit(corner.position.bearing_to(&next.position));
+        // 0 when carrying straight on, pi for a reversal
+        let turn_rad = std::f64::consts::PI - (back.0 * ahead.0 + back.1 * ahead.1).clamp(-1.0, 1.0).acos();
+        if turn_rad < MIN_SMOOTHED_TURN_DEGREES.to_radians() {
+            return None;
+        }
+
+        let shorter_leg_m = corner.position.distance_to(&previous.position).min(corner.position.distance_to(&next.position));
+        Some(TurnGeometry {
+            turn_rad,
+            radius_m,
+            tangent_m: radius_m * (turn_rad / 2.0).tan(),
+            available_m: shorter_leg_m as f64 / 2.0,
+            back,
+            ahead,
+        })
+    }
+
+    /// Waypoints whose turn needs more straight leg than is available at the path's turn radius
+    pub fn infeasible_turns(&self) -> Vec<InfeasibleTurn> {
+        (1..self.waypoints.len().saturating_sub(1))
+            .filter_map(|index| self.turn_at(index).map(|turn| (index, turn)))
+            .filter(|(_, turn)| turn.tangent_m > turn.available_m)
+            .map(|(index, turn)| InfeasibleTurn {
+                waypoint_id: self.waypoints[index].id,
+                turn_degrees: turn.turn_rad.to_degrees() as f32,
+                turn_radius_m: turn.radius_m as f32,
+                required_leg_m: turn.tangent_m as f32,
+                available_leg_m: turn.available_m as f32,
+            })
+            .collect()
+    }
+
+    /// The path as flown: each corner is replaced by an arc at the path's turn radius,
+    /// tangent to both legs, with points at most `TURN_ARC_STEP_DEGREES` apart.
+    ///
+    /// A corner too tight for the radius (see `infeasible_turns`) gets the widest arc that
+    /// fits; a path with no turn radius is returned as its waypoints.
+    pub fn smooth_turns(&self) -> Vec<GeoCoordinate> {
+        let mut points: Vec<GeoCoordinate> = self.waypoints.first()
+            .map(|waypoint| vec![waypoint.position.clone()])
+            .unwrap_or_default();
+
+        for index in 1..self.waypoints.len().saturating_sub(1) {
+            let corner = &self.waypoints[index].position;
+            let turn = match self.turn_at(index) {
+                Some(turn) => turn,
+                None => {
+                    points.push(corner.clone());
+                    continue;
+                }
+            };
+
+            let bisector = (turn.back.0 + turn.ahead.0, turn.back.1 + turn.ahead.1);
+            let bisector_length = bisector.0.hypot(bisector.1);
+            // A reversal has no arc tangent to both legs
+            if bisector_length < 1e-9 {
+                points.push(corner.clone());
+                continue;
+            }
+
+            let tangent_m = turn.tangent_m.min(turn.available_m);
+            let radius_m = tangent_m / (turn.turn_rad / 2.0).tan();
+            let center_distance_m = radius_m / (turn.turn_rad / 2.0).cos();
+            let center = (bisector.0 / bisector_length * center_distance_m, bisector.1 / bisector_length * center_distance_m);
+            let angle_to = |(east, north): (f64, f64)| (north - center.1).atan2(east - center.0);
+
+            let start = angle_to((turn.back.0 * tangent_m, turn.back.1 * tangent_m));
+            let end = angle_to((turn.ahead.0 * tangent_m, turn.ahead.1 * tangent_m));
+            let sweep = (end - start + std::f64::consts::PI).rem_euclid(2.0 * std::f64::consts::PI) - std::f64::consts::PI;
+            let steps = (sweep.abs().to_degrees() / TURN_ARC_STEP_DEGREES).ceil().max(1.0) as usize;
+
+            points.extend((0..=steps).map(|step| {
+                let angle = start + sweep * step as f64 / steps as f64;
+                corner.offset_by(center.0 + radius_m * angle.cos(), center.1 + radius_m * angle.sin())
+            }));
+        }
+
+        if self.waypoints.len() > 1 {
+            points.extend(self.waypoints.last().map(|waypoint| waypoint.position.clone()));
+        }
+        points
+    }
+
+    /// Densify the path into points at most `step_m` apart along each leg.
+    ///
+    /// Steep legs get extra points so that no step climbs or descends more than the
+    /// path's rate limits allow while covering it at the leg's ground speed.
+    pub fn interpolate(&self, step_m: f32) -> Vec<GeoCoordinate> {
+        let step_m = step_m.max(1.0);
+        let mut points: Vec<GeoCoordinate> = self.waypoints.first()
+            .map(|waypoint| vec![waypoint.position.clone()])
+            .unwrap_or_default();
+
+        for leg in self.waypoints.windows(2) {
+            points.extend(self.interpolate_segment(&leg[0], &leg[1], step_m));
+        }
+        points
+    }
+
+    /// Points at most `step_m` apart from `from` to `to` flown at this path's limits, `from` excluded
+    fn interpolate_segment(&self, from: &Waypoint, to: &Waypoint, step_m: f32) -> Vec<GeoCoordinate> {
+        let (start, end) = (&from.position, &to.position);
+        let horizontal_steps = (start.distance_to(end) / step_m).ceil();
+
+        let ground_speed = to.speed_limit_mps.unwrap_or(self.max_speed_mps).max(0.1);
+        let climb = end.altitude_msl - start.altitude_msl;
+        let rate = if climb >= 0.0 { self.climb_rate_max_mps } else { self.descent_rate_max_mps };
+        let vertical_per_step = rate.max(0.1) * step_m / ground_speed;
+        let vertical_steps = (climb.abs() / vertical_per_step).ceil();
+
+        let steps = horizontal_steps.max(vertical_steps).max(1.0) as usize;
+        (1..=steps).map(|i| start.intermediate(end, i as f64 / steps as f64)).collect()
+    }
+
+    /// Time to fly the path at each leg's speed limit, loiters included
+    pub fn flight_time(&self) -> Duration {
+        let cruise_s: f32 = self.waypoints.windows(2)
+            .map(|leg| leg[0].position.distance_to(&leg[1].position) / leg[1].speed_limit_mps.unwrap_or(self.max_speed_mps).max(0.1))
+            .sum();
+        let loiter_s: u32 = self.waypoints.iter().filter_map(|waypoint| waypoint.loiter_time_seconds).sum();
+        Duration::from_secs_f32(cruise_s + loiter_s as f32)
+    }
+
+    /// Speeds, rates, altitudes and tolerances outside what a drone can physically fly
+    pub fn physical_limit_violations(&self) -> Vec<MissionValidationIssue> {
+        let mut issues = Vec::new();
+        let mut check = |waypoint_id: Option<u32>, field: &str, value: f32, valid: bool, limit: String| {
+            if !valid {
+                issues.push(MissionValidationIssue::PhysicalLimit {
+                    path_id: self.id,
+                    waypoint_id,
+                    field: field.to_string(),
+                    value,
+                    limit,
+                });
+            }
+        };
+
+        check(None, "max_speed_mps", self.max_speed_mps, self.max_speed_mps >= 0.0, "must be non-negative".to_string());
+        check(None, "min_speed_mps", self.min_speed_mps, self.min_speed_mps >= 0.0, "must be non-negative".to_string());
+        check(None, "min_speed_mps", self.min_speed_mps, self.min_speed_mps <= self.max_speed_mps.max(0.0),
+            format!("must not exceed max_speed_mps ({})", self.max_speed_mps));
+        for (field, rate) in [("climb_rate_max_mps", self.climb_rate_max_mps), ("descent_rate_max_mps", self.descent_rate_max_mps)] {
+            check(None, field, rate, rate > 0.0 && rate <= MAX_VERTICAL_RATE_MPS,
+                format!("must be within (0, {}] m/s", MAX_VERTICAL_RATE_MPS));
+            // Vertical speed is a component of total speed
+            check(None, field, rate, rate <= self.max_speed_mps.max(0.0),
+                format!("must not exceed max_speed_mps ({})", self.max_speed_mps));
+        }
+
+        for waypoint in &self.waypoints {
+            let id = Some(waypoint.id);
+            // Sites below sea level are real (the Dead Sea shore is about -430 m), so only the value itself is checked
+            check(id, "altitude_msl", waypoint.position.altitude_msl, waypoint.position.altitude_msl.is_finite(), "must be finite".to_string());
+            check(id, "position_tolerance_m", waypoint.position_tolerance_m, waypoint.position_tolerance_m > 0.0, "must be positive".to_string());
+            check(id, "altitude_tolerance_m", waypoint.altitude_tolerance_m, waypoint.altitude_tolerance_m > 0.0, "must be positive".to_string());
+            check(id, "heading_tolerance_degrees", waypoint.heading_tolerance_degrees, waypoint.heading_tolerance_degrees > 0.0, "must be positive".to_string());
+            if let Some(speed_limit) = waypoint.speed_limit_mps {
+                check(id, "speed_limit_mps", speed_limit, speed_limit >= 0.0, "must be non-negative".to_string());
+            }
+            if waypoint.loiter_time_seconds.is_some_and(|seconds| seconds > 0) {
+                let radius = waypoint.loiter_radius_m.unwrap_or(0.0);
+                check(id, "loiter_radius_m", radius, radius > 0.0, "must be positive when loiter_time_seconds is set".to_string());
+            }
+        }
+        issues
+    }
+}
+
+impl GeoBounds {
+    /// True if the box crosses the antimeridian (west edge east of the east edge)
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    /// Longitude spans as non-wrapping intervals
+    fn longitude_spans(&self) -> Vec<(f64, f64)> {
+        if self.crosses_antimeridian() {
+            vec![(self.west, 180.0), (-180.0, self.east)]
+        } else {
+            vec![(self.west, self.east)]
+        }
+    }
+
+    /// Whether a coordinate lies inside the box, edges and altitude limits inclusive.
+    /// Longitudes are normalized to [-180, 180]; at a pole every longitude meets, so a
+    /// pole point is inside any box that reaches that pole.
+    pub fn contains(&self, coord: &GeoCoordinate) -> bool {
+        let at_pole = coord.latitude.abs() >= 90.0;
+        let longitude = normalize_longitude(coord.longitude);
+        coord.latitude >= self.south
+            && coord.latitude <= self.north
+            && coord.altitude_msl >= self.min_altitude
+            && coord.altitude_msl <= self.max_altitude
+            && (at_pole || self.longitude_spans().iter().any(|&(west, east)| longitude >= west && longitude <= east))
+    }
+
+    /// Reason the box cannot be used for containment checks, if any: latitudes beyond the
+    /// poles, longitudes outside [-180, 180], south above north, or inverted altitudes
+    pub fn validity_error(&self) -> Option<String> {
+        if !(-90.0..=90.0).contains(&self.south) || !(-90.0..=90.0).contains(&self.north) {
+            return Some(format!("latitudes {}..{} extend past a pole", self.south, self.north));
+        }
+        if self.south > self.north {
+            return Some(format!("south edge {} is north of north edge {}", self.south, self.north));
+        }
+        if !(-180.0..=180.0).contains(&self.west) || !(-180.0..=180.0).contains(&self.east) {
+            return Some(format!("longitudes {}..{} are outside [-180, 180]", self.west, self.east));
+        }
+        if self.min_altitude > self.max_altitude {
+            return Some(format!("min altitude {} exceeds max altitude {}", self.min_altitude, self.max_altitude));
+        }
+        None
+    }
+
+    /// Whether two boxes share any volume, touching edges included
+    pub fn intersects(&self, other: &GeoBounds) -> bool {
+        let latitude_overlap = self.south <= other.north && other.south <= self.north;
+        let altitude_overlap = self.min_altitude <= other.max_altitude && other.min_altitude <= self.max_altitude;
+        let longitude_overlap = self.longitude_spans().iter().any(|&(west, east)| {
+            other.longitude_spans().iter().any(|&(other_west, other_east)| west <= other_east && other_west <= east)
+        });
+        latitude_overlap && altitude_overlap && longitude_overlap
+    }
+
+    /// Grow the box by `margin_m` on every side, including altitude
+    pub fn expand(&self, margin_m: f32) -> GeoBounds {
+        const METERS_PER_DEGREE: f64 = 111_320.0;
+        let margin = margin_m as f64;
+        let north = (self.north + margin / METERS_PER_DEGREE).min(90.0);
+        let south = (self.south - margin / METERS_PER_DEGREE).max(-90.0);
+
+        // Longitude degrees shrink with latitude; use the widest-latitude edge so the margin is never short
+        let widest_latitude = north.abs().max(south.abs()).min(89.9);
+        let lon_margin = margin / (METERS_PER_DEGREE * widest_latitude.to_radians().cos());
+        let span = if self.crosses_antimeridian() { self.east + 360.0 - self.west } else { self.east - self.west };
+
+        let (west, east) = if span + 2.0 * lon_margin >= 360.0 {
+            (-180.0, 180.0)
+        } else {
+            let wrap = |longitude: f64| if longitude < -180.0 { longitude + 360.0 } else if longitude > 180.0 { longitude - 360.0 } else { longitude };
+            (wrap(self.west - lon_margin), wrap(self.east + lon_margin))
+        };
+
+        GeoBounds {
+            north,
+            south,
+            east,
+            west,
+            min_altitude: self.min_altitude - margin_m,
+            max_altitude: self.max_altitude + margin_m,
+        }
+    }
+}
+
+impl FlightPlan {
+    /// Consecutive waypoint pairs across all legs in flight order, including the
+    /// transition from one leg's last waypoint to the next leg's first. Each
+    /// segment is paired with the leg it flies into.
+    pub fn segments(&self) -> impl Iterator<Item = (&FlightPath, &Waypoint, &Waypoint)> + '_ {
+        let mut previous: Option<&Waypoint> = None;
+        self.paths.iter()
+            .flat_map(|path| path.waypoints.iter().map(move |waypoint| (path, waypoint)))
+            .filter_map(move |(path, waypoint)| {
+                let segment = previous.map(|from| (path, from, waypoint));
+                previous = Some(waypoint);
+                segment
+            })
+    }
+
+    /// Densify the route into points at most `step_m` apart, grouped by leg in flight order.
+    /// Each leg's samples include the transition flown into it from the previous leg.
+    pub fn interpolate(&self, step_m: f32) -> Vec<(&FlightPath, Vec<GeoCoordinate>)> {
+        let step_m = step_m.max(1.0);
+        let mut legs: Vec<(&FlightPath, Vec<GeoCoordinate>)> = self.paths.iter()
+            .find_map(|path| path.waypoints.first().map(|first| (path, vec![first.position.clone()])))
+            .into_iter()
+            .collect();
+
+        for (path, from, to) in self.segments() {
+            if !legs.last().map_or(false, |(leg, _)| std::ptr::eq(*leg, path)) {
+                legs.push((path, Vec::new()));
+            }
+            if let Some((_, samples)) = legs.last_mut() {
+                samples.extend(path.interpolate_segment(from, to, step_m));
+            }
+        }
+        legs
+    }
+
+    /// Ground distance over every leg and the transitions between them
+    pub fn total_distance_m(&self) -> f32 {
+        self.segments()
+            .map(|(_, from, to)| from.position.distance_to(&to.position))
+            .sum()
+    }
+
+    /// Expected position every `step` after `start`, flying each segment at its speed and
+    /// climb limits and holding at waypoints for their loiter time
+    pub fn positions_over_time(&self, start: SystemTime, step: Duration) -> Vec<(SystemTime, GeoCoordinate)> {
+        // Seconds after start at which each waypoint is reached (or left, after loitering)
+        let mut keyframes: Vec<(f32, &GeoCoordinate)> = Vec::new();
+        if let Some(first) = self.paths.iter().find_map(|path| path.waypoints.first()) {
+            keyframes.push((0.0, &first.position));
+        }
+        for (path, from, to) in self.segments() {
+            let mut elapsed_s = keyframes.last().map_or(0.0, |(t, _)| *t);
+            if let Some(loiter_s) = from.loiter_time_seconds.filter(|s| *s > 0) {
+                elapsed_s += loiter_s as f32;
+                keyframes.push((elapsed_s, &from.position));
+            }
+            let speed_mps = to.speed_limit_mps
+                .map_or(path.max_speed_mps, |limit| limit.min(path.max_speed_mps))
+                .max(f32::EPSILON);
+            let climb_m = to.position.altitude_msl - from.position.altitude_msl;
+            let vertical_rate = if climb_m >= 0.0 { path.climb_rate_max_mps } else { path.descent_rate_max_mps };
+            let travel_s = (from.position.distance_to(&to.position) / speed_mps).max(climb_m.abs() / vertical_rate.max(f32::EPSILON));
+            keyframes.push((elapsed_s + travel_s, &to.position));
+        }
+
+        let Some(&(end_s, _)) = keyframes.last() else {
+            return Vec::new();
+        };
+        let step_s = step.as_secs_f32().max(0.001);
+        let mut positions = Vec::new();
+        let mut index = 0;
+        let mut t = 0.0f32;
+        while t <= end_s {
+            while index + 1 < keyframes.len() && keyframes[index + 1].0 < t {
+                index += 1;
+            }
+            let (from_s, from) = keyframes[index];
+            let position = match keyframes.get(index + 1) {
+                Some(&(to_s, to)) if to_s > from_s => from.intermediate(to, ((t - from_s) / (to_s - from_s)).clamp(0.0, 1.0) as f64),
+                _ => from.clone(),
+            };
+            positions.push((start + Duration::from_secs_f32(t), position));
+            t += step_s;
+        }
+        positions
+    }
+
+    /// Descent from the end of the plan (home if it has no waypoints) to `ground_clearance_m`
+    /// above `target`, along the profile's glideslope on the inbound course. Speeds taper from
+    /// the approach to the final speed and are capped so no leg descends faster than the last
+    /// leg's `descent_rate_max_mps`. A start already inside the glideslope descends steeply
+    /// near it rather than overshooting the target.
+    pub fn generate_approach(&self, target: &GeoCoordinate, profile: ApproachProfile) -> Vec<Waypoint> {
+        let last = self.paths.iter().rev().find_map(|path| path.waypoints.last().map(|waypoint| (path, waypoint)));
+        let start = last.map_or(&self.home_location, |(_, waypoint)| &waypoint.position);
+        let descent_rate = last.map_or(MAX_VERTICAL_RATE_MPS, |(path, _)| path.descent_rate_max_mps).max(f32::EPSILON);
+        let first_id = self.paths.iter()
+            .flat_map(|path| path.waypoints.iter())
+            .map(|waypoint| waypoint.id + 1)
+            .max()
+            .unwrap_or(0);
+
+        let final_msl = target.altitude_msl + profile.ground_clearance_m;
+        let height_m = (start.altitude_msl - final_msl).max(0.0);
+        let course = start.bearing_to(target);
+        let available_run_m = start.distance_to(target);
+        let glideslope_tan = profile.glideslope_degrees.clamp(0.5, 89.0).to_radians().tan();
+        let steps = (height_m / profile.altitude_step_m.max(0.1)).ceil().max(1.0) as usize;
+
+        // (distance before the target, height above the final waypoint) of each waypoint
+        let points: Vec<(f32, f32)> = (0..=steps)
+            .map(|k| {
+                let height = height_m * (1.0 - k as f32 / steps as f32);
+                ((height / glideslope_tan).min(available_run_m), height)
+            })
+            .collect();
+
+        let (sin, cos) = (course as f64).to_radians().sin_cos();
+        let mut waypoints = Vec::new();
+        let mut previous = (available_run_m, height_m);
+        for (k, &(run_m, height)) in points.iter().enumerate() {
+            // Skip a top of descent that coincides with the start
+            if k == 0 && (previous.0 - run_m) < profile.position_tolerance_m {
+                continue;
+            }
+            let fraction = k as f32 / steps as f32;
+            let mut speed = profile.approach_speed_mps + (profile.final_speed_mps - profile.approach_speed_mps) * fraction;
+            let (leg_run_m, leg_drop_m) = (previous.0 - run_m, previous.1 - height);
+            if leg_drop_m > 0.0 {
+                // A vertical-only leg is flown no faster than the descent rate itself
+                let cap = if leg_run_m > 0.0 { descent_rate * leg_run_m / leg_drop_m } else { descent_rate };
+                speed = speed.min(cap);
+            }
+
+            let mut position = target.offset_by(-(run_m as f64) * sin, -(run_m as f64) * cos);
+            position.altitude_msl = final_msl + height;
+            waypoints.push(Waypoint {
+                id: first_id + waypoints.len() as u32,
+                position,
+                position_tolerance_m: profile.position_tolerance_m,
+                altitude_tolerance_m: profile.position_tolerance_m,
+                loiter_time_seconds: None,
+                loiter_radius_m: None,
+                speed_limit_mps: Some(speed),
+                heading_required_degrees: Some(course),
+                heading_tolerance_degrees: 10.0,
+            });
+            previous = (run_m, height);
+        }
+        waypoints
+    }
+
+    /// Import a MAVLink mission. Waypoints, timed loiters, takeoff and landing become waypoints
+    /// of a single leg, with acceptance radii kept as position tolerances; return-to-launch
+    /// adds a final waypoint at home. Home is the first navigation item, and relative
+    /// altitudes are converted using its altitude. Every unsupported command is reported.
+    pub fn from_mavlink(items: &[MavlinkMissionItem]) -> Result<FlightPlan, ImportError> {
+        Self::from_mavlink_with_control_points(items).map(|(plan, _)| plan)
+    }
+
+    /// `from_mavlink`, rejecting the mission if its checksum differs from `expected_checksum`
+    pub fn from_mavlink_verified(items: &[MavlinkMissionItem], expected_checksum: u32) -> Result<FlightPlan, ImportError> {
+        let actual = Self::mavlink_checksum(items);
+        if actual != expected_checksum {
+            return Err(ImportError::ChecksumMismatch { expected: expected_checksum, actual });
+        }
+        Self::from_mavlink(items)
+    }
+
+    /// CRC-32 over each item's frame, command, parameters and coordinates, in order
+    pub fn mavlink_checksum(items: &[MavlinkMissionItem]) -> u32 {
+        let mut hasher = crc32fast::Hasher::new();
+        for item in items {
+            hasher.update(&[item.frame]);
+            hasher.update(&item.command.to_le_bytes());
+            for param in [item.param1, item.param2, item.param3, item.param4] {
+                hasher.update(&param.to_le_bytes());
+            }
+            hasher.update(&item.x.to_le_bytes());
+            hasher.update(&item.y.to_le_bytes());
+            hasher.update(&item.z.to_le_bytes());
+        }
+        hasher.finalize()
+    }
+
+    /// `from_mavlink`, also returning a `ReturnToBase` control point for each return-to-launch item
+    pub fn from_mavlink_with_control_points(items: &[MavlinkMissionItem]) -> Result<(FlightPlan, Vec<ControlPoint>), ImportError> {
+        const DEFAULT_ACCEPTANCE_RADIUS_M: f32 = 2.0;
+        const ALTITUDE_TOLERANCE_M: f32 = 1.0;
+
+        let unsupported: Vec<(u16, u16)> = items.iter()
+            .filter(|item| !matches!(item.command,
+                mav_cmd::NAV_WAYPOINT | mav_cmd::NAV_LOITER_TIME | mav_cmd::NAV_RETURN_TO_LAUNCH
+                | mav_cmd::NAV_LAND | mav_cmd::NAV_TAKEOFF | mav_cmd::DO_CHANGE_SPEED))
+            .map(|item| (item.seq, item.command))
+            .collect();
+        if !unsupported.is_empty() {
+            return Err(ImportError::UnsupportedCommands(unsupported));
+        }
+
+        let is_positional = |item: &&MavlinkMissionItem| !matches!(item.command, mav_cmd::NAV_RETURN_TO_LAUNCH | mav_cmd::DO_CHANGE_SPEED);
+        let first = items.iter().find(is_positional).ok_or(ImportError::NoWaypoints)?;
+        let home_altitude = if first.frame == mav_frame::GLOBAL { first.z } else { 0.0 };
+        let position = |item: &MavlinkMissionItem| -> Result<GeoCoordinate, ImportError> {
+            let altitude_msl = match item.frame {
+                mav_frame::GLOBAL => item.z,
+                mav_frame::GLOBAL_RELATIVE_ALT => home_altitude + item.z,
+                frame => return Err(ImportError::UnsupportedFrame { seq: item.seq, frame }),
+            };
+            Ok(GeoCoordinate { latitude: item.x, longitude: item.y, altitude_msl })
+        };
+        let home_location = position(first)?;
+
+        let mut waypoints: Vec<Waypoint> = Vec::new();
+        let mut control_points = Vec::new();
+        let mut speed_limit_mps = None;
+        let (mut takeoff_procedure, mut landing_procedure) = (None, None);
+        for item in items {
+            let (position, acceptance_radius_m, loiter) = match item.command {
+                mav_cmd::DO_CHANGE_SPEED => {
+                    speed_limit_mps = (item.param2 > 0.0).then_some(item.param2);
+                    continue;
+                }
+                mav_cmd::NAV_RETURN_TO_LAUNCH => {
+                    control_points.push(ControlPoint::ReturnToBase {
+                        id: item.seq as u32,
+                        home_location: home_location.clone(),
+                        abort_conditions: Vec::new(),
+                    });
+                    landing_procedure = Some("return_to_launch".to_string());
+                    (home_location.clone(), 0.0, None)
+                }
+                mav_cmd::NAV_WAYPOINT => {
+                    let hold = (item.param1 > 0.0).then(|| (item.param1 as u32, None));
+                    (position(item)?, item.param2, hold)
+                }
+                mav_cmd::NAV_LOITER_TIME => (position(item)?, 0.0, Some((item.param1 as u32, Some(item.param3.abs())))),
+                mav_cmd::NAV_TAKEOFF => {
+                    takeoff_procedure = Some(format!("takeoff to {:.1} m", item.z));
+                    (position(item)?, 0.0, None)
+                }
+                _ => {
+                    landing_procedure = Some("land".to_string());
+                    (position(item)?, 0.0, None)
+                }
+            };
+
+            waypoints.push(Waypoint {
+                id: item.seq as u32,
+                position,
+                position_tolerance_m: if acceptance_radius_m > 0.0 { acceptance_radius_m } else { DEFAULT_ACCEPTANCE_RADIUS_M },
+                altitude_tolerance_m: ALTITUDE_TOLERANCE_M,
+                loiter_time_seconds: loiter.map(|(seconds, _)| seconds),
+                // A hold at a plain waypoint is flown within its acceptance radius
+                loiter_radius_m: loiter.map(|(_, radius)| radius.unwrap_or(acceptance_radius_m.max(DEFAULT_ACCEPTANCE_RADIUS_M))),
+                speed_limit_mps,
+                heading_required_degrees: None,
+                heading_tolerance_degrees: 180.0,
+            });
+        }
+
+        let path = FlightPath {
+            id: 1,
+            waypoints,
+            max_speed_mps: 15.0,
+            min_speed_mps: 0.0,
+            climb_rate_max_mps: 3.0,
+            descent_rate_max_mps: 2.0,
+            max_bank_angle_degrees: None,
+            min_turn_radius_m: None,
+            corridor_bounds: None,
+            navigation_mode: NavigationMode::Gps,
+        };
+        let plan = FlightPlan {
+            paths: vec![path],
+            home_location,
+            takeoff_procedure,
+            landing_procedure,
+            contingency_routes: Vec::new(),
+        };
+        Ok((plan, control_points))
+    }
+
+    /// Legs whose start is farther from the previous leg's end than either waypoint's tolerance
+    pub fn leg_discontinuities(&self) -> Vec<MissionValidationIssue> {
+        self.paths.windows(2)
+            .filter_map(|legs| {
+                let end = legs[0].waypoints.last()?;
+                let start = legs[1].waypoints.first()?;
+                let gap_m = end.position.distance_to(&start.position);
+                let tolerance_m = end.position_tolerance_m.max(start.position_tolerance_m);
+                (gap_m > tolerance_m).then(|| MissionValidationIssue::DiscontinuousLegs {
+                    from_path_id: legs[0].id,
+                    to_path_id: legs[1].id,
+                    gap_m,
+                })
+            })
+            .collect()
+    }
+}
+
+impl VideoQuality {
+    /// Typical encoded bitrate in megabits per second
+    pub fn bitrate_mbps(&self) -> f32 {
+        match self {
+            VideoQuality::Low => 5.0,
+            VideoQuality::Medium => 10.0,
+            VideoQuality::High => 45.0,
+            VideoQuality::Ultra => 100.0,
+        }
+    }
+}
+
+impl MissionAction {
+    /// Scope the operator must grant for the drone to perform this action
+    pub fn required_scope(&self) -> Option<AuthorizationScope> {
+        match self {
+            MissionAction::Handoff { .. } | MissionAction::BeaconSignal { .. } => Some(AuthorizationScope::Networking),
+            MissionAction::DeployPayload { .. } => Some(AuthorizationScope::Coupling),
+            _ => None,
         }
     }
+
+    /// Approximate data produced by the action, in megabytes
+    pub fn estimated_data_volume_mb(&self) -> f32 {
+        const IMAGE_SIZE_MB: f32 = 8.0;
+        match self {
+            MissionAction::RecordVideo { duration_seconds, quality, .. } => quality.bitrate_mbps() * *duration_seconds as f32 / 8.0,
+            MissionAction::CaptureImage { count, .. } => *count as f32 * IMAGE_SIZE_MB,
+            MissionAction::Handoff { handover_data, .. } => handover_data.len() as f32 / 1_000_000.0,
+            _ => 0.0,
+        }
+    }
+}
+
+impl MissionTask {
+    /// Approximate data produced by all of the task's actions, in megabytes
+    pub fn estimated_data_volume_mb(&self) -> f32 {
+        self.actions.iter().map(MissionAction::estimated_data_volume_mb).sum()
+    }
+}
+
+impl GeofenceZone {
+    /// Whether a position satisfies this zone
+    pub fn permits(&self, position: &GeoCoordinate) -> bool {
+        let applies = |bounds: &Option<GeoBounds>| bounds.as_ref().map_or(true, |bounds| {
+            let mut horizontal = bounds.clone();
+            horizontal.min_altitude = f32::MIN;
+            horizontal.max_altitude = f32::MAX;
+            horizontal.contains(position)
+        });
+
+        match self {
+            GeofenceZone::KeepOut { bounds, .. } => !bounds.contains(position),
+            GeofenceZone::KeepIn { bounds, .. } => bounds.contains(position),
+            GeofenceZone::AltitudeFloor { altitude_msl, bounds } => !applies(bounds) || position.altitude_msl >= *altitude_msl,
+            GeofenceZone::AltitudeCeiling { altitude_msl, bounds } => !applies(bounds) || position.altitude_msl <= *altitude_msl,
+            GeofenceZone::Corridor { waypoints, width_m } => match waypoints.len() {
+                0 => true,
+                1 => position.distance_to(&waypoints[0]) <= width_m / 2.0,
+                _ => waypoints.windows(2)
+                    .any(|segment| position.distance_to_segment_m(&segment[0], &segment[1]) <= width_m / 2.0),
+            },
+        }
+    }
+}
+
+/// First point of a path found outside what a geofence zone permits
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GeofenceBreach {
+    pub zone_index: usize, // Index into `constraints.geofencing`
+    pub path_id: u32,
+    pub position: GeoCoordinate,
+}
+
+/// Part of a formation's footprint checked against geofences
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FootprintElement {
+    Drone(String),
+    /// Corner of the payload footprint widened by sling swing: front-left, front-right, rear-right, rear-left
+    PayloadCorner(usize),
+}
+
+/// Footprint element of a formation outside what a geofence zone permits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormationGeofenceBreach {
+    pub zone_index: usize, // Index into the zones checked
+    pub element: FootprintElement,
+    pub position: GeoCoordinate,
+}
+
+/// Timed control point the route cannot honour, found by `MissionPayload::check_time_feasibility`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimeWindowIssue {
+    /// No waypoint on the route reaches the control point's location
+    NotOnRoute { control_point_id: u32 },
+    /// Even at the route's speed limits the window closes first; `required_speed_mps` is the
+    /// average ground speed that would have made it
+    ArrivesTooLate { control_point_id: u32, earliest_arrival: SystemTime, window_end: SystemTime, required_speed_mps: f32 },
+    /// Reached only after the drone's rated flight time is used up
+    BeyondEndurance { control_point_id: u32, arrival: SystemTime },
+}
+
+/// Path whose navigation mode a drone cannot fly, found by `MissionPayload::check_navigation`
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum NavigationIssue {
+    #[error("Path {path_id} requires {mode:?} navigation, which the drone does not support")]
+    UnsupportedMode { path_id: u32, mode: NavigationMode },
+    /// Dead reckoning over the whole path accumulates more error than it allows
+    #[error("Path {path_id} drifts an expected {expected_drift_m} m without GPS, above its {max_drift_m} m budget")]
+    DriftBudgetExceeded { path_id: u32, expected_drift_m: f32, max_drift_m: f32 },
+}
+
+/// Separation two drones must keep while their missions overlap in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeparationConfig {
+    pub horizontal_m: f32,
+    pub vertical_m: f32, // Drones at least this far apart in altitude may share horizontal space
+    pub time_step: Duration, // Trajectory sampling interval
+}
+
+impl Default for SeparationConfig {
+    fn default() -> Self {
+        Self {
+            horizontal_m: 50.0,
+            vertical_m: 100.0,
+            time_step: Duration::from_secs(1),
+        }
+    }
+}
+
+/// First moment two missions come closer than the configured separation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirspaceConflict {
+    pub mission_a: MissionId,
+    pub mission_b: MissionId,
+    pub at: SystemTime,
+    pub position_a: GeoCoordinate,
+    pub position_b: GeoCoordinate,
+    pub horizontal_m: f32,
+    pub vertical_m: f32,
+}
+
+/// Find mission pairs that, flown from their validity start, are within both the horizontal
+/// and the vertical separation at the same time. Drones stacked in different altitude bands
+/// may cross horizontally without conflicting. Reports the first conflict per pair.
+pub fn detect_conflicts(missions: &[&MissionPayload], separation: &SeparationConfig) -> Vec<AirspaceConflict> {
+    let trajectories: Vec<Vec<(SystemTime, GeoCoordinate)>> = missions.iter()
+        .map(|mission| mission.flight_plan.positions_over_time(mission.header.validity_start, separation.time_step))
+        .collect();
+    let half_step = separation.time_step / 2;
+    let mut conflicts = Vec::new();
+
+    for a in 0..missions.len() {
+        for b in a + 1..missions.len() {
+            let mut cursor = 0;
+            'pair: for (time_a, position_a) in &trajectories[a] {
+                // Samples of `b` are time-ordered; skip those too early to coincide with `time_a`
+                while cursor < trajectories[b].len() && trajectories[b][cursor].0 + half_step < *time_a {
+                    cursor += 1;
+                }
+                for (time_b, position_b) in trajectories[b][cursor..].iter().take_while(|(time_b, _)| *time_b <= *time_a + half_step) {
+                    let horizontal_m = position_a.distance_to(position_b);
+                    let vertical_m = (position_a.altitude_msl - position_b.altitude_msl).abs();
+                    if horizontal_m < separation.horizontal_m && vertical_m < separation.vertical_m {
+                        conflicts.push(AirspaceConflict {
+                            mission_a: missions[a].header.id,
+                            mission_b: missions[b].header.id,
+                            at: (*time_a).min(*time_b),
+                            position_a: position_a.clone(),
+                            position_b: position_b.clone(),
+                            horizontal_m,
+                            vertical_m,
+                        });
+                        break 'pair;
+                    }
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Unsatisfied task precondition found while checking the condition graph
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConditionIssue {
+    pub task_id: u32,
+    pub sequence_order: u32,
+    pub condition: String,
+    pub description: String,
+}
+
+/// Size bounds enforced by `MissionPayload::validate`, so an oversized mission is refused
+/// before it costs transfer time or validation CPU
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MissionLimits {
+    pub max_waypoints: usize, // Across all flight paths
+    pub max_tasks: usize,
+    pub max_geofence_zones: usize,
+    pub max_formation_drones: usize,
+}
+
+impl Default for MissionLimits {
+    fn default() -> Self {
+        Self {
+            max_waypoints: 1000,
+            max_tasks: 256,
+            max_geofence_zones: 64,
+            max_formation_drones: 32,
+        }
+    }
+}
+
+/// Structural problems found by `MissionPayload::validate`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MissionValidationIssue {
+    /// The mission holds `count` of `item`, more than the `max` allowed by `MissionLimits`
+    ExceedsLimit { item: String, count: usize, max: usize },
+    /// No paths, or a path (by id) with fewer than two waypoints
+    EmptyFlightPlan { path_id: Option<u32>, waypoint_count: usize },
+    /// Leg `to_path_id` does not start where leg `from_path_id` ends
+    DiscontinuousLegs { from_path_id: u32, to_path_id: u32, gap_m: f32 },
+    UnsatisfiedCondition(ConditionIssue),
+    /// A path or waypoint field outside its physically plausible range
+    PhysicalLimit { path_id: u32, waypoint_id: Option<u32>, field: String, value: f32, limit: String },
+    /// A requirement for carrying a hazardous payload is not met
+    HazardousPayload { hazard_class: String, violation: HazmatViolation },
+    /// Geofence zone `zone_index` has bounds that cannot be checked reliably
+    InvalidGeofence { zone_index: usize, reason: String },
+    /// A custom action in task `task_id` does not match its declared parameters
+    InvalidActionParams { task_id: u32, error: ParamError },
+    /// The mission breaks the policy attached to one of its tags
+    TagPolicy { tag: String, violation: TagPolicyViolation },
+    /// A flight path leaves what one of the mission's geofence zones permits
+    GeofenceBreach(GeofenceBreach),
+}
+
+/// Requirements a mission carrying a hazardous payload must meet
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum HazmatViolation {
+    /// `AuthorizationScope::HazardousMaterials` is not among the mission's scopes
+    MissingScope,
+    /// Path `path_id` passes within `required_clearance_m` of populated area `area_index`
+    CrowdProximity { path_id: u32, area_index: usize, position: GeoCoordinate, required_clearance_m: f32 },
+    /// No task lists `CONTAINMENT_CHECK_CONDITION` among its preconditions
+    NoContainmentCheck,
+}
+
+impl MissionValidationIssue {
+    /// Reporting severity; every issue still fails validation
+    pub fn severity(&self) -> ViolationSeverity {
+        match self {
+            MissionValidationIssue::HazardousPayload { .. }
+            | MissionValidationIssue::TagPolicy { .. }
+            | MissionValidationIssue::GeofenceBreach(_) => ViolationSeverity::Critical,
+            _ => ViolationSeverity::Warning,
+        }
+    }
+}
+
+impl std::fmt::Display for MissionValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MissionValidationIssue::ExceedsLimit { item, count, max } =>
+                write!(f, "exceeds_limit: {} {}, maximum {}", count, item, max),
+            MissionValidationIssue::EmptyFlightPlan { path_id: None, .. } => write!(f, "empty_flight_plan: no flight paths"),
+            MissionValidationIssue::EmptyFlightPlan { path_id: Some(path_id), waypoint_count } =>
+                write!(f, "empty_flight_plan: path {} has {} waypoint(s)", path_id, waypoint_count),
+            MissionValidationIssue::DiscontinuousLegs { from_path_id, to_path_id, gap_m } =>
+                write!(f, "discontinuous_legs: path {} starts {:.1} m from the end of path {}", to_path_id, gap_m, from_path_id),
+            MissionValidationIssue::UnsatisfiedCondition(issue) =>
+                write!(f, "unsatisfied_condition: task {} requires '{}'", issue.task_id, issue.condition),
+            MissionValidationIssue::PhysicalLimit { path_id, waypoint_id: None, field, value, limit } =>
+                write!(f, "physical_limit: path {} {} = {} {}", path_id, field, value, limit),
+            MissionValidationIssue::PhysicalLimit { path_id, waypoint_id: Some(waypoint_id), field, value, limit } =>
+                write!(f, "physical_limit: path {} waypoint {} {} = {} {}", path_id, waypoint_id, field, value, limit),
+            MissionValidationIssue::HazardousPayload { hazard_class, violation: HazmatViolation::MissingScope } =>
+                write!(f, "hazardous_payload: class {} requires the HazardousMaterials scope", hazard_class),
+            MissionValidationIssue::HazardousPayload { hazard_class, violation: HazmatViolation::CrowdProximity { path_id, area_index, required_clearance_m, .. } } =>
+                write!(f, "hazardous_payload: class {} path {} passes within {:.0} m of populated area {}", hazard_class, path_id, required_clearance_m, area_index),
+            MissionValidationIssue::HazardousPayload { hazard_class, violation: HazmatViolation::NoContainmentCheck } =>
+                write!(f, "hazardous_payload: class {} has no '{}' precondition", hazard_class, CONTAINMENT_CHECK_CONDITION),
+            MissionValidationIssue::InvalidGeofence { zone_index, reason } =>
+                write!(f, "invalid_geofence: zone {} {}", zone_index, reason),
+            MissionValidationIssue::InvalidActionParams { task_id, error } =>
+                write!(f, "invalid_action_params: task {} {}", task_id, error),
+            MissionValidationIssue::TagPolicy { tag, violation: TagPolicyViolation::MissingScope(scope) } =>
+                write!(f, "tag_policy: '{}' requires the {:?} scope", tag, scope),
+            MissionValidationIssue::TagPolicy { tag, violation: TagPolicyViolation::RestrictedZone { path_id, zone_index } } =>
+                write!(f, "tag_policy: '{}' forbids path {} entering restricted zone {}", tag, path_id, zone_index),
+            MissionValidationIssue::GeofenceBreach(breach) =>
+                write!(f, "geofence_breach: path {} leaves zone {} at ({:.6}, {:.6}, {:.0} m)", breach.path_id, breach.zone_index,
+                    breach.position.latitude, breach.position.longitude, breach.position.altitude_msl),
+        }
+    }
+}
+
+/// Sample spacing used to check flight paths against tag-restricted zones
+const TAG_ZONE_SAMPLE_SPACING_M: f32 = 25.0;
+
+/// Sample spacing `validate` uses to check flight paths against the mission's geofences
+const GEOFENCE_SAMPLE_SPACING_M: f32 = 25.0;
+
+/// Operational rules attached to a mission tag
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagPolicy {
+    pub required_scopes: Vec<AuthorizationScope>,
+    pub required_approvals: Vec<String>,        // Required wherever the mission flies
+    pub restricted_jurisdictions: Vec<String>,  // Where `restricted_approvals` also apply
+    pub restricted_approvals: Vec<String>,
+    pub restricted_zones: Vec<GeoBounds>,       // The flight plan must stay out of these
+}
+
+impl TagPolicy {
+    /// Approval steps required when flying in `jurisdiction`
+    pub fn approvals_in<'a>(&'a self, jurisdiction: Option<&str>) -> impl Iterator<Item = &'a String> {
+        let restricted = jurisdiction.map_or(false, |jurisdiction| self.restricted_jurisdictions.iter().any(|j| j == jurisdiction));
+        let extra: &[String] = if restricted { &self.restricted_approvals } else { &[] };
+        self.required_approvals.iter().chain(extra)
+    }
+}
+
+/// Rule a tagged mission breaks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TagPolicyViolation {
+    MissingScope(AuthorizationScope),
+    RestrictedZone { path_id: u32, zone_index: usize },
+}
+
+/// Tag policies keyed by lowercase tag
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagPolicyTable {
+    pub policies: HashMap<String, TagPolicy>,
+}
+
+impl TagPolicyTable {
+    /// Attach (or replace) the policy for `tag`
+    pub fn set(&mut self, tag: &str, policy: TagPolicy) -> &mut Self {
+        self.policies.insert(tag.to_lowercase(), policy);
+        self
+    }
+
+    fn policies_for<'a>(&'a self, mission: &'a MissionPayload) -> impl Iterator<Item = (&'a String, &'a TagPolicy)> {
+        mission.header.tags.iter()
+            .filter_map(move |tag| self.policies.get(&tag.to_lowercase()).map(|policy| (tag, policy)))
+    }
+
+    /// Tags with no policy, worth a warning since they may be typos of governed tags
+    pub fn unknown_tags<'a>(&self, mission: &'a MissionPayload) -> Vec<&'a str> {
+        mission.header.tags.iter()
+            .filter(|tag| !self.policies.contains_key(&tag.to_lowercase()))
+            .map(|tag| tag.as_str())
+            .collect()
+    }
+
+    /// Approval steps the mission's tags demand when flown in `jurisdiction`
+    pub fn required_approvals(&self, mission: &MissionPayload, jurisdiction: Option<&str>) -> Vec<String> {
+        let mut approvals: Vec<String> = Vec::new();
+        for (_, policy) in self.policies_for(mission) {
+            for approval in policy.approvals_in(jurisdiction) {
+                if !approvals.contains(approval) {
+                    approvals.push(approval.clone());
+                }
+            }
+        }
+        approvals
+    }
+
+    /// Check the mission against the policy of every tag it carries
+    ///
+    /// Approval steps are not checked here: the mission author cannot vouch for them, so
+    /// `required_approvals` feeds them to the operator's `ApprovalWorkflow`, which refuses
+    /// approval until each one has been recorded.
+    pub fn evaluate(&self, mission: &MissionPayload, jurisdiction: Option<&str>) -> Vec<MissionValidationIssue> {
+        let mut issues = Vec::new();
+
+        for (tag, policy) in self.policies_for(mission) {
+            let issue = |violation| MissionValidationIssue::TagPolicy { tag: tag.clone(), violation };
+
+            for scope in &policy.required_scopes {
+                if !mission.policies.authorization_scopes.contains(scope) {
+                    issues.push(issue(TagPolicyViolation::MissingScope(scope.clone())));
+                }
+            }
+
+            for (path, samples) in mission.flight_plan.interpolate(TAG_ZONE_SAMPLE_SPACING_M) {
+                for (zone_index, zone) in policy.restricted_zones.iter().enumerate() {
+                    if samples.iter().any(|position| zone.contains(position)) {
+                        issues.push(issue(TagPolicyViolation::RestrictedZone { path_id: path.id, zone_index }));
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+impl MissionDiff {
+    /// Field-level changes from `before` to `after`, ignoring signatures and transfer state
+    pub fn between(before: &MissionPayload, after: &MissionPayload) -> Result<Self, CryptoError> {
+        let content = |mission: &MissionPayload| {
+            let mut value = serde_json::to_value(mission).map_err(|e| CryptoError::GenericError(e.to_string()))?;
+            if let Some(fields) = value.as_object_mut() {
+                fields.remove("crypto");
+            }
+            Ok::<_, CryptoError>(value)
+        };
+        let mut diff = Self::default();
+        diff.collect("", Some(&content(before)?), Some(&content(after)?));
+        Ok(diff)
+    }
+
+    /// Whether the revisions have identical content
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn collect(&mut self, path: &str, before: Option<&serde_json::Value>, after: Option<&serde_json::Value>) {
+        use serde_json::Value;
+
+        let child = |segment: &str| if path.is_empty() { segment.to_string() } else { format!("{}.{}", path, segment) };
+        match (before, after) {
+            (Some(Value::Object(old)), Some(Value::Object(new))) => {
+                let keys: std::collections::BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+                for key in keys {
+                    self.collect(&child(key), old.get(key), new.get(key));
+                }
+            }
+            (Some(Value::Array(old)), Some(Value::Array(new))) => {
+                for index in 0..old.len().max(new.len()) {
+                    self.collect(&format!("{}[{}]", path, index), old.get(index), new.get(index));
+                }
+            }
+            _ if before != after => self.changes.push(FieldChange {
+                path: path.to_string(),
+                before: before.cloned(),
+                after: after.cloned(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+impl MissionPayload {
+    /// Sign the mission itself: stamps a fresh nonce and timestamp, then stores an Ed25519
+    /// signature over the canonical payload in `crypto.payload_signature`
+    pub fn sign(&mut self, engine: &CryptoEngine) -> Result<(), CryptoError> {
+        self.crypto.nonce = rand::random();
+        self.crypto.timestamp = SystemTime::now();
+        self.crypto.payload_signature = engine.sign_data(&self.canonical_signing_bytes()?)?;
+        Ok(())
+    }
+
+    /// Whether the stored signature was made by `engine`'s key over this exact payload
+    pub fn verify(&self, engine: &CryptoEngine) -> bool {
+        self.verify_with_key(engine.ed25519_public_key())
+    }
+
+    /// Whether the stored signature was made by `public_key` over this exact payload
+    pub fn verify_with_key(&self, public_key: &[u8]) -> bool {
+        if self.crypto.payload_signature.is_empty() {
+            return false;
+        }
+        self.canonical_signing_bytes()
+            .and_then(|bytes| CryptoEngine::verify_log_signature(public_key, &bytes, &self.crypto.payload_signature))
+            .is_ok()
+    }
+
+    /// Bytes covered by the payload signature: everything except the signature itself and
+    /// the transfer-layer MAC binding and session key. Serialized through a JSON value so
+    /// map keys are sorted and the bytes survive a round trip.
+    fn canonical_signing_bytes(&self) -> Result<Vec<u8>, CryptoError> {
+        let mut unsigned = self.clone();
+        unsigned.crypto.payload_signature.clear();
+        unsigned.crypto.channel_mac_binding.clear();
+        unsigned.crypto.session_key = None;
+
+        let value = serde_json::to_value(&unsigned).map_err(|e| CryptoError::GenericError(e.to_string()))?;
+        let mut bytes = b"gibberlink-mission-payload-v1".to_vec();
+        bytes.extend(serde_json::to_vec(&value).map_err(|e| CryptoError::GenericError(e.to_string()))?);
+        Ok(bytes)
+    }
+
+    /// Edit a mission as a new revision: applies `changes`, increments `header.revision`,
+    /// links the new revision to this one through `header.supersedes` and re-signs it.
+    ///
+    /// The mission ID is unchanged, so a drone already holding the previous revision refuses
+    /// the new one as a conflicting payload until it is re-transferred from scratch. Once
+    /// decrypted, the drone accepts it only if `supersedes` names the revision it accepted.
+    pub fn amend(self, changes: impl FnOnce(&mut MissionPayload), engine: &CryptoEngine) -> Result<AmendedMission, CryptoError> {
+        let mut new = self.clone();
+        changes(&mut new);
+        let diff = MissionDiff::between(&self, &new)?;
+
+        new.header.id = self.header.id;
+        new.header.revision = self.header.revision + 1;
+        new.header.supersedes = Some(self.revision_fingerprint()?);
+        new.sign(engine)?;
+
+        Ok(AmendedMission { revision: new.header.revision, previous: self, new, diff })
+    }
+
+    /// Compact binary encoding for bandwidth-limited links: a `PACKED_FORMAT_VERSION` byte,
+    /// then every field in declaration order with variable-length integers and no field
+    /// names. Unlike CBOR it is not self-describing, so both ends must run the same layout
+    /// version; `from_packed` refuses any other.
+    pub fn to_packed(&self) -> Result<Vec<u8>, PackedMissionError> {
+        let mut bytes = vec![PACKED_FORMAT_VERSION];
+        bincode::DefaultOptions::new()
+            .serialize_into(&mut bytes, self)
+            .map_err(|e| PackedMissionError::Codec(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Decode a mission written by `to_packed`, refusing anything that is or claims to be
+    /// larger than `MAX_PACKED_MISSION_BYTES`
+    pub fn from_packed(bytes: &[u8]) -> Result<Self, PackedMissionError> {
+        let (&version, body) = bytes.split_first().ok_or(PackedMissionError::Empty)?;
+        if version != PACKED_FORMAT_VERSION {
+            return Err(PackedMissionError::UnsupportedVersion { found: version, expected: PACKED_FORMAT_VERSION });
+        }
+        // bincode ignores the limit when decoding a slice, so decode through a reader
+        let mut reader = std::io::Cursor::new(body);
+        let mission = bincode::DefaultOptions::new()
+            .with_limit(MAX_PACKED_MISSION_BYTES)
+            .deserialize_from(&mut reader)
+            .map_err(|e| match *e {
+                bincode::ErrorKind::SizeLimit => PackedMissionError::TooLarge { limit: MAX_PACKED_MISSION_BYTES },
+                other => PackedMissionError::Codec(other.to_string()),
+            })?;
+        if reader.position() != body.len() as u64 {
+            return Err(PackedMissionError::Codec("trailing bytes after mission".to_string()));
+        }
+        Ok(mission)
+    }
+
+    /// Hash identifying this exact signed revision, referenced by the revision amending it
+    pub fn revision_fingerprint(&self) -> Result<[u8; 32], CryptoError> {
+        let mut content = self.canonical_signing_bytes()?;
+        content.extend_from_slice(&self.crypto.payload_signature);
+        Ok(CryptoEngine::generate_device_fingerprint(&content))
+    }
+
+    /// Short code for operators to read aloud and confirm the station and drone hold the
+    /// same mission before arming, like a pairing code. It is 40 bits of a hash over the
+    /// signed content, grouped as `XXXX-XXXX` in base32: enough to catch a mismatch by eye,
+    /// not a cryptographic check. Signature verification remains the security boundary.
+    pub fn verification_code(&self) -> String {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+        let Ok(canonical) = self.canonical_signing_bytes() else {
+            return "????-????".to_string(); // Never equal to a real code
+        };
+        let mut content = b"gibberlink-mission-verification-v1".to_vec();
+        content.extend(canonical);
+        let digest = CryptoEngine::generate_device_fingerprint(&content);
+
+        let bits = digest[..5].iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+        let symbols: String = (0..8).rev()
+            .map(|index| ALPHABET[((bits >> (index * 5)) & 0x1f) as usize] as char)
+            .collect();
+        format!("{}-{}", &symbols[..4], &symbols[4..])
+    }
+
+    /// Re-scope the mission to the scopes the operator granted: actions needing any other
+    /// scope are removed and the policy keeps only granted scopes. Returns the requested
+    /// scopes that were denied.
+    pub fn restrict_to_scopes(&mut self, granted: &[AuthorizationScope]) -> Vec<AuthorizationScope> {
+        let requested = std::mem::take(&mut self.policies.authorization_scopes);
+        let (kept, denied): (Vec<AuthorizationScope>, Vec<AuthorizationScope>) = requested.into_iter()
+            .partition(|scope| granted.contains(scope));
+        self.policies.authorization_scopes = kept;
+
+        for task in &mut self.tasks {
+            task.actions.retain(|action| action.required_scope().map_or(true, |scope| granted.contains(&scope)));
+        }
+        denied
+    }
+
+    /// Check the extra requirements for a hazardous formation payload: the hazmat scope,
+    /// `HAZMAT_CROWD_MARGIN_FACTOR` times the usual crowd clearance from every populated
+    /// area, and a containment integrity check declared as a task precondition
+    pub fn hazmat_violations(&self) -> Vec<MissionValidationIssue> {
+        let hazard_class = match self.formation_config.as_ref().map(|config| &config.payload_config.payload_type) {
+            Some(PayloadType::Hazardous { hazard_class, .. }) => hazard_class,
+            _ => return Vec::new(),
+        };
+        let issue = |violation| MissionValidationIssue::HazardousPayload { hazard_class: hazard_class.clone(), violation };
+        let mut issues = Vec::new();
+
+        if !self.policies.authorization_scopes.contains(&AuthorizationScope::HazardousMaterials) {
+            issues.push(issue(HazmatViolation::MissingScope));
+        }
+
+        let safety = &self.constraints.safety;
+        let required_clearance_m = safety.max_proximity_to_crowd_m * HAZMAT_CROWD_MARGIN_FACTOR;
+        for (path, samples) in self.flight_plan.interpolate((required_clearance_m / 2.0).max(1.0)) {
+            for (area_index, area) in safety.populated_areas.iter().enumerate() {
+                // Clearance is horizontal: flying over a crowd is never far enough
+                let mut keep_out = area.expand(required_clearance_m);
+                keep_out.min_altitude = f32::MIN;
+                keep_out.max_altitude = f32::MAX;
+                if let Some(position) = samples.iter().find(|position| keep_out.contains(position)) {
+                    issues.push(issue(HazmatViolation::CrowdProximity {
+                        path_id: path.id,
+                        area_index,
+                        position: position.clone(),
+                        required_clearance_m,
+                    }));
+                }
+            }
+        }
+
+        let containment_checked = self.tasks.iter()
+            .any(|task| task.preconditions.iter().any(|condition| condition == CONTAINMENT_CHECK_CONDITION));
+        if !containment_checked {
+            issues.push(issue(HazmatViolation::NoContainmentCheck));
+        }
+
+        issues
+    }
+
+    /// True if the plan has at least one path and every path has two or more waypoints
+    pub fn has_flyable_flight_plan(&self) -> bool {
+        !self.flight_plan.paths.is_empty()
+            && self.flight_plan.paths.iter().all(|path| path.waypoints.len() >= 2)
+    }
+
+    /// Check every flight path against the geofences, sampling the route every `step_m`
+    /// so legs, and the transitions between them, that cut through a zone between
+    /// waypoints are caught. Reports the first breaching point per zone and path.
+    pub fn geofence_breaches(&self, step_m: f32) -> Vec<GeofenceBreach> {
+        let mut breaches = Vec::new();
+        for (path, samples) in self.flight_plan.interpolate(step_m) {
+            for (zone_index, zone) in self.constraints.geofencing.iter().enumerate() {
+                if let Some(position) = samples.iter().find(|position| !zone.permits(position)) {
+                    breaches.push(GeofenceBreach { zone_index, path_id: path.id, position: position.clone() });
+                }
+            }
+        }
+        breaches
+    }
+
+    /// Check every `Rendezvous` control point can be reached inside its time window when the
+    /// route is flown at its speed and climb limits from `start_time`. Rendezvous are matched
+    /// to waypoints in task sequence order; a drone reaching one early holds until the window
+    /// opens, which delays everything after it. `max_flight_time` is the drone's endurance.
+    pub fn check_time_feasibility(&self, start_time: SystemTime, max_flight_time: Duration) -> Vec<TimeWindowIssue> {
+        // Route waypoints with the travel time (s) and distance (m) from the previous one
+        let mut route: Vec<(&Waypoint, f32, f32)> = Vec::new();
+        if let Some(first) = self.flight_plan.paths.iter().find_map(|path| path.waypoints.first()) {
+            route.push((first, 0.0, 0.0));
+        }
+        for (path, from, to) in self.flight_plan.segments() {
+            let distance_m = from.position.distance_to(&to.position);
+            let speed_mps = to.speed_limit_mps
+                .map_or(path.max_speed_mps, |limit| limit.min(path.max_speed_mps))
+                .max(f32::EPSILON);
+            let climb_m = to.position.altitude_msl - from.position.altitude_msl;
+            let vertical_rate = if climb_m >= 0.0 { path.climb_rate_max_mps } else { path.descent_rate_max_mps };
+            let vertical_s = climb_m.abs() / vertical_rate.max(f32::EPSILON);
+            let loiter_s = from.loiter_time_seconds.unwrap_or(0) as f32;
+            route.push((to, (distance_m / speed_mps).max(vertical_s) + loiter_s, distance_m));
+        }
+
+        let mut rendezvous: Vec<&MissionTask> = self.tasks.iter()
+            .filter(|task| matches!(task.control_point, Some(ControlPoint::Rendezvous { .. })))
+            .collect();
+        rendezvous.sort_by_key(|task| task.sequence_order);
+
+        let endurance_s = max_flight_time.as_secs_f32();
+        let mut issues = Vec::new();
+        let (mut index, mut elapsed_s, mut distance_m) = (0, 0.0f32, 0.0f32);
+
+        for task in rendezvous {
+            let (id, location, window_start, window_end) = match &task.control_point {
+                Some(ControlPoint::Rendezvous { id, location, time_window_start, time_window_end, .. }) =>
+                    (*id, location, *time_window_start, *time_window_end),
+                _ => continue,
+            };
+
+            // Fly forward to the next waypoint at the rendezvous
+            let mut cursor = (index, elapsed_s, distance_m);
+            let mut reached = None;
+            while cursor.0 < route.len() {
+                let (waypoint, travel_s, leg_m) = route[cursor.0];
+                cursor = (cursor.0 + 1, cursor.1 + travel_s, cursor.2 + leg_m);
+                if waypoint.position.distance_to(location) <= waypoint.position_tolerance_m {
+                    reached = Some(cursor);
+                    break;
+                }
+            }
+            let (next_index, arrival_s, route_m) = match reached {
+                Some(reached) => reached,
+                None => {
+                    issues.push(TimeWindowIssue::NotOnRoute { control_point_id: id });
+                    continue;
+                }
+            };
+
+            let earliest_arrival = start_time + Duration::from_secs_f32(arrival_s);
+            if earliest_arrival > window_end {
+                let available_s = window_end.duration_since(start_time).map_or(0.0, |d| d.as_secs_f32());
+                issues.push(TimeWindowIssue::ArrivesTooLate {
+                    control_point_id: id,
+                    earliest_arrival,
+                    window_end,
+                    required_speed_mps: if available_s > 0.0 { route_m / available_s } else { f32::INFINITY },
+                });
+            }
+            if arrival_s > endurance_s {
+                issues.push(TimeWindowIssue::BeyondEndurance { control_point_id: id, arrival: earliest_arrival });
+            }
+
+            let window_opens_s = window_start.duration_since(start_time).map_or(0.0, |d| d.as_secs_f32());
+            index = next_index;
+            elapsed_s = arrival_s.max(window_opens_s);
+            distance_m = route_m;
+        }
+
+        issues
+    }
+
+    /// Check the drone supports each path's navigation mode, and that GPS-denied paths are
+    /// short enough for its dead-reckoning drift to stay within their budget
+    pub fn check_navigation(&self, navigation: &NavigationCapabilities) -> Vec<NavigationIssue> {
+        let mut issues = Vec::new();
+        for path in &self.flight_plan.paths {
+            let unsupported = || NavigationIssue::UnsupportedMode { path_id: path.id, mode: path.navigation_mode.clone() };
+            match path.navigation_mode {
+                NavigationMode::Gps => {}
+                NavigationMode::Visual => {
+                    if !navigation.visual_navigation {
+                        issues.push(unsupported());
+                    }
+                }
+                NavigationMode::GpsDenied { max_drift_m } => match navigation.dead_reckoning_drift_mps {
+                    None => issues.push(unsupported()),
+                    Some(drift_mps) => {
+                        let expected_drift_m = drift_mps * path.flight_time().as_secs_f32();
+                        if expected_drift_m > max_drift_m {
+                            issues.push(NavigationIssue::DriftBudgetExceeded { path_id: path.id, expected_drift_m, max_drift_m });
+                        }
+                    }
+                },
+            }
+        }
+        issues
+    }
+
+    /// Validate mission structure before it is signed or transferred, within the default `MissionLimits`
+    pub fn validate(&self) -> Result<(), Vec<MissionValidationIssue>> {
+        self.validate_with_limits(&MissionLimits::default())
+    }
+
+    /// `validate` against explicit size limits. A mission over any limit is rejected with
+    /// only the limit issues, without running the costlier structural checks.
+    pub fn validate_with_limits(&self, limits: &MissionLimits) -> Result<(), Vec<MissionValidationIssue>> {
+        let counts = [
+            ("waypoints", self.flight_plan.paths.iter().map(|path| path.waypoints.len()).sum(), limits.max_waypoints),
+            ("tasks", self.tasks.len(), limits.max_tasks),
+            ("geofence_zones", self.constraints.geofencing.len(), limits.max_geofence_zones),
+            ("formation_drones", self.formation_config.as_ref().map_or(0, |formation| formation.drones.len()), limits.max_formation_drones),
+        ];
+        let oversized: Vec<MissionValidationIssue> = counts.into_iter()
+            .filter(|&(_, count, max)| count > max)
+            .map(|(item, count, max)| MissionValidationIssue::ExceedsLimit { item: item.to_string(), count, max })
+            .collect();
+        if !oversized.is_empty() {
+            return Err(oversized);
+        }
+
+        let mut issues = Vec::new();
+
+        if self.flight_plan.paths.is_empty() {
+            issues.push(MissionValidationIssue::EmptyFlightPlan { path_id: None, waypoint_count: 0 });
+        }
+        for path in &self.flight_plan.paths {
+            if path.waypoints.len() < 2 {
+                issues.push(MissionValidationIssue::EmptyFlightPlan {
+                    path_id: Some(path.id),
+                    waypoint_count: path.waypoints.len(),
+                });
+            }
+        }
+        issues.extend(self.flight_plan.leg_discontinuities());
+        for path in &self.flight_plan.paths {
+            issues.extend(path.physical_limit_violations());
+        }
+        issues.extend(self.hazmat_violations());
+        let mut invalid_zones = HashSet::new();
+        for (zone_index, zone) in self.constraints.geofencing.iter().enumerate() {
+            let bounds = match zone {
+                GeofenceZone::KeepOut { bounds, .. } | GeofenceZone::KeepIn { bounds, .. } => Some(bounds),
+                GeofenceZone::AltitudeFloor { bounds, .. } | GeofenceZone::AltitudeCeiling { bounds, .. } => bounds.as_ref(),
+                GeofenceZone::Corridor { .. } => None,
+            };
+            if let Some(reason) = bounds.and_then(|bounds| bounds.validity_error()) {
+                issues.push(MissionValidationIssue::InvalidGeofence { zone_index, reason });
+                invalid_zones.insert(zone_index);
+            }
+        }
+        // Zones with unusable bounds are already reported; breaches of them would be noise
+        issues.extend(self.geofence_breaches(GEOFENCE_SAMPLE_SPACING_M).into_iter()
+            .filter(|breach| !invalid_zones.contains(&breach.zone_index))
+            .map(MissionValidationIssue::GeofenceBreach));
+        let action_schema = CustomActionSchema::builtin();
+        for task in &self.tasks {
+            for action in &task.actions {
+                if let Err(errors) = action.validate_params(&action_schema) {
+                    issues.extend(errors.into_iter().map(|error| MissionValidationIssue::InvalidActionParams { task_id: task.id, error }));
+                }
+            }
+        }
+
+        if let Err(condition_issues) = self.check_condition_graph() {
+            issues.extend(condition_issues.into_iter().map(MissionValidationIssue::UnsatisfiedCondition));
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// `validate`, plus the policies attached to the mission's tags when flown in `jurisdiction`
+    pub fn validate_with_tag_policies(&self, tag_policies: &TagPolicyTable, jurisdiction: Option<&str>) -> Result<(), Vec<MissionValidationIssue>> {
+        let mut issues = self.validate().err().unwrap_or_default();
+        issues.extend(tag_policies.evaluate(self, jurisdiction));
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Verify every task precondition is produced by an earlier task or declared initially
+    ///
+    /// Tasks are walked in `sequence_order`; postconditions of a task only become
+    /// available to tasks with a strictly greater sequence order.
+    pub fn check_condition_graph(&self) -> Result<(), Vec<ConditionIssue>> {
+        let mut satisfied: HashSet<&str> = self.initial_conditions.iter()
+            .map(|c| c.as_str())
+            .collect();
+
+        let mut ordered: Vec<&MissionTask> = self.tasks.iter().collect();
+        ordered.sort_by_key(|task| task.sequence_order);
+
+        let mut issues = Vec::new();
+        let mut index = 0;
+        while index < ordered.len() {
+            let order = ordered[index].sequence_order;
+            let group_end = ordered[index..].iter()
+                .position(|task| task.sequence_order != order)
+                .map_or(ordered.len(), |offset| index + offset);
+
+            for task in &ordered[index..group_end] {
+                for condition in &task.preconditions {
+                    if !satisfied.contains(condition.as_str()) {
+                        issues.push(ConditionIssue {
+                            task_id: task.id,
+                            sequence_order: task.sequence_order,
+                            condition: condition.clone(),
+                            description: format!(
+                                "Task {} ('{}') requires '{}' but no earlier task or initial condition provides it",
+                                task.id, task.label, condition
+                            ),
+                        });
+                    }
+                }
+            }
+
+            // Facts produced by this step become available to later steps only
+            for task in &ordered[index..group_end] {
+                if let Some(postcondition) = &task.postconditions {
+                    satisfied.insert(postcondition.as_str());
+                }
+            }
+
+            index = group_end;
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
+
+impl SynchronizationConfig {
+    /// Takeoff timeline: drones in `takeoff_sequence` order, commanded to ascend at the
+    /// formation's `takeoff_delay_ms` values taken in ascending order, so the n-th drone in the
+    /// sequence leaves at the n-th smallest delay. Drones missing from the sequence follow in the
+    /// order given.
+    pub fn build_takeoff_schedule(&self, drones: &[FormationDrone]) -> Result<Vec<TimedCommand>, ScheduleError> {
+        Self::build_schedule(&self.takeoff_sequence, drones, |drone| FormationCommand::Ascend {
+            target_altitude: drone.synchronization_offset.target_altitude,
+        })
+    }
+
+    /// Landing timeline: drones in `landing_sequence` order, spaced by the same delays as
+    /// takeoff; a drone's slot comes from its position in the sequence, not its own delay
+    pub fn build_landing_schedule(&self, drones: &[FormationDrone]) -> Result<Vec<TimedCommand>, ScheduleError> {
+        Self::build_schedule(&self.landing_sequence, drones, |_| FormationCommand::Land)
+    }
+
+    fn build_schedule(
+        sequence: &[String],
+        drones: &[FormationDrone],
+        command: impl Fn(&FormationDrone) -> FormationCommand,
+    ) -> Result<Vec<TimedCommand>, ScheduleError> {
+        let mut ordered: Vec<&FormationDrone> = Vec::with_capacity(drones.len());
+        for drone_id in sequence {
+            let drone = drones.iter()
+                .find(|drone| drone.drone_id == *drone_id)
+                .ok_or_else(|| ScheduleError::UnknownDrone(drone_id.clone()))?;
+            if ordered.iter().any(|scheduled| scheduled.drone_id == *drone_id) {
+                return Err(ScheduleError::DuplicateDrone(drone_id.clone()));
+            }
+            ordered.push(drone);
+        }
+        for drone in drones {
+            if !sequence.contains(&drone.drone_id) {
+                ordered.push(drone);
+            }
+        }
+
+        // Slots are handed out by sequence position so no drone overtakes one sequenced before it
+        let mut slots: Vec<u32> = ordered.iter().map(|drone| drone.synchronization_offset.takeoff_delay_ms).collect();
+        slots.sort_unstable();
+        Ok(ordered.into_iter()
+            .zip(slots)
+            .map(|(drone, delay_ms)| TimedCommand {
+                offset: Duration::from_millis(delay_ms as u64),
+                drone_id: drone.drone_id.clone(),
+                command: command(drone),
+            })
+            .collect())
+    }
+}
+
+impl FormationConfiguration {
+    /// Drones that carry part of the payload (scouts, relays and spares do not)
+    pub fn load_bearing_drones(&self) -> usize {
+        self.lifters().count()
+    }
+
+    fn lifters(&self) -> impl Iterator<Item = &FormationDrone> {
+        self.drones.iter()
+            .filter(|drone| !matches!(drone.role, DroneRole::Scout | DroneRole::Communications | DroneRole::Emergency))
+    }
+
+    /// Allowed clock skew: drift may accumulate for one sync interval, so allow 10% of it (min 1 ms)
+    pub fn clock_skew_tolerance(&self) -> Duration {
+        Duration::from_millis((self.synchronization.clock_sync_interval_ms as u64 / 10).max(1))
+    }
+
+    /// Compare measured per-drone clock offsets from the formation reference against tolerance
+    pub fn evaluate_clock_sync(&self, drone_clock_offsets: &HashMap<String, Duration>) -> SyncQuality {
+        let tolerance = self.clock_skew_tolerance();
+        let mut max_skew = Duration::ZERO;
+        let mut offenders = Vec::new();
+
+        for drone in &self.drones {
+            match drone_clock_offsets.get(&drone.drone_id) {
+                Some(offset) => {
+                    max_skew = max_skew.max(*offset);
+                    if *offset > tolerance {
+                        offenders.push(drone.drone_id.clone());
+                    }
+                }
+                // An unmeasured clock cannot be trusted for takeoff offsets
+                None => offenders.push(drone.drone_id.clone()),
+            }
+        }
+
+        SyncQuality {
+            max_skew,
+            tolerance,
+            within_tolerance: offenders.is_empty(),
+            offenders,
+        }
+    }
+
+    /// Decide how to react when `lost_links` have been down for `elapsed`
+    ///
+    /// Drones are isolated when they can no longer reach the leader (or the first drone
+    /// when there is none) through the surviving mesh. The fail-safe triggered is the
+    /// leader's configured `fail_safe_behavior`.
+    pub fn on_partition(&self, lost_links: &[MeshLink], elapsed: Duration) -> FormationResponse {
+        let reference = match self.drones.iter().find(|drone| matches!(drone.role, DroneRole::Leader)).or(self.drones.first()) {
+            Some(reference) => reference,
+            None => return FormationResponse::Continue,
+        };
+
+        let is_lost = |link: &MeshLink| lost_links.iter().any(|lost| {
+            (lost.from_drone == link.from_drone && lost.to_drone == link.to_drone)
+                || (lost.from_drone == link.to_drone && lost.to_drone == link.from_drone)
+        });
+        let connected_before = self.reachable_from(&reference.drone_id, |_| true);
+        let connected_now = self.reachable_from(&reference.drone_id, |link| !is_lost(link));
+
+        // Drones that were never meshed in are not partitioned by losing a link
+        let isolated: Vec<&FormationDrone> = self.drones.iter()
+            .filter(|drone| connected_before.contains(drone.drone_id.as_str()) && !connected_now.contains(drone.drone_id.as_str()))
+            .collect();
+        let isolated_drones: Vec<String> = isolated.iter().map(|drone| drone.drone_id.clone()).collect();
+
+        if isolated.is_empty() {
+            return FormationResponse::Continue;
+        }
+
+        let tolerance = Duration::from_millis(self.synchronization.partition_tolerance_ms as u64);
+        if elapsed <= tolerance {
+            return FormationResponse::Hold { isolated_drones, remaining: tolerance - elapsed };
+        }
+
+        if isolated.iter().all(|drone| matches!(drone.role, DroneRole::Scout | DroneRole::Communications | DroneRole::Emergency)) {
+            return FormationResponse::Reconfigure { dropped_drones: isolated_drones };
+        }
+
+        FormationResponse::TriggerFailSafe {
+            behavior: reference.fail_safe_behavior.clone(),
+            isolated_drones,
+        }
+    }
+
+    /// Drones reachable from `start` over mesh links accepted by `usable`
+    fn reachable_from<'a>(&'a self, start: &'a str, usable: impl Fn(&MeshLink) -> bool) -> HashSet<&'a str> {
+        let mut reached: HashSet<&str> = HashSet::new();
+        reached.insert(start);
+        let mut frontier = vec![start];
+
+        while let Some(current) = frontier.pop() {
+            for link in self.communication_mesh.iter().filter(|link| usable(link)) {
+                let neighbour = if link.from_drone == current {
+                    link.to_drone.as_str()
+                } else if link.to_drone == current {
+                    link.from_drone.as_str()
+                } else {
+                    continue;
+                };
+                if reached.insert(neighbour) {
+                    frontier.push(neighbour);
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Share of the payload each load-bearing drone carries, by drone ID
+    ///
+    /// The payload is split evenly; it is infeasible when a share exceeds `critical_load_threshold`.
+    pub fn solve_load_distribution(&self) -> Result<HashMap<String, f32>, ReconfigError> {
+        let lifters: Vec<&FormationDrone> = self.lifters().collect();
+        if lifters.is_empty() {
+            return Err(ReconfigError::NoLoadBearingDrones);
+        }
+
+        let per_drone_kg = self.payload_config.weight_kg / lifters.len() as f32;
+        let limit_kg = self.load_distribution.critical_load_threshold;
+        if per_drone_kg > limit_kg {
+            return Err(ReconfigError::Overloaded { per_drone_kg, limit_kg });
+        }
+        Ok(lifters.into_iter().map(|drone| (drone.drone_id.clone(), per_drone_kg)).collect())
+    }
+
+    /// Whether the formation can still complete after losing `lost` drones: enough survive
+    /// to meet `min_operational_drones` and they can still carry the payload
+    pub fn is_viable_after_losses(&self, lost: &[String]) -> bool {
+        let mut survivors = self.clone();
+        survivors.drones.retain(|drone| !lost.contains(&drone.drone_id));
+        survivors.drones.len() >= self.min_operational_drones && survivors.solve_load_distribution().is_ok()
+    }
+
+    /// Check each drone's reported capabilities against its role, keyed by drone ID
+    ///
+    /// Load-bearing drones and emergency spares (which may replace one) must lift
+    /// `target_load_per_drone_kg`; relays need a channel beyond GibberLink short range;
+    /// scouts need at least one sensor. Every mismatch is reported, not just the first.
+    pub fn validate_roles(&self, caps: &HashMap<String, RoleCapabilities>) -> Result<(), Vec<RoleMismatch>> {
+        let required_kg = self.load_distribution.target_load_per_drone_kg;
+        let mut mismatches = Vec::new();
+
+        for drone in &self.drones {
+            let drone_id = drone.drone_id.clone();
+            let Some(capabilities) = caps.get(&drone.drone_id) else {
+                mismatches.push(RoleMismatch::MissingCapabilities { drone_id });
+                continue;
+            };
+
+            match drone.role {
+                DroneRole::Leader | DroneRole::Wingman | DroneRole::Anchor | DroneRole::Lift | DroneRole::Emergency => {
+                    if capabilities.max_payload_kg < required_kg {
+                        mismatches.push(RoleMismatch::InsufficientPayload {
+                            drone_id,
+                            required_kg,
+                            available_kg: capabilities.max_payload_kg,
+                        });
+                    }
+                }
+                DroneRole::Communications => {
+                    if !capabilities.relay_capable {
+                        mismatches.push(RoleMismatch::NoRelayChannel { drone_id });
+                    }
+                }
+                DroneRole::Scout => {
+                    if capabilities.sensor_count == 0 {
+                        mismatches.push(RoleMismatch::NoSensors { drone_id });
+                    }
+                }
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches)
+        }
+    }
+
+    /// Reduce payload weight to what the remaining drones can carry in degraded mode
+    ///
+    /// Each load-bearing drone is limited to `critical_load_threshold` kg, derated by
+    /// `target_margin`. Returns the reduced configuration and the weight shed in kg.
+    pub fn shed_to_feasible(&self, target_margin: f32) -> Result<(FormationConfiguration, f32), ReconfigError> {
+        if !(0.0..1.0).contains(&target_margin) {
+            return Err(ReconfigError::InvalidMargin(target_margin));
+        }
+
+        let lifters = self.load_bearing_drones();
+        if lifters == 0 {
+            return Err(ReconfigError::NoLoadBearingDrones);
+        }
+
+        let per_drone_kg = self.load_distribution.critical_load_threshold * (1.0 - target_margin);
+        let max_payload_kg = per_drone_kg * lifters as f32;
+        if max_payload_kg <= 0.0 {
+            return Err(ReconfigError::NothingCarriable);
+        }
+
+        let mut degraded = self.clone();
+        let shed_kg = (self.payload_config.weight_kg - max_payload_kg).max(0.0);
+        degraded.payload_config.weight_kg -= shed_kg;
+        degraded.load_distribution.target_load_per_drone_kg = degraded.payload_config.weight_kg / lifters as f32;
+        degraded.load_distribution.redistribution_strategy = LoadRedistribution::ShedLoad;
+
+        Ok((degraded, shed_kg))
+    }
+
+    /// Geographic position of each drone when the formation is centered on `center`
+    /// and facing `heading_deg` (0 = north, clockwise); offsets are given for a north heading
+    pub fn resolve_positions(&self, center: &GeoCoordinate, heading_deg: f32) -> Vec<(String, GeoCoordinate)> {
+        self.drones.iter()
+            .map(|drone| {
+                let position = &drone.position;
+                let mut location = Self::rotate_offset(center, heading_deg, position.x_offset_m, position.y_offset_m);
+                location.altitude_msl = center.altitude_msl + position.z_offset_m;
+                (drone.drone_id.clone(), location)
+            })
+            .collect()
+    }
+
+    /// Render the formation as a KML document: drone placemarks labelled with role and
+    /// load, sling lines, the payload footprint and the formation geofence
+    pub fn to_kml(&self, center: &GeoCoordinate, heading_deg: f32) -> String {
+        let positions = self.resolve_positions(center, heading_deg);
+        let mut kml = String::new();
+        kml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        kml.push_str("<kml xmlns=\"http://www.opengis.net/kml/2.2\">\n<Document>\n");
+        kml.push_str("<name>Formation overlay</name>\n");
+
+        for (drone, (drone_id, location)) in self.drones.iter().zip(&positions) {
+            let load_kg = if matches!(drone.role, DroneRole::Scout | DroneRole::Communications | DroneRole::Emergency) {
+                0.0
+            } else {
+                self.load_distribution.target_load_per_drone_kg
+            };
+            let label = format!("{} ({:?}, {:.1} kg)", drone_id, drone.role, load_kg);
+            kml.push_str(&format!(
+                "<Placemark>\n<name>{}</name>\n<Point>\n<altitudeMode>absolute</altitudeMode>\n<coordinates>{}</coordinates>\n</Point>\n</Placemark>\n",
+                kml_escape(&label),
+                kml_coordinate(location),
+            ));
+        }
+
+        // Slings run from the carrying drone down to their payload attachment point
+        for sling in &self.payload_config.lifting_slings {
+            let Some((_, drone_location)) = positions.iter().find(|(drone_id, _)| *drone_id == sling.drone_assignment) else {
+                continue;
+            };
+            let attachment = self.attachment_points.iter()
+                .find(|point| point.sling_connection.as_deref() == Some(sling.sling_id.as_str()))
+                .map(|point| {
+                    let mut location = Self::rotate_offset(center, heading_deg, point.location.x_m, point.location.y_m);
+                    location.altitude_msl = center.altitude_msl + point.location.z_m;
+                    location
+                })
+                .unwrap_or_else(|| sling.attachment_point.clone());
+            kml.push_str(&format!(
+                "<Placemark>\n<name>{}</name>\n<LineString>\n<altitudeMode>absolute</altitudeMode>\n<coordinates>{} {}</coordinates>\n</LineString>\n</Placemark>\n",
+                kml_escape(&format!("Sling {}", sling.sling_id)),
+                kml_coordinate(drone_location),
+                kml_coordinate(&attachment),
+            ));
+        }
+
+        // Payload footprint, length along the heading
+        let half_length = self.payload_config.dimensions.length_m / 2.0;
+        let half_width = self.payload_config.dimensions.width_m / 2.0;
+        let footprint: Vec<GeoCoordinate> = [(-half_width, half_length), (half_width, half_length), (half_width, -half_length), (-half_width, -half_length)]
+            .iter()
+            .map(|(x, y)| Self::rotate_offset(center, heading_deg, *x, *y))
+            .collect();
+        kml.push_str(&kml_polygon("Payload footprint", &footprint));
+
+        if let Some(bounds) = &self.formation_geofence {
+            let corner = |latitude: f64, longitude: f64| GeoCoordinate { latitude, longitude, altitude_msl: bounds.max_altitude };
+            let outline = [
+                corner(bounds.north, bounds.west),
+                corner(bounds.north, bounds.east),
+                corner(bounds.south, bounds.east),
+                corner(bounds.south, bounds.west),
+            ];
+            kml.push_str(&kml_polygon("Formation geofence", &outline));
+        }
+
+        kml.push_str("</Document>\n</kml>\n");
+        kml
+    }
+
+    /// Check the whole formation footprint against `zones` with the center at `center` facing
+    /// `heading_deg`: every drone slot, plus the payload corners widened by how far the payload
+    /// can swing on its longest sling (at the larger of the roll and pitch limits) and lowered
+    /// to the payload's underside.
+    pub fn check_geofence(&self, center: &GeoCoordinate, heading_deg: f32, zones: &[GeofenceZone]) -> Vec<FormationGeofenceBreach> {
+        let mut footprint: Vec<(FootprintElement, GeoCoordinate)> = self.resolve_positions(center, heading_deg)
+            .into_iter()
+            .map(|(drone_id, location)| (FootprintElement::Drone(drone_id), location))
+            .collect();
+
+        let payload = &self.payload_config;
+        let sling_length_m = payload.lifting_slings.iter().map(|sling| sling.length_m).fold(0.0f32, f32::max);
+        let swing_angle_deg = payload.stability_requirements.max_roll_degrees.max(payload.stability_requirements.max_pitch_degrees);
+        let swing_m = sling_length_m * swing_angle_deg.to_radians().sin();
+        let half_length = payload.dimensions.length_m / 2.0 + swing_m;
+        let half_width = payload.dimensions.width_m / 2.0 + swing_m;
+        let underside_msl = center.altitude_msl - sling_length_m - payload.dimensions.height_m;
+        for (index, (x, y)) in [(-half_width, half_length), (half_width, half_length), (half_width, -half_length), (-half_width, -half_length)].iter().enumerate() {
+            let mut corner = Self::rotate_offset(center, heading_deg, *x, *y);
+            corner.altitude_msl = underside_msl;
+            footprint.push((FootprintElement::PayloadCorner(index), corner));
+        }
+
+        let mut breaches = Vec::new();
+        for (zone_index, zone) in zones.iter().enumerate() {
+            for (element, position) in &footprint {
+                if !zone.permits(position) {
+                    breaches.push(FormationGeofenceBreach { zone_index, element: element.clone(), position: position.clone() });
+                }
+            }
+        }
+        breaches
+    }
+
+    /// Corrective velocity for each drone with a reported position. Errors within the formation's
+    /// `position_sync_tolerance_m` give a zero vector so drones do not jitter around their slot;
+    /// larger errors are corrected proportionally, capped at `MAX_FORMATION_CORRECTION_MPS`.
+    pub fn compute_corrections(
+        &self,
+        actual: &HashMap<String, GeoCoordinate>,
+        center: &GeoCoordinate,
+        heading_deg: f32,
+    ) -> HashMap<String, CorrectionVector> {
+        const METERS_PER_DEGREE: f64 = 111_320.0;
+        let deadband_m = self.synchronization.position_sync_tolerance_m.max(0.0);
+
+        self.resolve_positions(center, heading_deg).into_iter()
+            .filter_map(|(drone_id, target)| {
+                let position = actual.get(&drone_id)?;
+                let east_m = ((target.longitude - position.longitude) * METERS_PER_DEGREE * position.latitude.to_radians().cos()) as f32;
+                let north_m = ((target.latitude - position.latitude) * METERS_PER_DEGREE) as f32;
+                let up_m = target.altitude_msl - position.altitude_msl;
+                let error_m = (east_m.powi(2) + north_m.powi(2) + up_m.powi(2)).sqrt();
+
+                if error_m <= deadband_m {
+                    return Some((drone_id, CorrectionVector::default()));
+                }
+                let speed_mps = (error_m * FORMATION_CORRECTION_GAIN).min(MAX_FORMATION_CORRECTION_MPS);
+                let scale = speed_mps / error_m;
+                Some((drone_id, CorrectionVector { east_mps: east_m * scale, north_mps: north_m * scale, up_mps: up_m * scale }))
+            })
+            .collect()
+    }
+
+    /// Approach for every drone on one shared glideslope: the formation center flies
+    /// `generate_approach`, with clearance measured at the underside of the slung payload,
+    /// and each drone holds its slot on the inbound course. Waypoint IDs and speeds are the
+    /// same for all drones so they descend in step.
+    pub fn approach_waypoints(&self, plan: &FlightPlan, target: &GeoCoordinate, profile: ApproachProfile) -> HashMap<String, Vec<Waypoint>> {
+        let payload = &self.payload_config;
+        let sling_length_m = payload.lifting_slings.iter().map(|sling| sling.length_m).fold(0.0f32, f32::max);
+        let center_profile = ApproachProfile {
+            ground_clearance_m: profile.ground_clearance_m + sling_length_m + payload.dimensions.height_m,
+            ..profile
+        };
+
+        let mut per_drone: HashMap<String, Vec<Waypoint>> = self.drones.iter()
+            .map(|drone| (drone.drone_id.clone(), Vec::new()))
+            .collect();
+        for center in plan.generate_approach(target, center_profile) {
+            let heading = center.heading_required_degrees.unwrap_or(0.0);
+            for (drone_id, position) in self.resolve_positions(&center.position, heading) {
+                if let Some(waypoints) = per_drone.get_mut(&drone_id) {
+                    waypoints.push(Waypoint { position, ..center.clone() });
+                }
+            }
+        }
+        per_drone
+    }
+
+    /// Rotate a north-referenced (east, north) offset clockwise by `heading_deg` and apply it to `center`
+    fn rotate_offset(center: &GeoCoordinate, heading_deg: f32, x_m: f32, y_m: f32) -> GeoCoordinate {
+        let (sin, cos) = (heading_deg as f64).to_radians().sin_cos();
+        let (x, y) = (x_m as f64, y_m as f64);
+        center.offset_by(x * cos + y * sin, y * cos - x * sin)
+    }
+}
+
+/// Wrap a longitude (or longitude difference) into [-180, 180]
+fn normalize_longitude(longitude: f64) -> f64 {
+    if (-180.0..=180.0).contains(&longitude) {
+        longitude
+    } else {
+        (longitude + 180.0).rem_euclid(360.0) - 180.0
+    }
+}
+
+fn kml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn kml_coordinate(location: &GeoCoordinate) -> String {
+    format!("{:.7},{:.7},{:.1}", location.longitude, location.latitude, location.altitude_msl)
+}
+
+/// Closed KML polygon placemark through `corners`
+fn kml_polygon(name: &str, corners: &[GeoCoordinate]) -> String {
+    let mut ring: Vec<String> = corners.iter().map(kml_coordinate).collect();
+    if let Some(first) = ring.first().cloned() {
+        ring.push(first);
+    }
+    format!(
+        "<Placemark>\n<name>{}</name>\n<Polygon>\n<altitudeMode>absolute</altitudeMode>\n<outerBoundaryIs>\n<LinearRing>\n<coordinates>{}</coordinates>\n</LinearRing>\n</outerBoundaryIs>\n</Polygon>\n</Placemark>\n",
+        kml_escape(name),
+        ring.join(" "),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{formation, formation_drone, mavlink_item, path_through};
+
+    fn task(id: u32, sequence_order: u32, preconditions: &[&str], postcondition: Option<&str>) -> MissionTask {
+        MissionTask {
+            id,
+            label: format!("Task {}", id),
+            sequence_order,
+            control_point: None,
+            actions: Vec::new(),
+            preconditions: preconditions.iter().map(|c| c.to_string()).collect(),
+            postconditions: postcondition.map(|c| c.to_string()),
+            timeout_seconds: None,
+        }
+    }
+
+    #[test]
+    fn test_video_quality_drives_task_data_volume() {
+        let recording = |quality| {
+            let mut video_task = task(1, 1, &[], None);
+            video_task.actions = vec![MissionAction::RecordVideo { duration_seconds: 120, quality, target_location: None }];
+            video_task.estimated_data_volume_mb()
+        };
+
+        // 120 s at 45 Mbit/s
+        assert!((recording(VideoQuality::High) - 675.0).abs() < 1e-3);
+        assert!(recording(VideoQuality::Ultra) > 10.0 * recording(VideoQuality::Low));
+    }
+
+    #[test]
+    fn test_denied_networking_scope_strips_handoff_actions() {
+        let mut mission = MissionPayload::default();
+        mission.policies.authorization_scopes = vec![AuthorizationScope::ExecuteMission, AuthorizationScope::Networking];
+        let mut relay_task = task(1, 1, &[], None);
+        relay_task.actions = vec![
+            MissionAction::CaptureImage { count: 3, interval_seconds: None, target_location: None },
+            MissionAction::Handoff { target_system: "ground_relay".to_string(), handover_data: vec![1, 2, 3] },
+        ];
+        mission.tasks = vec![relay_task];
+
+        let denied = mission.restrict_to_scopes(&[AuthorizationScope::ExecuteMission]);
+
+        assert_eq!(denied, vec![AuthorizationScope::Networking]);
+        assert_eq!(mission.policies.authorization_scopes, vec![AuthorizationScope::ExecuteMission]);
+        assert_eq!(mission.tasks[0].actions.len(), 1);
+        assert!(!mission.tasks[0].actions.iter().any(|action| matches!(action, MissionAction::Handoff { .. })));
+    }
+
+    #[test]
+    fn test_condition_graph_satisfied() {
+        let mut mission = MissionPayload::default();
+        mission.initial_conditions = vec!["weather_acceptable".to_string()];
+        mission.tasks = vec![
+            task(2, 2, &["formation_established"], Some("destination_reached")),
+            task(1, 1, &["weather_acceptable"], Some("formation_established")),
+        ];
+
+        assert!(mission.check_condition_graph().is_ok());
+    }
+
+    #[test]
+    fn test_condition_graph_reports_unsatisfied_precondition() {
+        let mut mission = MissionPayload::default();
+        mission.tasks = vec![
+            task(1, 1, &[], Some("payload_attached")),
+            task(2, 2, &["formation_established"], Some("destination_reached")),
+        ];
+
+        let issues = mission.check_condition_graph().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].task_id, 2);
+        assert_eq!(issues[0].condition, "formation_established");
+    }
+
+    #[test]
+    fn test_default_mission_fails_empty_flight_plan() {
+        let mission = MissionPayload::default();
+
+        assert!(!mission.has_flyable_flight_plan());
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues, vec![MissionValidationIssue::EmptyFlightPlan { path_id: None, waypoint_count: 0 }]);
+    }
+
+    #[test]
+    fn test_single_waypoint_path_fails_validation() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(7, &[(45.0, 2.0)])];
+
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues, vec![MissionValidationIssue::EmptyFlightPlan { path_id: Some(7), waypoint_count: 1 }]);
+    }
+
+    fn bounds(south: f64, north: f64, west: f64, east: f64) -> GeoBounds {
+        GeoBounds { north, south, east, west, min_altitude: 0.0, max_altitude: 120.0 }
+    }
+
+    #[test]
+    fn test_geo_bounds_containment_edges() {
+        let area = bounds(45.0, 45.1, 2.0, 2.1);
+
+        assert!(area.contains(&GeoCoordinate { latitude: 45.1, longitude: 2.0, altitude_msl: 120.0 }));
+        assert!(!area.contains(&GeoCoordinate { latitude: 45.05, longitude: 2.05, altitude_msl: 120.1 }));
+        assert!(area.expand(1.0).contains(&GeoCoordinate { latitude: 45.05, longitude: 2.05, altitude_msl: 120.1 }));
+
+        let dateline = bounds(-10.0, 10.0, 179.0, -179.0);
+        assert!(dateline.contains(&GeoCoordinate { latitude: 0.0, longitude: -179.5, altitude_msl: 50.0 }));
+        assert!(!dateline.contains(&GeoCoordinate { latitude: 0.0, longitude: 0.0, altitude_msl: 50.0 }));
+    }
+
+    #[test]
+    fn test_geo_bounds_intersection() {
+        let area = bounds(45.0, 45.1, 2.0, 2.1);
+
+        assert!(area.intersects(&bounds(45.05, 45.2, 2.05, 2.2)));
+        assert!(!area.intersects(&bounds(45.2, 45.3, 2.0, 2.1)));
+        assert!(bounds(-10.0, 10.0, 179.0, -179.0).intersects(&bounds(-1.0, 1.0, -179.5, -170.0)));
+
+        let mut high = area.clone();
+        high.min_altitude = 200.0;
+        high.max_altitude = 300.0;
+        assert!(!area.intersects(&high));
+    }
+
+    #[test]
+    fn test_interpolation_catches_leg_through_keep_out_zone() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.02, 2.0)])];
+        mission.constraints.geofencing = vec![GeofenceZone::KeepOut {
+            bounds: GeoBounds { north: 45.011, south: 45.009, east: 2.001, west: 1.999, min_altitude: 0.0, max_altitude: 500.0 },
+            reason: "substation".to_string(),
+            exception_conditions: vec![],
+        }];
+
+        let zone = &mission.constraints.geofencing[0];
+        assert!(mission.flight_plan.paths[0].waypoints.iter().all(|waypoint| zone.permits(&waypoint.position)));
+
+        let breaches = mission.geofence_breaches(50.0);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].path_id, 1);
+        assert!(breaches[0].position.latitude > 45.009 && breaches[0].position.latitude < 45.011);
+
+        // Validation refuses the mission for it
+        let issues = mission.validate().unwrap_err();
+        assert!(issues.iter().any(|issue| matches!(issue,
+            MissionValidationIssue::GeofenceBreach(GeofenceBreach { zone_index: 0, path_id: 1, .. }))));
+
+        // The transition between legs is flown too, and belongs to the leg it flies into
+        mission.flight_plan.paths = vec![
+            path_through(1, &[(45.0, 2.0), (45.005, 2.0)]),
+            path_through(2, &[(45.015, 2.0), (45.02, 2.0)]),
+        ];
+        let breaches = mission.geofence_breaches(50.0);
+        assert_eq!(breaches.len(), 1);
+        assert_eq!(breaches[0].path_id, 2);
+    }
+
+    #[test]
+    fn test_distant_rendezvous_in_short_window_is_infeasible() {
+        let start = SystemTime::now();
+        let rendezvous = |id: u32, latitude: f64, longitude: f64, window: Duration| ControlPoint::Rendezvous {
+            id,
+            location: GeoCoordinate { latitude, longitude, altitude_msl: 100.0 },
+            time_window_start: start,
+            time_window_end: start + window,
+            partner_id: Some("ground_team".to_string()),
+        };
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.0, 2.1), (45.2, 2.1)])];
+        mission.tasks = vec![task(1, 1, &[], None), task(2, 2, &[], None)];
+        mission.tasks[0].control_point = Some(rendezvous(1, 45.0, 2.1, Duration::from_secs(3600)));
+        mission.tasks[1].control_point = Some(rendezvous(2, 45.2, 2.1, Duration::from_secs(600)));
+
+        let issues = mission.check_time_feasibility(start, Duration::from_secs(120 * 60));
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0], TimeWindowIssue::ArrivesTooLate { control_point_id: 2, required_speed_mps, .. }
+            if required_speed_mps > 10.0));
+    }
+
+    #[test]
+    fn test_discontinuous_legs_fail_validation() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![
+            path_through(1, &[(45.0, 2.0), (45.01, 2.0)]),
+            path_through(2, &[(45.02, 2.0), (45.03, 2.0)]),
+        ];
+
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0],
+            MissionValidationIssue::DiscontinuousLegs { from_path_id: 1, to_path_id: 2, gap_m } if (gap_m - 1112.0).abs() < 5.0));
+
+        // Three segments of ~1.11 km each, including the transition between legs
+        assert!((mission.flight_plan.total_distance_m() - 3336.0).abs() < 15.0);
+
+        mission.flight_plan.paths[1] = path_through(2, &[(45.01, 2.0), (45.02, 2.0)]);
+        assert!(mission.validate().is_ok());
+    }
+
+    #[test]
+    fn test_negative_speed_limit_fails_validation() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        mission.flight_plan.paths[0].waypoints[1].speed_limit_mps = Some(-4.0);
+
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues, vec![MissionValidationIssue::PhysicalLimit {
+            path_id: 1,
+            waypoint_id: Some(1),
+            field: "speed_limit_mps".to_string(),
+            value: -4.0,
+            limit: "must be non-negative".to_string(),
+        }]);
+        assert_eq!(issues[0].to_string(), "physical_limit: path 1 waypoint 1 speed_limit_mps = -4 must be non-negative");
+
+        // Below sea level is a valid site, not a physical limit
+        mission.flight_plan.paths[0].waypoints[1].speed_limit_mps = None;
+        for waypoint in &mut mission.flight_plan.paths[0].waypoints {
+            waypoint.position.altitude_msl = -400.0;
+        }
+        assert!(mission.validate().is_ok());
+    }
+
+    #[test]
+    fn test_loiter_time_without_radius_fails_validation() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(3, &[(45.0, 2.0), (45.01, 2.0)])];
+        mission.flight_plan.paths[0].waypoints[0].loiter_time_seconds = Some(30);
+        mission.flight_plan.paths[0].waypoints[0].loiter_radius_m = Some(0.0);
+
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], MissionValidationIssue::PhysicalLimit { path_id: 3, waypoint_id: Some(0), field, .. }
+            if field == "loiter_radius_m"));
+
+        mission.flight_plan.paths[0].waypoints[0].loiter_radius_m = Some(15.0);
+        assert!(mission.validate().is_ok());
+    }
+
+    #[test]
+    fn test_mavlink_waypoints_import_with_tolerances() {
+        let mut items = vec![
+            mavlink_item(0, mav_cmd::NAV_WAYPOINT, [0.0; 4], 45.0, 2.0, 0.0),
+            mavlink_item(1, mav_cmd::NAV_TAKEOFF, [0.0; 4], 45.0, 2.0, 30.0),
+            mavlink_item(2, mav_cmd::NAV_WAYPOINT, [0.0, 3.5, 0.0, 0.0], 45.001, 2.0, 40.0),
+            mavlink_item(3, mav_cmd::NAV_LOITER_TIME, [20.0, 0.0, 15.0, 0.0], 45.002, 2.001, 40.0),
+            mavlink_item(4, mav_cmd::NAV_RETURN_TO_LAUNCH, [0.0; 4], 0.0, 0.0, 0.0),
+        ];
+        items[0].frame = mav_frame::GLOBAL;
+        items[0].z = 120.0; // Home at 120 m MSL
+
+        let checksum = FlightPlan::mavlink_checksum(&items);
+        let (plan, control_points) = FlightPlan::from_mavlink_with_control_points(&items).unwrap();
+        let waypoints = &plan.paths[0].waypoints;
+
+        assert_eq!(waypoints.len(), 5);
+        assert_eq!(waypoints[2].position, GeoCoordinate { latitude: 45.001, longitude: 2.0, altitude_msl: 160.0 });
+        assert_eq!(waypoints[2].position_tolerance_m, 3.5);
+        assert_eq!(waypoints[1].position_tolerance_m, 2.0);
+        assert_eq!((waypoints[3].loiter_time_seconds, waypoints[3].loiter_radius_m), (Some(20), Some(15.0)));
+        assert_eq!(waypoints[4].position, plan.home_location);
+        assert!(matches!(control_points.as_slice(), [ControlPoint::ReturnToBase { id: 4, .. }]));
+        assert!(plan.paths[0].physical_limit_violations().is_empty());
+        assert!(FlightPlan::from_mavlink_verified(&items, checksum).is_ok());
+        assert!(matches!(FlightPlan::from_mavlink_verified(&items, checksum ^ 1), Err(ImportError::ChecksumMismatch { .. })));
+
+        // Unlimited loiter has no equivalent and is reported, not dropped
+        items.push(mavlink_item(5, 17, [0.0; 4], 45.0, 2.0, 40.0));
+        assert_eq!(FlightPlan::from_mavlink(&items).unwrap_err(), ImportError::UnsupportedCommands(vec![(5, 17)]));
+    }
+
+    #[test]
+    fn test_degraded_formation_sheds_to_feasible_weight() {
+        // Four-drone lift lost one drone; a scout does not count towards lift
+        let config = formation(
+            vec![
+                formation_drone("lift_1", DroneRole::Leader),
+                formation_drone("lift_2", DroneRole::Lift),
+                formation_drone("lift_3", DroneRole::Lift),
+                formation_drone("scout", DroneRole::Scout),
+            ],
+            30.0,
+            10.0,
+        );
+
+        let (degraded, shed_kg) = config.shed_to_feasible(0.2).unwrap();
+
+        // 3 drones * 10 kg * (1 - 0.2) = 24 kg carriable
+        assert!((shed_kg - 6.0).abs() < 1e-4);
+        assert!((degraded.payload_config.weight_kg - 24.0).abs() < 1e-4);
+        assert!((degraded.load_distribution.target_load_per_drone_kg - 8.0).abs() < 1e-4);
+        assert!(matches!(config.shed_to_feasible(1.0), Err(ReconfigError::InvalidMargin(_))));
+    }
+
+    #[test]
+    fn test_clock_skew_beyond_tolerance_flags_drone() {
+        // 100 ms sync interval gives a 10 ms tolerance
+        let config = formation(
+            vec![
+                formation_drone("lift_1", DroneRole::Leader),
+                formation_drone("lift_2", DroneRole::Lift),
+                formation_drone("lift_3", DroneRole::Lift),
+            ],
+            20.0,
+            10.0,
+        );
+        let offsets = HashMap::from([
+            ("lift_1".to_string(), Duration::from_millis(0)),
+            ("lift_2".to_string(), Duration::from_millis(4)),
+            ("lift_3".to_string(), Duration::from_millis(35)),
+        ]);
+
+        let quality = config.evaluate_clock_sync(&offsets);
+
+        assert_eq!(quality.tolerance, Duration::from_millis(10));
+        assert_eq!(quality.max_skew, Duration::from_millis(35));
+        assert!(!quality.within_tolerance);
+        assert_eq!(quality.offenders, vec!["lift_3".to_string()]);
+    }
+
+    #[test]
+    fn test_firmware_versions_follow_semver_precedence() {
+        let version = |v: &str| FirmwareVersion::parse(v).unwrap();
+
+        assert!(version("1.10.0") > version("1.9.3"));
+        assert!(version("2.0.0-rc.1") < version("2.0.0"));
+        assert!(version("2.0.0-alpha") < version("2.0.0-alpha.1"));
+        assert!(version("2.0.0-alpha.2") < version("2.0.0-beta"));
+        assert_eq!(version("v2.1"), version("2.1.0+build.42"));
+        assert!(FirmwareVersion::parse("two.one").is_none());
+    }
+
+    #[test]
+    fn test_displaced_drone_is_corrected_back_to_slot() {
+        let mut drones = vec![
+            formation_drone("lift_1", DroneRole::Leader),
+            formation_drone("lift_2", DroneRole::Lift),
+        ];
+        drones[1].position.x_offset_m = 10.0;
+        let config = formation(drones, 20.0, 10.0);
+        let center = GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 };
+        let targets: HashMap<String, GeoCoordinate> = config.resolve_positions(&center, 0.0).into_iter().collect();
+
+        // lift_1 within the 1 m deadband, lift_2 blown 5 m north of its slot
+        let mut actual = targets.clone();
+        actual.insert("lift_1".to_string(), targets["lift_1"].offset_by(0.5, 0.0));
+        actual.insert("lift_2".to_string(), targets["lift_2"].offset_by(0.0, 5.0));
+
+        let corrections = config.compute_corrections(&actual, &center, 0.0);
+
+        assert!(corrections["lift_1"].is_zero());
+        let correction = corrections["lift_2"];
+        assert!(correction.north_mps < 0.0);
+        assert!(correction.east_mps.abs() < 0.01);
+        assert!((correction.speed_mps() - MAX_FORMATION_CORRECTION_MPS).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_formation_kml_has_placemark_per_drone_and_is_well_formed() {
+        let mut drones = vec![
+            formation_drone("lift_1", DroneRole::Leader),
+            formation_drone("lift_2", DroneRole::Lift),
+            formation_drone("scout", DroneRole::Scout),
+        ];
+        drones[1].position.x_offset_m = 10.0;
+        let mut config = formation(drones, 20.0, 10.0);
+        config.formation_geofence = Some(GeoBounds { north: 45.01, south: 44.99, east: 2.01, west: 1.99, min_altitude: 0.0, max_altitude: 150.0 });
+        let center = GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 };
+
+        // Facing east, an east offset ends up to the south
+        let positions = config.resolve_positions(&center, 90.0);
+        assert!(positions[1].1.latitude < center.latitude);
+        assert!((positions[1].1.distance_to(&center) - 10.0).abs() < 0.1);
+
+        let kml = config.to_kml(&center, 90.0);
+        for drone_id in ["lift_1", "lift_2", "scout"] {
+            assert!(kml.contains(&format!("<name>{} (", drone_id)));
+        }
+        assert!(kml.contains("scout (Scout, 0.0 kg)"));
+        assert!(kml.contains("Formation geofence"));
+        assert_eq!(kml.matches("<Placemark>").count(), 5); // 3 drones, footprint, geofence
+
+        // Every opening tag is closed in order
+        let mut stack = Vec::new();
+        for tag in kml.split('<').skip(1).map(|rest| rest.split('>').next().unwrap()) {
+            if tag.starts_with('?') {
+                continue;
+            } else if let Some(name) = tag.strip_prefix('/') {
+                assert_eq!(stack.pop(), Some(name.to_string()));
+            } else {
+                stack.push(tag.split_whitespace().next().unwrap().to_string());
+            }
+        }
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_hazardous_payload_over_crowded_area_fails_validation() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        let mut config = formation(vec![formation_drone("lift-1", DroneRole::Leader)], 20.0, 50.0);
+        config.payload_config.payload_type = PayloadType::Hazardous {
+            hazard_class: "UN3480".to_string(),
+            containment: "sealed_drum".to_string(),
+        };
+        mission.formation_config = Some(config);
+        mission.policies.authorization_scopes.push(AuthorizationScope::HazardousMaterials);
+        mission.initial_conditions = vec![CONTAINMENT_CHECK_CONDITION.to_string()];
+        mission.tasks = vec![task(1, 1, &[CONTAINMENT_CHECK_CONDITION], None)];
+
+        // ~80 m east of the route: clear of the 50 m crowd limit but not the doubled hazmat margin
+        mission.constraints.safety.populated_areas = vec![bounds(45.004, 45.006, 2.001, 2.002)];
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            &issues[0],
+            MissionValidationIssue::HazardousPayload { violation: HazmatViolation::CrowdProximity { path_id: 1, area_index: 0, .. }, .. }
+        ));
+        assert_eq!(issues[0].severity(), ViolationSeverity::Critical);
+
+        mission.constraints.safety.populated_areas = vec![bounds(45.004, 45.006, 2.01, 2.02)];
+        assert!(mission.validate().is_ok());
+
+        mission.policies.authorization_scopes.retain(|scope| *scope != AuthorizationScope::HazardousMaterials);
+        mission.tasks[0].preconditions.clear();
+        let issues = mission.validate().unwrap_err();
+        assert!(issues.iter().all(|issue| issue.severity() == ViolationSeverity::Critical));
+        assert_eq!(issues.len(), 2);
+    }
+
+    #[test]
+    fn test_altitude_separated_crossing_routes_do_not_conflict() {
+        let start = SystemTime::now();
+        let mission = |id: u8, points: &[(f64, f64)], altitude_msl: f32| {
+            let mut mission = MissionPayload::default();
+            mission.header.id = [id; 16];
+            mission.header.validity_start = start;
+            let mut path = path_through(1, points);
+            for waypoint in &mut path.waypoints {
+                waypoint.position.altitude_msl = altitude_msl;
+            }
+            mission.flight_plan.paths = vec![path];
+            mission
+        };
+        // Reciprocal tracks 8 m apart that pass each other mid-route
+        let northbound = mission(1, &[(44.995, 2.0), (45.005, 2.0)], 100.0);
+        let southbound_high = mission(2, &[(45.005, 2.0001), (44.995, 2.0001)], 140.0);
+        let southbound_level = mission(3, &[(45.005, 2.0001), (44.995, 2.0001)], 100.0);
+        let separation = SeparationConfig { vertical_m: 30.0, ..Default::default() };
+
+        assert!(detect_conflicts(&[&northbound, &southbound_high], &separation).is_empty());
+
+        let conflicts = detect_conflicts(&[&northbound, &southbound_level], &separation);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].mission_b, [3; 16]);
+        assert!(conflicts[0].horizontal_m < separation.horizontal_m);
+        assert!(conflicts[0].at > start);
+    }
+
+    #[test]
+    fn test_heavy_lift_takeoff_schedule_follows_sequence_and_delays() {
+        let drones: Vec<FormationDrone> = [("DRONE-SE", 1500), ("DRONE-NW", 0), ("DRONE-SW", 1000), ("DRONE-NE", 500)]
+            .into_iter()
+            .map(|(id, delay_ms)| {
+                let mut drone = formation_drone(id, DroneRole::Lift);
+                drone.synchronization_offset.takeoff_delay_ms = delay_ms;
+                drone
+            })
+            .collect();
+        let mut sync = formation(drones.clone(), 100.0, 50.0).synchronization;
+        sync.takeoff_sequence = ["DRONE-NW", "DRONE-NE", "DRONE-SW", "DRONE-SE"].iter().map(|id| id.to_string()).collect();
+        sync.landing_sequence = sync.takeoff_sequence.iter().rev().cloned().collect();
+
+        let takeoff = sync.build_takeoff_schedule(&drones).unwrap();
+        let order: Vec<(&str, u128)> = takeoff.iter().map(|c| (c.drone_id.as_str(), c.offset.as_millis())).collect();
+        assert_eq!(order, vec![("DRONE-NW", 0), ("DRONE-NE", 500), ("DRONE-SW", 1000), ("DRONE-SE", 1500)]);
+        assert!(takeoff.iter().all(|c| c.command == FormationCommand::Ascend { target_altitude: 50.0 }));
+
+        let landing = sync.build_landing_schedule(&drones).unwrap();
+        let order: Vec<(&str, u128)> = landing.iter().map(|c| (c.drone_id.as_str(), c.offset.as_millis())).collect();
+        assert_eq!(order, vec![("DRONE-SE", 0), ("DRONE-SW", 500), ("DRONE-NE", 1000), ("DRONE-NW", 1500)]);
+        assert!(landing.iter().all(|c| c.command == FormationCommand::Land));
+
+        sync.takeoff_sequence.push("DRONE-XX".to_string());
+        assert_eq!(sync.build_takeoff_schedule(&drones), Err(ScheduleError::UnknownDrone("DRONE-XX".to_string())));
+    }
+
+    #[test]
+    fn test_geofence_straddling_antimeridian_and_touching_pole() {
+        let dateline = bounds(-10.0, 10.0, 170.0, -170.0);
+        assert!(dateline.contains(&GeoCoordinate { latitude: 5.0, longitude: 175.0, altitude_msl: 50.0 }));
+        assert!(dateline.contains(&GeoCoordinate { latitude: 5.0, longitude: -185.0, altitude_msl: 50.0 }));
+        assert!(!dateline.contains(&GeoCoordinate { latitude: 5.0, longitude: -160.0, altitude_msl: 50.0 }));
+
+        // A route across 180° stays inside a keep-in box straddling it
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(0.0, 179.9), (0.0, -179.9)])];
+        mission.constraints.geofencing = vec![
+            GeofenceZone::KeepIn { bounds: dateline, reason: "maritime sector".to_string() },
+            GeofenceZone::Corridor {
+                waypoints: vec![
+                    GeoCoordinate { latitude: 0.0, longitude: 179.5, altitude_msl: 100.0 },
+                    GeoCoordinate { latitude: 0.0, longitude: -179.5, altitude_msl: 100.0 },
+                ],
+                width_m: 200.0,
+            },
+        ];
+        assert!(mission.geofence_breaches(100.0).is_empty());
+        assert!(mission.validate().is_ok());
+
+        let arctic = bounds(85.0, 90.0, -10.0, 10.0);
+        assert!(arctic.contains(&GeoCoordinate { latitude: 90.0, longitude: 120.0, altitude_msl: 50.0 }));
+        assert!(!arctic.contains(&GeoCoordinate { latitude: 89.0, longitude: 120.0, altitude_msl: 50.0 }));
+
+        mission.constraints.geofencing = vec![GeofenceZone::KeepOut {
+            bounds: bounds(85.0, 95.0, -10.0, 10.0),
+            reason: "ice shelf".to_string(),
+            exception_conditions: vec![],
+        }];
+        let issues = mission.validate().unwrap_err();
+        assert!(matches!(&issues[..], [MissionValidationIssue::InvalidGeofence { zone_index: 0, .. }]));
+    }
+
+    #[test]
+    fn test_signed_mission_detects_waypoint_change() {
+        let engine = CryptoEngine::new();
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        assert!(!mission.verify(&engine));
+
+        mission.sign(&engine).unwrap();
+        assert!(mission.verify(&engine));
+        assert!(!mission.verify(&CryptoEngine::new()));
+
+        // Survives serialization, as when a drone receives it
+        let received: MissionPayload = serde_cbor::from_slice(&serde_cbor::to_vec(&mission).unwrap()).unwrap();
+        assert!(received.verify_with_key(engine.ed25519_public_key()));
+
+        mission.flight_plan.paths[0].waypoints[1].position.latitude += 0.001;
+        assert!(!mission.verify(&engine));
+    }
+
+    #[test]
+    fn test_partition_beyond_tolerance_triggers_fail_safe() {
+        let link = |from: &str, to: &str| MeshLink {
+            from_drone: from.to_string(),
+            to_drone: to.to_string(),
+            link_type: CommunicationType::MeshRouting,
+            max_distance_m: 20.0,
+            redundancy_required: true,
+        };
+        let mut leader = formation_drone("LEAD", DroneRole::Leader);
+        leader.fail_safe_behavior = FailSafeBehavior::DescendSlowly;
+        let mut config = formation(vec![
+            leader,
+            formation_drone("LIFT-1", DroneRole::Lift),
+            formation_drone("LIFT-2", DroneRole::Lift),
+            formation_drone("SCOUT", DroneRole::Scout),
+        ], 100.0, 80.0);
+        config.communication_mesh = vec![link("LEAD", "LIFT-1"), link("LEAD", "LIFT-2"), link("LIFT-1", "LIFT-2"), link("LEAD", "SCOUT")];
+        let tolerance = Duration::from_millis(config.synchronization.partition_tolerance_ms as u64);
+
+        // LIFT-1 is still reachable through LIFT-2
+        assert!(matches!(config.on_partition(&[link("LIFT-1", "LEAD")], tolerance * 2), FormationResponse::Continue));
+
+        let lost = [link("LEAD", "LIFT-1"), link("LIFT-2", "LIFT-1")];
+        assert!(matches!(config.on_partition(&lost, tolerance / 2), FormationResponse::Hold { .. }));
+        match config.on_partition(&lost, tolerance + Duration::from_millis(1)) {
+            FormationResponse::TriggerFailSafe { behavior, isolated_drones } => {
+                assert!(matches!(behavior, FailSafeBehavior::DescendSlowly));
+                assert_eq!(isolated_drones, vec!["LIFT-1".to_string()]);
+            }
+            other => panic!("expected fail-safe, got {:?}", other),
+        }
+
+        // Losing only the scout reconfigures without it
+        assert!(matches!(
+            config.on_partition(&[link("LEAD", "SCOUT")], tolerance * 2),
+            FormationResponse::Reconfigure { ref dropped_drones } if dropped_drones == &vec!["SCOUT".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_non_numeric_custom_param_fails_validation() {
+        let sync_check = |position_tolerance: &str| MissionAction::Custom {
+            action_type: "formation_sync_check".to_string(),
+            parameters: [
+                ("position_tolerance", position_tolerance),
+                ("heading_alignment", "5.0"),
+                ("altitude_sync", "1.0"),
+            ].iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+        };
+        let schema = CustomActionSchema::builtin();
+        assert!(sync_check("0.5").validate_params(&schema).is_ok());
+
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        let mut sync_task = task(3, 1, &[], None);
+        sync_task.actions = vec![sync_check("abc")];
+        mission.tasks = vec![sync_task];
+
+        let issues = mission.validate().unwrap_err();
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(&issues[0], MissionValidationIssue::InvalidActionParams {
+            task_id: 3,
+            error: ParamError::Malformed { parameter, expected: ParamKind::Number, .. },
+        } if parameter == "position_tolerance"));
+    }
+
+    #[test]
+    fn test_thermal_tag_needs_privacy_review_in_restricted_jurisdiction() {
+        let mut tag_policies = TagPolicyTable::default();
+        tag_policies.set("thermal", TagPolicy {
+            restricted_jurisdictions: vec!["FR".to_string()],
+            restricted_approvals: vec!["privacy_review".to_string()],
+            ..Default::default()
+        });
+
+        let mut untagged = MissionPayload::default();
+        untagged.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        let mut thermal = untagged.clone();
+        thermal.header.tags = vec!["Thermal".to_string(), "night-ops".to_string()];
+
+        assert!(tag_policies.required_approvals(&untagged, Some("FR")).is_empty());
+        assert_eq!(tag_policies.required_approvals(&thermal, Some("FR")), vec!["privacy_review".to_string()]);
+        assert!(tag_policies.required_approvals(&thermal, Some("DE")).is_empty());
+        assert_eq!(tag_policies.unknown_tags(&thermal), vec!["night-ops"]);
+
+        // Approvals are recorded by the operator workflow, so validation does not gate on them
+        assert!(thermal.validate_with_tag_policies(&tag_policies, Some("FR")).is_ok());
+    }
+
+    #[test]
+    fn test_corner_drone_breaches_keep_in_while_center_is_inside() {
+        let corner = |id: &str, x_offset_m: f32, y_offset_m: f32| {
+            let mut drone = formation_drone(id, DroneRole::Lift);
+            drone.position.x_offset_m = x_offset_m;
+            drone.position.y_offset_m = y_offset_m;
+            drone
+        };
+        let config = formation(vec![
+            corner("NW", -8.0, 6.0),
+            corner("NE", 8.0, 6.0),
+            corner("SW", -8.0, -6.0),
+            corner("SE", 8.0, -6.0),
+        ], 200.0, 70.0);
+        let center = GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 50.0 };
+
+        // East edge 5 m from the center: inside for the center, not for the eastern drones
+        let east_edge = center.offset_by(5.0, 0.0).longitude;
+        let keep_in = GeofenceZone::KeepIn { bounds: bounds(44.999, 45.001, 1.999, east_edge), reason: "site boundary".to_string() };
+        assert!(keep_in.permits(&center));
+
+        let breaches = config.check_geofence(&center, 0.0, &[keep_in]);
+        let elements: Vec<FootprintElement> = breaches.iter().map(|breach| breach.element.clone()).collect();
+        assert_eq!(elements, vec![FootprintElement::Drone("NE".to_string()), FootprintElement::Drone("SE".to_string())]);
+    }
+
+    #[test]
+    fn test_verification_code_matches_only_identical_missions() {
+        let station_copy = MissionPayload::default();
+        let drone_copy: MissionPayload = serde_json::from_slice(&serde_json::to_vec(&station_copy).unwrap()).unwrap();
+
+        let code = station_copy.verification_code();
+        assert_eq!(code, drone_copy.verification_code());
+        assert_eq!(code.len(), 9);
+        assert!(code.chars().all(|c| c == '-' || c.is_ascii_uppercase() || ('2'..='7').contains(&c)));
+
+        let mut altered = drone_copy.clone();
+        altered.header.name.push('!');
+        assert_ne!(code, altered.verification_code());
+    }
+
+    #[test]
+    fn test_generated_approach_respects_descent_rate_and_clearance() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0)])];
+        let plan = &mission.flight_plan;
+        let target = GeoCoordinate { latitude: 45.01, longitude: 2.0, altitude_msl: 20.0 };
+
+        let approach = plan.generate_approach(&target, ApproachProfile::default());
+
+        assert!(approach.len() > 2);
+        let mut previous = plan.paths[0].waypoints[0].position.clone();
+        for waypoint in &approach {
+            let run_m = previous.distance_to(&waypoint.position);
+            let drop_m = previous.altitude_msl - waypoint.position.altitude_msl;
+            let duration_s = run_m / waypoint.speed_limit_mps.unwrap();
+            assert!(drop_m <= 2.0 * duration_s + 1e-3, "descent of {} m in {} s", drop_m, duration_s);
+            previous = waypoint.position.clone();
+        }
+        let last = approach.last().unwrap();
+        assert!((last.position.altitude_msl - 22.0).abs() < 1e-3);
+        assert!(last.position.distance_to(&target) < 1.0);
+        assert!(last.speed_limit_mps.unwrap() <= 0.5 + 1e-6);
+
+        // Starting 55 m out, well inside the glideslope, most of the drop is vertical-only
+        let steep_target = GeoCoordinate { latitude: 45.0005, longitude: 2.0, altitude_msl: 20.0 };
+        let steep = plan.generate_approach(&steep_target, ApproachProfile::default());
+        let mut previous = plan.paths[0].waypoints[0].position.clone();
+        let mut vertical_legs = 0;
+        for waypoint in &steep {
+            if previous.distance_to(&waypoint.position) < 0.5 {
+                vertical_legs += 1;
+                assert!(waypoint.speed_limit_mps.unwrap() <= 2.0 + 1e-6);
+            }
+            previous = waypoint.position.clone();
+        }
+        assert!(vertical_legs > 0);
+    }
+
+    #[test]
+    fn test_hexagon_tolerates_two_losses_but_not_three() {
+        let mut config = formation(
+            (1..=6).map(|n| formation_drone(&format!("hex_{}", n), DroneRole::Lift)).collect(),
+            40.0,
+            12.0,
+        );
+        config.formation_type = FormationType::Hexagon;
+        config.min_operational_drones = 4;
+        let lost = |count: usize| (1..=count).map(|n| format!("hex_{}", n)).collect::<Vec<_>>();
+
+        // 40 kg over 4 survivors is 10 kg each, within the 12 kg limit
+        assert!(config.is_viable_after_losses(&lost(2)));
+        assert!(!config.is_viable_after_losses(&lost(3)));
+
+        // Three survivors would each carry 13.3 kg even with the count requirement waived
+        config.min_operational_drones = 0;
+        let mut survivors = config.clone();
+        survivors.drones.drain(..3);
+        assert!(matches!(survivors.solve_load_distribution(), Err(ReconfigError::Overloaded { .. })));
+        assert!(!config.is_viable_after_losses(&lost(3)));
+    }
+
+    #[test]
+    fn test_amendment_bumps_revision_and_resigns() {
+        let engine = CryptoEngine::new();
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        mission.sign(&engine).unwrap();
+
+        // Re-route the final waypoint around a new restriction
+        let amended = mission.amend(|mission| mission.flight_plan.paths[0].waypoints[1].position.longitude = 2.01, &engine).unwrap();
+
+        assert_eq!(amended.revision, 1);
+        assert_eq!(amended.new.header.revision, 1);
+        assert_eq!(amended.new.header.id, amended.previous.header.id);
+        assert_eq!(amended.new.header.supersedes, Some(amended.previous.revision_fingerprint().unwrap()));
+        assert_ne!(amended.new.crypto.payload_signature, amended.previous.crypto.payload_signature);
+        assert!(amended.new.verify(&engine));
+        assert!(amended.previous.verify(&engine));
+
+        assert!(!amended.diff.is_empty());
+        assert_eq!(amended.diff.changes, vec![FieldChange {
+            path: "flight_plan.paths[0].waypoints[1].position.longitude".to_string(),
+            before: Some(serde_json::json!(2.0)),
+            after: Some(serde_json::json!(2.01)),
+        }]);
+    }
+
+    #[test]
+    fn test_packed_encoding_round_trips_and_beats_cbor() {
+        for (name, mission) in crate::fixtures::representative_missions() {
+            let packed = mission.to_packed().unwrap();
+            let cbor = serde_cbor::to_vec(&mission).unwrap();
+
+            assert!(packed.len() < cbor.len(), "{}: packed {} bytes, CBOR {} bytes", name, packed.len(), cbor.len());
+            let decoded = MissionPayload::from_packed(&packed).unwrap();
+            assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&mission).unwrap(), "{}", name);
+        }
+
+        let mut future_layout = crate::fixtures::surveillance_mission().to_packed().unwrap();
+        future_layout[0] = PACKED_FORMAT_VERSION + 1;
+        assert!(matches!(MissionPayload::from_packed(&future_layout), Err(PackedMissionError::UnsupportedVersion { .. })));
+        assert_eq!(MissionPayload::from_packed(&[]).unwrap_err(), PackedMissionError::Empty);
+    }
+
+    #[test]
+    fn test_packed_mission_with_oversized_length_prefix_is_refused() {
+        let packed = crate::fixtures::surveillance_mission().to_packed().unwrap();
+        // Version byte and the 16-byte mission ID, then the name's varint length prefix
+        let name_len_at = 1 + 16;
+        assert!(packed[name_len_at] < 251, "fixture name should use a one-byte length");
+
+        let mut oversized = packed[..name_len_at].to_vec();
+        oversized.push(253); // Varint marker for a little-endian u64
+        oversized.extend_from_slice(&(MAX_PACKED_MISSION_BYTES * 64).to_le_bytes());
+        oversized.extend_from_slice(&packed[name_len_at + 1..]);
+
+        assert_eq!(MissionPayload::from_packed(&oversized).unwrap_err(),
+            PackedMissionError::TooLarge { limit: MAX_PACKED_MISSION_BYTES });
+
+        let mut trailing = packed.clone();
+        trailing.push(0);
+        assert!(matches!(MissionPayload::from_packed(&trailing), Err(PackedMissionError::Codec(_))));
+    }
+
+    #[test]
+    fn test_mission_over_waypoint_limit_reports_count() {
+        let mut mission = MissionPayload::default();
+        mission.flight_plan.paths = vec![
+            path_through(1, &[(45.0, 2.0), (45.01, 2.0)]),
+            path_through(2, &[(45.01, 2.0), (45.02, 2.0), (45.03, 2.0)]),
+        ];
+        let limits = MissionLimits { max_waypoints: 4, ..MissionLimits::default() };
+
+        let issues = mission.validate_with_limits(&limits).unwrap_err();
+
+        assert_eq!(issues, vec![MissionValidationIssue::ExceedsLimit { item: "waypoints".to_string(), count: 5, max: 4 }]);
+        assert_eq!(issues[0].to_string(), "exceeds_limit: 5 waypoints, maximum 4");
+        assert!(mission.validate().is_ok());
+    }
+
+    #[test]
+    fn test_underpowered_lift_drone_fails_role_validation() {
+        // 100 kg over four drones: each needs to lift 25 kg
+        let config = formation(vec![
+            formation_drone("lead", DroneRole::Leader),
+            formation_drone("lift-1", DroneRole::Lift),
+            formation_drone("lift-2", DroneRole::Lift),
+            formation_drone("lift-3", DroneRole::Lift),
+        ], 100.0, 40.0);
+        let lift = |max_payload_kg: f32| RoleCapabilities { max_payload_kg, ..RoleCapabilities::default() };
+        let mut caps: HashMap<String, RoleCapabilities> = config.drones.iter()
+            .map(|drone| (drone.drone_id.clone(), lift(30.0)))
+            .collect();
+        assert_eq!(config.validate_roles(&caps), Ok(()));
+
+        caps.insert("lift-2".to_string(), lift(10.0));
+        let mismatches = config.validate_roles(&caps).unwrap_err();
+        assert_eq!(mismatches, vec![RoleMismatch::InsufficientPayload {
+            drone_id: "lift-2".to_string(),
+            required_kg: 25.0,
+            available_kg: 10.0,
+        }]);
+    }
+
+    #[test]
+    fn test_gps_denied_path_beyond_drift_budget_is_flagged() {
+        let mut navigation = NavigationCapabilities::default();
+        let mut mission = MissionPayload::default();
+        // About 1.1 km at 10 m/s: 111 s of dead reckoning
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        mission.flight_plan.paths[0].navigation_mode = NavigationMode::GpsDenied { max_drift_m: 5.0 };
+
+        assert!(matches!(mission.check_navigation(&navigation).as_slice(), [NavigationIssue::UnsupportedMode { path_id: 1, .. }]));
+
+        navigation.dead_reckoning_drift_mps = Some(0.1);
+        let issues = mission.check_navigation(&navigation);
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(issues[0], NavigationIssue::DriftBudgetExceeded { path_id: 1, expected_drift_m, max_drift_m: 5.0 }
+            if expected_drift_m > 10.0 && expected_drift_m < 12.0));
+
+        // A tenth of the distance stays inside the budget
+        mission.flight_plan.paths[0] = path_through(1, &[(45.0, 2.0), (45.001, 2.0)]);
+        mission.flight_plan.paths[0].navigation_mode = NavigationMode::GpsDenied { max_drift_m: 5.0 };
+        assert!(mission.check_navigation(&navigation).is_empty());
+    }
+
+    #[test]
+    fn test_corner_is_smoothed_into_arc_and_tight_turn_flagged() {
+        // North about 1.1 km, then a right angle east for about 790 m
+        let mut path = path_through(1, &[(45.0, 2.0), (45.01, 2.0), (45.01, 2.01)]);
+        path.min_turn_radius_m = Some(50.0);
+        let corner = path.waypoints[1].position.clone();
+
+        let smoothed = path.smooth_turns();
+        // Start, a 90 degree arc in 10 degree steps, end
+        assert_eq!(smoothed.len(), 12);
+        let (entry, exit) = (&smoothed[1], &smoothed[10]);
+        assert!((entry.distance_to(&corner) - 50.0).abs() < 1.0);
+        assert!((exit.distance_to(&corner) - 50.0).abs() < 1.0);
+        // The arc cuts the corner, passing about R / cos(45) - R = 20.7 m from it
+        let closest = smoothed[1..11].iter().map(|point| point.distance_to(&corner)).fold(f32::MAX, f32::min);
+        assert!(closest > 20.0 && closest < 23.0);
+        assert!(path.infeasible_turns().is_empty());
+
+        path.min_turn_radius_m = Some(2000.0);
+        let tight = path.infeasible_turns();
+        assert_eq!(tight.len(), 1);
+        assert_eq!(tight[0].waypoint_id, 1);
+        assert!(tight[0].required_leg_m > tight[0].available_leg_m);
+    }
 }
@@ -8,6 +8,7 @@ use crate::laser::{LaserEngine, ModulationScheme, PowerProfile};
 use crate::ultrasonic_beam::UltrasonicBeamEngine;
 use crate::range_detector::{RangeDetector, RangeDetectorCategory};
 use crate::security::WeatherCondition;
+use crate::cancellation::CancellationToken;
 use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -24,6 +25,8 @@ pub enum PerformanceError {
     InvalidMetrics,
     #[error("Timeout during performance test")]
     Timeout,
+    #[error("Operation cancelled")]
+    Cancelled,
 }
 
 /// Performance metrics for different communication aspects
@@ -51,6 +54,64 @@ pub struct EnvironmentalFactors {
     pub wind_speed_mps: f32,
 }
 
+/// Fast analytic link model used to seed benchmarks without spending hardware time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelModel {
+    pub max_range_m: f64, // Range at which signal falls to zero in clear air
+    pub handshake_base_ms: f64,
+    pub handshake_ms_per_m: f64,
+    pub environmental_conditions: EnvironmentalFactors,
+}
+
+impl Default for ChannelModel {
+    fn default() -> Self {
+        Self {
+            max_range_m: 200.0,
+            handshake_base_ms: 300.0,
+            handshake_ms_per_m: 1.0,
+            environmental_conditions: EnvironmentalFactors::default(),
+        }
+    }
+}
+
+impl ChannelModel {
+    /// Normalized signal strength at a range, reduced when visibility is shorter than the link
+    pub fn signal_at(&self, range_m: f64) -> f64 {
+        let visibility = (self.environmental_conditions.visibility_meters as f64 / self.max_range_m).min(1.0);
+        ((1.0 - range_m / self.max_range_m) * visibility).clamp(0.0, 1.0)
+    }
+
+    /// Predicted metrics for a modulation at a range, given the link's nominal rate and power budget
+    pub fn predict(&self, modulation: ModulationScheme, range_m: f64, nominal_bps: f64, max_power_mw: f64) -> PerformanceMetrics {
+        let signal_strength = self.signal_at(range_m);
+        let robustness = match modulation {
+            ModulationScheme::Ook => 1.0,
+            ModulationScheme::Pwm => 0.8,
+            ModulationScheme::Manchester => 0.6,
+            ModulationScheme::QrProjection => 0.5,
+            _ => 1.0,
+        };
+        let bit_error_rate = error_rate_for_signal(signal_strength) * robustness;
+
+        PerformanceMetrics {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            handshake_latency_ms: self.handshake_base_ms + self.handshake_ms_per_m * range_m,
+            data_throughput_bps: nominal_bps * (1.0 - bit_error_rate),
+            bit_error_rate,
+            packet_loss_rate: bit_error_rate * 2.0,
+            power_consumption_mw: max_power_mw * (0.4 + 0.5 * (range_m / self.max_range_m).min(1.0)),
+            range_meters: range_m,
+            signal_strength,
+            modulation_scheme: modulation,
+            ecc_strength: 0.6 + (bit_error_rate * 0.4),
+            environmental_conditions: self.environmental_conditions.clone(),
+        }
+    }
+}
+
 /// Benchmark results for different configurations
 #[derive(Debug, Clone)]
 pub struct BenchmarkResult {
@@ -59,6 +120,7 @@ pub struct BenchmarkResult {
     pub score: f64, // Overall performance score (0-100)
     pub reliability_score: f64,
     pub efficiency_score: f64,
+    pub simulated: bool, // Seeded from a ChannelModel; replaced by the first real benchmark run
 }
 
 /// Performance configuration presets
@@ -86,6 +148,9 @@ pub struct PerformanceConfig {
     pub environmental_compensation: bool,
 }
 
+/// Default EMA weight of the newest sample when smoothing metrics for optimization
+pub const DEFAULT_SMOOTHING_ALPHA: f32 = 0.3;
+
 /// Real-time performance monitor
 pub struct PerformanceMonitor {
     metrics_history: Arc<Mutex<VecDeque<PerformanceMetrics>>>,
@@ -98,6 +163,7 @@ pub struct PerformanceMonitor {
     protocol_engine: Option<Arc<Mutex<crate::protocol::ProtocolEngine>>>,
     monitoring_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
     max_history_size: usize,
+    smoothing_alpha: f32,
 }
 
 impl PerformanceMonitor {
@@ -113,9 +179,16 @@ impl PerformanceMonitor {
             protocol_engine: None,
             monitoring_handle: Arc::new(Mutex::new(None)),
             max_history_size,
+            smoothing_alpha: DEFAULT_SMOOTHING_ALPHA,
         }
     }
 
+    /// Set the EMA weight `optimize_performance` gives the newest sample (clamped to 0.01..=1.0)
+    pub fn with_smoothing_alpha(mut self, alpha: f32) -> Self {
+        self.smoothing_alpha = alpha.clamp(0.01, 1.0);
+        self
+    }
+
     /// Initialize with communication engines
     pub fn with_engines(
         mut self,
@@ -133,9 +206,15 @@ impl PerformanceMonitor {
 
     /// Start real-time performance monitoring
     pub async fn start_monitoring(&self) -> Result<(), PerformanceError> {
+        self.start_monitoring_until(CancellationToken::new()).await
+    }
+
+    /// Start real-time performance monitoring that stops when `cancel` is cancelled
+    pub async fn start_monitoring_until(&self, cancel: CancellationToken) -> Result<(), PerformanceError> {
         *self.optimization_active.lock().await = true;
 
         // Spawn monitoring task
+        let optimization_active = self.optimization_active.clone();
         let metrics_history = self.metrics_history.clone();
         let laser_engine = self.laser_engine.clone();
         let ultrasonic_engine = self.ultrasonic_engine.clone();
@@ -147,7 +226,13 @@ impl PerformanceMonitor {
             let mut interval = tokio::time::interval(Duration::from_millis(100)); // 10Hz monitoring
 
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = cancel.cancelled() => {
+                        *optimization_active.lock().await = false;
+                        break;
+                    }
+                }
 
                 let metrics = Self::collect_current_metrics(
                     &laser_engine,
@@ -294,6 +379,12 @@ impl PerformanceMonitor {
 
     /// Run comprehensive benchmark suite
     pub async fn run_benchmark_suite(&self, duration_secs: u64) -> Result<Vec<BenchmarkResult>, PerformanceError> {
+        self.run_benchmark_suite_cancellable(duration_secs, &CancellationToken::new()).await
+    }
+
+    /// Run the benchmark suite, abandoning it when `cancel` fires. A cancelled suite
+    /// returns `Cancelled` and stores none of its partial results.
+    pub async fn run_benchmark_suite_cancellable(&self, duration_secs: u64, cancel: &CancellationToken) -> Result<Vec<BenchmarkResult>, PerformanceError> {
         let mut results = Vec::new();
         let start_time = Instant::now();
 
@@ -309,7 +400,10 @@ impl PerformanceMonitor {
                 break;
             }
 
-            let result = self.benchmark_modulation_scheme(modulation, 10).await?;
+            let result = tokio::select! {
+                result = self.benchmark_modulation_scheme(modulation, 10) => result?,
+                _ = cancel.cancelled() => return Err(PerformanceError::Cancelled),
+            };
             results.push(result);
         }
 
@@ -326,12 +420,16 @@ impl PerformanceMonitor {
                 break;
             }
 
-            let result = self.benchmark_range_category(category, 5).await?;
+            let result = tokio::select! {
+                result = self.benchmark_range_category(category, 5) => result?,
+                _ = cancel.cancelled() => return Err(PerformanceError::Cancelled),
+            };
             results.push(result);
         }
 
-        // Store results
+        // Store results, superseding any simulated baseline
         let mut benchmark_results = self.benchmark_results.lock().await;
+        benchmark_results.retain(|result| !result.simulated);
         benchmark_results.extend(results.clone());
 
         Ok(results)
@@ -419,6 +517,7 @@ impl PerformanceMonitor {
             score,
             reliability_score,
             efficiency_score,
+            simulated: false,
         })
     }
 
@@ -448,9 +547,56 @@ impl PerformanceMonitor {
             score,
             reliability_score,
             efficiency_score,
+            simulated: false,
         })
     }
 
+    /// Seed baseline benchmarks from an analytic model so the optimizer has data before any
+    /// hardware sweep. Covers the same modulation and range sweeps as `run_benchmark_suite`,
+    /// which replaces these results once it runs.
+    pub async fn bootstrap_benchmarks(&self, model: &ChannelModel) -> Vec<BenchmarkResult> {
+        let mut results = Vec::new();
+
+        for modulation in [ModulationScheme::Ook, ModulationScheme::Pwm, ModulationScheme::QrProjection] {
+            let nominal = PerformanceConfig::default();
+            let metrics = model.predict(modulation.clone(), 100.0, nominal.target_throughput_bps, nominal.max_power_mw);
+            let config = PerformanceConfig {
+                target_throughput_bps: metrics.data_throughput_bps,
+                max_power_mw: metrics.power_consumption_mw,
+                min_reliability: 1.0 - metrics.bit_error_rate,
+                modulation_scheme: modulation,
+                ..nominal
+            };
+            results.push(self.simulated_result(config, metrics));
+        }
+
+        for category in [RangeDetectorCategory::Close, RangeDetectorCategory::Medium, RangeDetectorCategory::Far, RangeDetectorCategory::Extreme] {
+            let metrics = model.predict(category.optimal_modulation(), category.expected_range(), category.expected_throughput(), category.max_power());
+            let config = PerformanceConfig {
+                target_throughput_bps: category.expected_throughput(),
+                max_power_mw: category.max_power(),
+                min_reliability: 0.90,
+                modulation_scheme: category.optimal_modulation(),
+                ..PerformanceConfig::default()
+            };
+            results.push(self.simulated_result(config, metrics));
+        }
+
+        self.benchmark_results.lock().await.extend(results.clone());
+        results
+    }
+
+    fn simulated_result(&self, config: PerformanceConfig, metrics: PerformanceMetrics) -> BenchmarkResult {
+        BenchmarkResult {
+            score: self.calculate_performance_score(&metrics, &config),
+            reliability_score: 1.0 - metrics.bit_error_rate,
+            efficiency_score: metrics.data_throughput_bps / metrics.power_consumption_mw.max(f64::EPSILON),
+            config,
+            metrics,
+            simulated: true,
+        }
+    }
+
     /// Run range-specific benchmark
     async fn run_range_benchmark(&self, category: RangeDetectorCategory, duration_secs: u64) -> Result<PerformanceMetrics, PerformanceError> {
         let start_time = Instant::now();
@@ -531,7 +677,7 @@ impl PerformanceMonitor {
             packet_loss_rate: error_rate * 2.0,
             power_consumption_mw: avg_power.max(expected_power * 0.5), // Use measured or minimum expected
             range_meters: measured_range,
-            signal_strength: (1.0 - error_rate * 2.0).max(0.1), // Signal strength based on error rate
+            signal_strength: signal_for_error_rate(error_rate),
             modulation_scheme: category.optimal_modulation(),
             ecc_strength: 0.6 + (error_rate * 0.4), // Higher ECC for higher error rates
             environmental_conditions: EnvironmentalFactors::default(),
@@ -663,6 +809,33 @@ impl PerformanceMonitor {
         history.back().cloned()
     }
 
+    /// Exponential moving average of the recorded history, oldest first, so one noisy
+    /// sample moves the result by at most `alpha` of its deviation. Non-numeric fields
+    /// and the timestamp come from the latest sample.
+    pub async fn get_smoothed_metrics(&self, alpha: f32) -> Option<PerformanceMetrics> {
+        let alpha = alpha.clamp(0.01, 1.0) as f64;
+        let history = self.metrics_history.lock().await;
+        let mut samples = history.iter();
+        let mut smoothed = samples.next()?.clone();
+
+        for sample in samples {
+            let ema = |previous: f64, current: f64| alpha * current + (1.0 - alpha) * previous;
+            smoothed.handshake_latency_ms = ema(smoothed.handshake_latency_ms, sample.handshake_latency_ms);
+            smoothed.data_throughput_bps = ema(smoothed.data_throughput_bps, sample.data_throughput_bps);
+            smoothed.bit_error_rate = ema(smoothed.bit_error_rate, sample.bit_error_rate);
+            smoothed.packet_loss_rate = ema(smoothed.packet_loss_rate, sample.packet_loss_rate);
+            smoothed.power_consumption_mw = ema(smoothed.power_consumption_mw, sample.power_consumption_mw);
+            smoothed.range_meters = ema(smoothed.range_meters, sample.range_meters);
+            smoothed.signal_strength = ema(smoothed.signal_strength, sample.signal_strength);
+            smoothed.ecc_strength = ema(smoothed.ecc_strength, sample.ecc_strength);
+            smoothed.timestamp = sample.timestamp;
+            smoothed.modulation_scheme = sample.modulation_scheme.clone();
+            smoothed.environmental_conditions = sample.environmental_conditions.clone();
+        }
+
+        Some(smoothed)
+    }
+
     /// Get performance history
     pub async fn get_metrics_history(&self, count: usize) -> Vec<PerformanceMetrics> {
         let history = self.metrics_history.lock().await;
@@ -676,7 +849,8 @@ impl PerformanceMonitor {
 
     /// Optimize performance based on current conditions
     pub async fn optimize_performance(&self) -> Result<(), PerformanceError> {
-        let current_metrics = self.get_current_metrics().await
+        // Decide on smoothed values so a momentary spike does not trigger reconfiguration
+        let current_metrics = self.get_smoothed_metrics(self.smoothing_alpha).await
             .ok_or(PerformanceError::InvalidMetrics)?;
 
         let config = self.current_config.lock().await.clone();
@@ -777,6 +951,29 @@ impl PerformanceMonitor {
     }
 }
 
+/// Normalized signal strength implied by a measured error rate
+pub fn signal_for_error_rate(error_rate: f64) -> f64 {
+    (1.0 - error_rate * 2.0).max(MIN_USABLE_SIGNAL)
+}
+
+/// Expected error rate at a normalized signal strength; inverse of `signal_for_error_rate`
+pub fn error_rate_for_signal(signal_strength: f64) -> f64 {
+    (1.0 - signal_strength.clamp(MIN_USABLE_SIGNAL, 1.0)) / 2.0
+}
+
+/// Sustainable throughput of a channel with the given nominal rate, stepped down along the
+/// range-category throughput curve as signal weakens. Zero below the usable signal floor.
+pub fn adapted_throughput_bps(nominal_bps: f64, signal_strength: f64) -> f64 {
+    if signal_strength < MIN_USABLE_SIGNAL {
+        return 0.0;
+    }
+    let category = RangeDetectorCategory::for_signal_strength(signal_strength);
+    nominal_bps * category.expected_throughput() / RangeDetectorCategory::Close.expected_throughput()
+}
+
+/// Weakest signal at which a link still carries data
+const MIN_USABLE_SIGNAL: f64 = 0.1;
+
 impl Default for PerformanceConfig {
     fn default() -> Self {
         Self {
@@ -805,6 +1002,16 @@ impl Default for EnvironmentalFactors {
 }
 
 impl RangeDetectorCategory {
+    /// Range category whose link budget matches a normalized signal strength
+    fn for_signal_strength(signal_strength: f64) -> Self {
+        match signal_strength {
+            s if s >= 0.8 => RangeDetectorCategory::Close,
+            s if s >= 0.6 => RangeDetectorCategory::Medium,
+            s if s >= 0.4 => RangeDetectorCategory::Far,
+            _ => RangeDetectorCategory::Extreme,
+        }
+    }
+
     fn expected_throughput(&self) -> f64 {
         match self {
             RangeDetectorCategory::Close => 2_000_000.0,
@@ -859,9 +1066,61 @@ mod tests {
         assert_eq!(config.target_throughput_bps, 1_000_000.0);
     }
 
+    #[tokio::test]
+    async fn test_cancelled_benchmark_suite_stores_nothing() {
+        let monitor = PerformanceMonitor::new(100);
+        let cancel = CancellationToken::new();
+        let canceller = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            canceller.cancel();
+        });
+
+        let started = Instant::now();
+        let result = monitor.run_benchmark_suite_cancellable(60, &cancel).await;
+
+        assert!(matches!(result, Err(PerformanceError::Cancelled)));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(monitor.get_benchmark_results().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_benchmarks_feed_optimizer() {
+        let monitor = PerformanceMonitor::new(100);
+        monitor.bootstrap_benchmarks(&ChannelModel::default()).await;
+        assert!(!monitor.get_benchmark_results().await.is_empty());
+
+        let mid_range = ChannelModel::default().predict(ModulationScheme::Ook, 125.0, 1_000_000.0, 50.0);
+        let config = monitor.find_optimal_config(&mid_range).await.unwrap();
+        assert!(matches!(config.modulation_scheme, ModulationScheme::Pwm)); // Medium range's modulation
+        assert_eq!(config.target_throughput_bps, RangeDetectorCategory::Medium.expected_throughput());
+    }
+
     #[tokio::test]
     async fn test_range_category_methods() {
         assert_eq!(RangeDetectorCategory::Close.expected_throughput(), 2_000_000.0);
         assert_eq!(RangeDetectorCategory::Extreme.max_power(), 100.0);
     }
+
+    #[tokio::test]
+    async fn test_smoothed_metrics_damp_single_outlier() {
+        let monitor = PerformanceMonitor::new(100);
+        let base = ChannelModel::default().predict(ModulationScheme::Ook, 50.0, 1_000_000.0, 50.0);
+        for (i, jitter) in [0.0, 12.0, -8.0, 5.0, -10.0, 7.0, -3.0, 9.0, 0.0].into_iter().enumerate() {
+            let mut sample = base.clone();
+            sample.timestamp = i as u64;
+            sample.handshake_latency_ms = 200.0 + jitter;
+            monitor.record_metrics(sample).await;
+        }
+        let mut spike = base.clone();
+        spike.timestamp = 9;
+        spike.handshake_latency_ms = 2000.0;
+        monitor.record_metrics(spike).await;
+
+        let raw = monitor.get_current_metrics().await.unwrap();
+        let smoothed = monitor.get_smoothed_metrics(DEFAULT_SMOOTHING_ALPHA).await.unwrap();
+        assert!((smoothed.handshake_latency_ms - 200.0).abs() < (raw.handshake_latency_ms - 200.0).abs() / 2.0);
+        assert!(smoothed.handshake_latency_ms > 200.0);
+        assert_eq!(smoothed.timestamp, 9);
+    }
 }
\ No newline at end of file
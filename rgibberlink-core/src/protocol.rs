@@ -1,7 +1,7 @@
 use crate::audio::AudioEngine;
 use crate::crypto::CryptoEngine;
 use crate::visual::{VisualEngine, VisualPayload};
-use crate::ultrasonic_beam::{UltrasonicBeamEngine, UltrasonicBeamError};
+use crate::ultrasonic_beam::{TransmissionPriority, UltrasonicBeamEngine, UltrasonicBeamError};
 use crate::laser::{LaserEngine, LaserError, LaserConfig, ReceptionConfig};
 use crate::channel_validator::{ChannelValidator, ChannelData, ChannelType, ValidationError};
 use crate::fallback::{FallbackManager, FallbackConfig, FallbackStatus, ChannelHealth, ChannelFailure};
@@ -553,7 +553,7 @@ impl ProtocolEngine {
         // Send ACK via ultrasonic beam (coupled with laser validation)
         if let Some(ultrasonic) = &self.ultrasonic_beam {
             let ack_data = b"LONG_RANGE_ACK";
-            ultrasonic.transmit_control_data(ack_data, 1).await
+            ultrasonic.transmit_control_data(ack_data, TransmissionPriority::Ack).await
                 .map_err(|e| ProtocolError::UltrasonicBeamError(e))?;
         }
 
@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 use pyo3::exceptions::PyRuntimeError;
+use pyo3::types::PyDict;
 use std::collections::HashMap;
 use crate::crypto::{CryptoEngine, CryptoError};
 use crate::visual::{VisualEngine, VisualError, VisualPayload};
@@ -348,6 +349,19 @@ impl PyWeatherManager {
             Ok(PyValidationResult { inner: result })
         })
     }
+
+    /// Launch decision as a dict: `decision` ("go", "hold" or "no_go"), `reasons`
+    /// and `retry_after` in seconds (None for a go)
+    fn go_no_go(&self, py: Python, mission: &PyMissionPayload, drone_specs: &PyDroneSpecifications) -> PyResult<PyObject> {
+        let decision = py.allow_threads(|| self.inner.go_no_go(&mission.inner, &drone_specs.inner))
+            .map_err(|e| PyRuntimeError::new_err(format!("Go/no-go error: {}", e)))?;
+
+        let result = PyDict::new(py);
+        result.set_item("decision", decision.verdict.as_str())?;
+        result.set_item("reasons", decision.reasons)?;
+        result.set_item("retry_after", decision.retry_after.map(|delay| delay.as_secs_f64()))?;
+        Ok(result.to_object(py))
+    }
 }
 
 /// Python wrapper for WeatherData
@@ -459,6 +473,16 @@ impl PyWindImpact {
     fn abort_threshold_exceeded(&self) -> bool {
         self.inner.abort_threshold_exceeded
     }
+
+    #[getter]
+    fn turbulence_intensity(&self) -> f32 {
+        self.inner.turbulence_intensity
+    }
+
+    #[getter]
+    fn payload_swing_m(&self) -> Option<f32> {
+        self.inner.payload_swing_m
+    }
 }
 
 /// Python wrapper for ConstraintValidationResult
@@ -572,6 +596,14 @@ impl PyMissionPayload {
     fn tasks(&self) -> Vec<PyMissionTask> {
         self.inner.tasks.iter().map(|t| PyMissionTask { inner: t.clone() }).collect()
     }
+
+    /// Structural validation issues; an empty list means the mission is valid
+    fn validate(&self) -> Vec<String> {
+        match self.inner.validate() {
+            Ok(()) => Vec::new(),
+            Err(issues) => issues.iter().map(|issue| issue.to_string()).collect(),
+        }
+    }
 }
 
 /// Python wrapper for MissionHeader
@@ -592,6 +624,11 @@ impl PyMissionHeader {
     fn priority(&self) -> String {
         format!("{:?}", self.inner.priority)
     }
+
+    #[getter]
+    fn revision(&self) -> u32 {
+        self.inner.revision
+    }
 }
 
 /// Python wrapper for MissionTask
@@ -634,6 +671,7 @@ impl PyDroneSpecifications {
                 mass_kg,
                 battery_capacity_wh,
                 sensor_types: vec!["sensor".to_string(); sensor_count], // Placeholder
+                battery_derating: crate::weather::BatteryDeratingCurve::default(),
             },
         }
     }
@@ -4,6 +4,8 @@ use tokio::sync::Mutex;
 use serde::{Serialize, Deserialize};
 use crate::crypto::{CryptoEngine, CryptoError};
 use crate::channel_validator::{ChannelValidator, ChannelData, ChannelType, ValidationError};
+use crate::audit::{AuditSystem, AuditEventType, AuditSeverity, AuditActor, AuditOperation, create_audit_entry};
+use crate::audit::events::{OperationResult, OperationContext, ResourceConsumption, PerformanceMetrics, AuditContext};
 use aes_gcm::KeyInit;
 use hmac::Mac;
 
@@ -12,6 +14,7 @@ use hmac::Mac;
 pub struct SecurityManager {
     config: SecurityConfig,
     state: Arc<Mutex<SecurityState>>,
+    audit: Option<Arc<Mutex<AuditSystem>>>, // Receives authorization decisions when attached
 }
 
 /// Cryptographic algorithm configuration for agility
@@ -430,9 +433,15 @@ impl SecurityManager {
         Self {
             config,
             state: Arc::new(Mutex::new(state)),
+            audit: None,
         }
     }
 
+    /// Record every grant, denial and lockout in the given audit system
+    pub fn attach_audit_system(&mut self, audit: Arc<Mutex<AuditSystem>>) {
+        self.audit = Some(audit);
+    }
+
     /// Check if PIN change is required
     pub async fn pin_change_required(&self) -> bool {
         self.state.lock().await.pin_change_required
@@ -440,6 +449,19 @@ impl SecurityManager {
 
     /// Validate PIN
     pub async fn validate_pin(&self, pin: &str) -> Result<(), SecurityError> {
+        let result = self.verify_pin(pin).await;
+
+        if let Err(SecurityError::AccountLocked) = &result {
+            let parameters = HashMap::from([
+                ("lockout_duration_secs".to_string(), serde_json::json!(self.config.lockout_duration_secs)),
+            ]);
+            self.audit_authorization("pin_lockout", parameters, Self::system_actor(), AuditSeverity::High, Some("Account locked")).await;
+        }
+
+        result
+    }
+
+    async fn verify_pin(&self, pin: &str) -> Result<(), SecurityError> {
         let mut state = self.state.lock().await;
 
         // Check lockout
@@ -495,13 +517,23 @@ impl SecurityManager {
 
     /// Check permission for operation
     pub async fn check_permission(&self, permission: PermissionType, scope: PermissionScope) -> Result<(), SecurityError> {
-        let state = self.state.lock().await;
+        let result = self.evaluate_permission(&permission, &scope).await;
+
+        let error = result.as_ref().err().map(|e| e.to_string());
+        let severity = if result.is_ok() { AuditSeverity::Informational } else { AuditSeverity::Medium };
+        self.audit_authorization("check_permission", Self::permission_parameters(&permission, &scope), Self::system_actor(), severity, error.as_deref()).await;
+
+        result
+    }
 
-        // Rate limiting check
+    async fn evaluate_permission(&self, permission: &PermissionType, scope: &PermissionScope) -> Result<(), SecurityError> {
+        // Rate limiting check (before taking the state lock it also needs)
         if self.is_rate_limited().await {
             return Err(SecurityError::RateLimitExceeded);
         }
 
+        let state = self.state.lock().await;
+
         match self.config.security_level {
             SecurityLevel::Minimum => {
                 // Only allow discussions
@@ -528,6 +560,15 @@ impl SecurityManager {
         Ok(())
     }
 
+    /// Check whether an unexpired grant exists for the permission and scope
+    pub async fn has_permission(&self, permission: &PermissionType, scope: &PermissionScope) -> bool {
+        let state = self.state.lock().await;
+        let key = format!("{:?}_{:?}", permission, scope);
+        state.active_permissions.get(&key)
+            .map(|grant| grant.expires_at.map_or(true, |expires| std::time::SystemTime::now() < expires))
+            .unwrap_or(false)
+    }
+
     /// Grant permission
     pub async fn grant_permission(&self, permission: PermissionType, scope: PermissionScope, granted_by: &str) -> Result<(), SecurityError> {
         let mut state = self.state.lock().await;
@@ -542,10 +583,79 @@ impl SecurityManager {
 
         let key = format!("{:?}_{:?}", permission, scope);
         state.active_permissions.insert(key, grant);
+        drop(state);
+
+        let actor = AuditActor::HumanOperator {
+            operator_id: granted_by.to_string(),
+            clearance_level: "unknown".to_string(),
+            department: None,
+        };
+        self.audit_authorization("grant_permission", Self::permission_parameters(&permission, &scope), actor, AuditSeverity::Informational, None).await;
 
         Ok(())
     }
 
+    /// Emit an AuthorizationCheck audit entry if an audit system is attached
+    async fn audit_authorization(
+        &self,
+        operation_name: &str,
+        parameters: HashMap<String, serde_json::Value>,
+        actor: AuditActor,
+        severity: AuditSeverity,
+        error: Option<&str>,
+    ) {
+        let audit = match &self.audit {
+            Some(audit) => audit,
+            None => return,
+        };
+
+        let mut entry = create_audit_entry(
+            AuditEventType::AuthorizationCheck,
+            severity,
+            actor,
+            AuditOperation {
+                operation_type: "authorization".to_string(),
+                operation_name: operation_name.to_string(),
+                parameters,
+                execution_context: OperationContext {
+                    security_level: format!("{:?}", self.config.security_level),
+                    ..Default::default()
+                },
+                expected_duration: None,
+                resource_consumption: ResourceConsumption::default(),
+            },
+            OperationResult {
+                success: error.is_none(),
+                error_code: error.map(|_| operation_name.to_string()),
+                error_message: error.map(|e| e.to_string()),
+                duration_ms: 0,
+                performance_metrics: PerformanceMetrics::default(),
+                side_effects: Vec::new(),
+            },
+            AuditContext::default(),
+        );
+        entry.security_metadata.authentication_method = "security_manager".to_string();
+        entry.security_metadata.authorization_checks.push(operation_name.to_string());
+
+        // Audit failures must not change the authorization outcome
+        let _ = audit.lock().await.record_event(entry);
+    }
+
+    fn permission_parameters(permission: &PermissionType, scope: &PermissionScope) -> HashMap<String, serde_json::Value> {
+        HashMap::from([
+            ("permission".to_string(), serde_json::json!(format!("{:?}", permission))),
+            ("scope".to_string(), serde_json::json!(format!("{:?}", scope))),
+        ])
+    }
+
+    fn system_actor() -> AuditActor {
+        AuditActor::System {
+            component: "security_manager".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            subsystem: "authorization".to_string(),
+        }
+    }
+
     /// Register peer identity
     pub async fn register_peer(&self, peer_id: &str, initial_trust: TrustLevel) -> Result<(), SecurityError> {
         let mut peer = PeerIdentity::from_string(peer_id)?;
@@ -1098,6 +1208,32 @@ mod tests {
         assert!(manager.grant_permission(PermissionType::Command, PermissionScope::Single, "test_user").await.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_denied_permission_is_audited() {
+        use crate::audit::AuditQuery;
+
+        let audit = Arc::new(Mutex::new(AuditSystem::new(100)));
+        let mut manager = SecurityManager::new(SecurityConfig::default());
+        manager.attach_audit_system(audit.clone());
+
+        assert!(manager.check_permission(PermissionType::Command, PermissionScope::Single).await.is_err());
+
+        let entries = audit.lock().await.query_audit(AuditQuery {
+            start_time: None,
+            end_time: None,
+            event_types: vec![AuditEventType::AuthorizationCheck],
+            min_severity: None,
+            actor_filter: None,
+            compliance_flags: Vec::new(),
+            limit: None,
+        });
+
+        assert_eq!(entries.len(), 1);
+        assert!(!entries[0].result.success);
+        assert_eq!(entries[0].operation.parameters["permission"], serde_json::json!("Command"));
+        assert_eq!(entries[0].operation.parameters["scope"], serde_json::json!("Single"));
+    }
+
     #[tokio::test]
     async fn test_peer_management() {
         let config = SecurityConfig::default();
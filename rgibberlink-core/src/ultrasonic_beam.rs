@@ -1,4 +1,6 @@
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use std::collections::VecDeque;
 use reed_solomon_erasure::galois_8::ReedSolomon;
@@ -80,6 +82,38 @@ pub struct BeamReception {
     pub data: Vec<u8>,
 }
 
+/// Nominal control-channel bit rate, used to time frames on the shared acoustic medium
+pub const CONTROL_CHANNEL_BPS: u32 = 16_000;
+
+/// Transmitted frames kept for diagnostics
+const TRANSMIT_HISTORY_LIMIT: usize = 64;
+
+/// Priority of a frame on the shared acoustic channel; higher variants transmit first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TransmissionPriority {
+    Telemetry,
+    Ack,
+    ControlBinding,
+}
+
+/// Frame waiting for the acoustic channel
+#[derive(Debug, Clone)]
+struct QueuedFrame {
+    sequence: u64,
+    priority: TransmissionPriority,
+    data: Vec<u8>,
+}
+
+/// Frame that went out on the acoustic channel, with its time on air
+#[derive(Debug, Clone)]
+pub struct TransmittedFrame {
+    pub sequence: u64,
+    pub priority: TransmissionPriority,
+    pub len: usize,
+    pub started: Instant,
+    pub finished: Instant,
+}
+
 /// Comprehensive ultrasonic channel diagnostics
 #[derive(Debug, Clone)]
 pub struct UltrasonicChannelDiagnostics {
@@ -96,6 +130,9 @@ pub struct UltrasonicBeamEngine {
     config: BeamConfig,
     is_active: bool,
     reception_buffer: Arc<Mutex<VecDeque<BeamReception>>>,
+    transmit_queue: Arc<Mutex<Vec<QueuedFrame>>>,
+    channel: Arc<Mutex<VecDeque<TransmittedFrame>>>, // Held while a frame is on air
+    next_sequence: AtomicU64,
     // Placeholder for Android JNI integration
     // jni_interface: Option<JNIInterface>,
 }
@@ -107,6 +144,9 @@ impl UltrasonicBeamEngine {
             config: BeamConfig::default(),
             is_active: false,
             reception_buffer: Arc::new(Mutex::new(VecDeque::new())),
+            transmit_queue: Arc::new(Mutex::new(Vec::new())),
+            channel: Arc::new(Mutex::new(VecDeque::new())),
+            next_sequence: AtomicU64::new(0),
         }
     }
 
@@ -139,8 +179,7 @@ impl UltrasonicBeamEngine {
 
         Ok(Self {
             config,
-            is_active: false,
-            reception_buffer: Arc::new(Mutex::new(VecDeque::new())),
+            ..Self::new()
         })
     }
 
@@ -255,8 +294,15 @@ impl UltrasonicBeamEngine {
         Ok(())
     }
 
-    /// Transmit control data via low-bandwidth channel
-    pub async fn transmit_control_data(&self, data: &[u8], priority: u8) -> Result<(), UltrasonicBeamError> {
+    /// Transmit control data via low-bandwidth channel, queued behind any higher-priority frames
+    pub async fn transmit_control_data(&self, data: &[u8], priority: TransmissionPriority) -> Result<(), UltrasonicBeamError> {
+        self.enqueue_frame(data, priority).await?;
+        self.flush_transmit_queue().await?;
+        Ok(())
+    }
+
+    /// Queue a control frame for the shared acoustic channel; returns its sequence number
+    pub async fn enqueue_frame(&self, data: &[u8], priority: TransmissionPriority) -> Result<u64, UltrasonicBeamError> {
         if !self.is_active {
             return Err(UltrasonicBeamError::HardwareUnavailable);
         }
@@ -267,13 +313,70 @@ impl UltrasonicBeamEngine {
             ));
         }
 
+        let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+        self.transmit_queue.lock().await.push(QueuedFrame { sequence, priority, data: data.to_vec() });
+        Ok(sequence)
+    }
+
+    /// Frames waiting for the channel
+    pub async fn queue_depth(&self) -> usize {
+        self.transmit_queue.lock().await.len()
+    }
+
+    /// Transmit queued frames one at a time, highest priority first and FIFO within a
+    /// priority. Concurrent callers wait for the channel, so frames never overlap on air.
+    /// Returns the number of frames this call transmitted.
+    pub async fn flush_transmit_queue(&self) -> Result<usize, UltrasonicBeamError> {
+        let mut history = self.channel.lock().await;
+        let mut transmitted = 0;
+
+        loop {
+            // Re-select after every frame so urgent frames queued meanwhile go next
+            let frame = {
+                let mut queue = self.transmit_queue.lock().await;
+                let next = queue.iter().enumerate()
+                    .max_by(|(_, a), (_, b)| a.priority.cmp(&b.priority).then(b.sequence.cmp(&a.sequence)))
+                    .map(|(index, _)| index);
+                match next {
+                    Some(index) => queue.remove(index),
+                    None => break,
+                }
+            };
+
+            let started = Instant::now();
+            self.transmit_frame(&frame).await?;
+            history.push_back(TransmittedFrame {
+                sequence: frame.sequence,
+                priority: frame.priority,
+                len: frame.data.len(),
+                started,
+                finished: Instant::now(),
+            });
+            if history.len() > TRANSMIT_HISTORY_LIMIT {
+                history.pop_front();
+            }
+            transmitted += 1;
+        }
+
+        Ok(transmitted)
+    }
+
+    /// Most recent transmitted frames, oldest first
+    pub async fn transmit_history(&self) -> Vec<TransmittedFrame> {
+        self.channel.lock().await.iter().cloned().collect()
+    }
+
+    /// Put one frame on air and wait until it has been fully sent
+    async fn transmit_frame(&self, frame: &QueuedFrame) -> Result<(), UltrasonicBeamError> {
         let _control_signal = BeamSignal::ControlData {
-            data: data.to_vec(),
-            priority,
+            data: frame.data.clone(),
+            priority: frame.priority as u8,
         };
 
         // Transmit with error correction for reliability
         // TODO: JNI implementation with forward error correction
+        let airtime = Duration::from_secs_f64((frame.data.len() * 8) as f64 / CONTROL_CHANNEL_BPS as f64);
+        tokio::time::sleep(airtime).await;
 
         Ok(())
     }
@@ -415,7 +518,34 @@ mod tests {
         engine.is_active = true; // Simulate initialization
         let large_data = vec![0u8; 64]; // Exceeds limit
 
-        let result = engine.transmit_control_data(&large_data, 1).await;
+        let result = engine.transmit_control_data(&large_data, TransmissionPriority::Ack).await;
         assert!(matches!(result, Err(UltrasonicBeamError::InvalidParameters(_))));
     }
+
+    #[tokio::test]
+    async fn test_queued_frames_transmit_in_priority_order_without_overlap() {
+        let mut engine = UltrasonicBeamEngine::new();
+        engine.is_active = true; // Simulate initialization
+
+        for (priority, byte) in [
+            (TransmissionPriority::Telemetry, 1u8),
+            (TransmissionPriority::Ack, 2),
+            (TransmissionPriority::ControlBinding, 3),
+            (TransmissionPriority::Telemetry, 4),
+            (TransmissionPriority::ControlBinding, 5),
+        ] {
+            engine.enqueue_frame(&[byte; 16], priority).await.unwrap();
+        }
+        assert_eq!(engine.queue_depth().await, 5);
+
+        // Two subsystems flushing at once still share the channel one frame at a time
+        let (first, second) = tokio::join!(engine.flush_transmit_queue(), engine.flush_transmit_queue());
+        assert_eq!(first.unwrap() + second.unwrap(), 5);
+        assert_eq!(engine.queue_depth().await, 0);
+
+        let history = engine.transmit_history().await;
+        let order: Vec<u64> = history.iter().map(|frame| frame.sequence).collect();
+        assert_eq!(order, vec![2, 4, 1, 0, 3]);
+        assert!(history.windows(2).all(|pair| pair[1].started >= pair[0].finished));
+    }
 }
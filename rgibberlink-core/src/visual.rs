@@ -1,4 +1,4 @@
-use qrcode::QrCode;
+use qrcode::{EcLevel, QrCode, Version};
 use reed_solomon_erasure::galois_8::ReedSolomon;
 use serde::{Deserialize, Serialize};
 use serde_cbor;
@@ -19,6 +19,95 @@ pub enum VisualError {
     InvalidCompensationState,
 }
 
+/// QR error-correction level (L recovers ~7% of modules, M ~15%, Q ~25%, H ~30%)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QrEccLevel {
+    L,
+    M,
+    Q,
+    H,
+}
+
+impl QrEccLevel {
+    fn ec_level(self) -> EcLevel {
+        match self {
+            QrEccLevel::L => EcLevel::L,
+            QrEccLevel::M => EcLevel::M,
+            QrEccLevel::Q => EcLevel::Q,
+            QrEccLevel::H => EcLevel::H,
+        }
+    }
+
+    fn from_ec_level(level: EcLevel) -> Self {
+        match level {
+            EcLevel::L => QrEccLevel::L,
+            EcLevel::M => QrEccLevel::M,
+            EcLevel::Q => QrEccLevel::Q,
+            EcLevel::H => QrEccLevel::H,
+        }
+    }
+
+    /// Byte-mode capacity of a version 40 symbol at this level
+    pub fn max_data_bytes(self) -> usize {
+        match self {
+            QrEccLevel::L => 2953,
+            QrEccLevel::M => 2331,
+            QrEccLevel::Q => 1663,
+            QrEccLevel::H => 1273,
+        }
+    }
+
+    /// Next stronger level (H stays H)
+    pub fn stronger(self) -> Self {
+        match self {
+            QrEccLevel::L => QrEccLevel::M,
+            QrEccLevel::M => QrEccLevel::Q,
+            QrEccLevel::Q | QrEccLevel::H => QrEccLevel::H,
+        }
+    }
+
+    /// Pick a level for the scan distance, stepping up once for poor visibility or precipitation
+    pub fn select(range_m: f32, conditions: &crate::performance_monitor::EnvironmentalFactors) -> Self {
+        use crate::security::WeatherCondition;
+
+        let level = if range_m < 1.0 {
+            QrEccLevel::L
+        } else if range_m < 3.0 {
+            QrEccLevel::M
+        } else if range_m < 6.0 {
+            QrEccLevel::Q
+        } else {
+            QrEccLevel::H
+        };
+
+        let degraded = conditions.visibility_meters < 1000.0
+            || !matches!(conditions.weather, WeatherCondition::Clear | WeatherCondition::Cloudy);
+        if degraded { level.stronger() } else { level }
+    }
+}
+
+/// Rendered QR code together with the symbol parameters that were used
+#[derive(Debug, Clone)]
+pub struct QrEncoding {
+    pub svg: String,
+    pub ecc_level: QrEccLevel,
+    pub version: i16,
+    pub physical_size_mm: Option<f32>, // Printed/displayed edge length including quiet zone, when requested
+}
+
+/// Scanning camera optics used to size QR modules for a given range
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraSpec {
+    pub horizontal_resolution_px: u32,
+    pub horizontal_fov_degrees: f32,
+}
+
+/// Camera pixels each QR module must cover for reliable decoding
+pub const MIN_PIXELS_PER_MODULE: f32 = 3.0;
+
+/// Modules of blank margin the renderer adds on each side of the symbol
+const QR_QUIET_ZONE_MODULES: usize = 4;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisualPayload {
     pub session_id: [u8; 16],
@@ -113,12 +202,96 @@ impl VisualEngine {
     }
 
     pub fn encode_payload(&self, payload: &VisualPayload) -> Result<String, VisualError> {
-        // Serialize to CBOR
+        self.encode_payload_with_ecc(payload, QrEccLevel::M).map(|encoding| encoding.svg)
+    }
+
+    /// Encode with an explicit QR error-correction level; decoding auto-detects the level
+    pub fn encode_payload_with_ecc(&self, payload: &VisualPayload, ecc_level: QrEccLevel) -> Result<QrEncoding, VisualError> {
+        let encoded_data = self.shard_payload(payload)?;
+
+        if encoded_data.len() > ecc_level.max_data_bytes() {
+            return Err(VisualError::DataTooLarge);
+        }
+
+        let code = QrCode::with_error_correction_level(&encoded_data, ecc_level.ec_level())
+            .map_err(|_| VisualError::QrCodeError)?;
+        let version = match code.version() {
+            Version::Normal(v) | Version::Micro(v) => v,
+        };
+        let svg = code.render::<qrcode::render::svg::Color>().build();
+
+        Ok(QrEncoding {
+            svg,
+            ecc_level: QrEccLevel::from_ec_level(code.error_correction_level()),
+            version,
+            physical_size_mm: None,
+        })
+    }
+
+    /// Smallest module edge (mm) the camera resolves with `MIN_PIXELS_PER_MODULE` pixels at `range_m`
+    pub fn recommended_module_size_mm(&self, range_m: f32, camera: CameraSpec) -> f32 {
+        let half_fov = (camera.horizontal_fov_degrees.clamp(1.0, 179.0) / 2.0).to_radians();
+        let field_width_mm = 2.0 * range_m.max(0.0) * half_fov.tan() * 1000.0;
+        let mm_per_pixel = field_width_mm / camera.horizontal_resolution_px.max(1) as f32;
+        mm_per_pixel * MIN_PIXELS_PER_MODULE
+    }
+
+    /// Encode like `encode_payload_with_ecc`, with the SVG sized to `module_size_mm` per module
+    pub fn encode_payload_at_size(&self, payload: &VisualPayload, ecc_level: QrEccLevel, module_size_mm: f32) -> Result<QrEncoding, VisualError> {
+        let encoded_data = self.shard_payload(payload)?;
+        if encoded_data.len() > ecc_level.max_data_bytes() {
+            return Err(VisualError::DataTooLarge);
+        }
+
+        let code = QrCode::with_error_correction_level(&encoded_data, ecc_level.ec_level())
+            .map_err(|_| VisualError::QrCodeError)?;
+        let version = match code.version() {
+            Version::Normal(v) | Version::Micro(v) => v,
+        };
+
+        // One SVG unit per module, then scale the document to physical units
+        let modules = code.width() + 2 * QR_QUIET_ZONE_MODULES;
+        let physical_size_mm = modules as f32 * module_size_mm;
+        let svg = code.render::<qrcode::render::svg::Color>().module_dimensions(1, 1).build();
+        let mut sized = format!(r#"width="{:.2}mm" height="{:.2}mm""#, physical_size_mm, physical_size_mm);
+        if !svg.contains("viewBox") {
+            sized.push_str(&format!(r#" viewBox="0 0 {} {}""#, modules, modules));
+        }
+        let svg = svg.replacen(&format!(r#"width="{}" height="{}""#, modules, modules), &sized, 1);
+
+        Ok(QrEncoding {
+            svg,
+            ecc_level: QrEccLevel::from_ec_level(code.error_correction_level()),
+            version,
+            physical_size_mm: Some(physical_size_mm),
+        })
+    }
+
+    /// Maximum byte-mode payload for a symbol of the given version (module size) and level
+    pub fn qr_capacity_bytes(version: i16, ecc_level: QrEccLevel) -> usize {
+        let fits = |len: usize| QrCode::with_version(vec![0u8; len], Version::Normal(version), ecc_level.ec_level()).is_ok();
+
+        let (mut low, mut high) = (0usize, ecc_level.max_data_bytes() + 1);
+        while low + 1 < high {
+            let mid = (low + high) / 2;
+            if fits(mid) { low = mid } else { high = mid }
+        }
+        low
+    }
+
+    /// CBOR-serialize and Reed-Solomon shard a payload into the bytes carried by the QR symbol
+    pub fn shard_payload(&self, payload: &VisualPayload) -> Result<Vec<u8>, VisualError> {
         let cbor_data = serde_cbor::to_vec(payload).map_err(|_| VisualError::CborError)?;
+        self.shard_bytes(&cbor_data)
+    }
+
+    /// Reed-Solomon shard arbitrary bytes (at most 64 KiB) into the bytes carried by the QR symbol
+    pub fn shard_bytes(&self, data: &[u8]) -> Result<Vec<u8>, VisualError> {
+        let data_len = u16::try_from(data.len()).map_err(|_| VisualError::DataTooLarge)?;
 
         // Compress data (simple length-prefixed for prototype)
-        let mut compressed = (cbor_data.len() as u16).to_le_bytes().to_vec();
-        compressed.extend(cbor_data);
+        let mut compressed = data_len.to_le_bytes().to_vec();
+        compressed.extend_from_slice(data);
 
         // Split into shards
         let shard_size = (compressed.len() + 7) / 8; // Ceiling division
@@ -143,18 +316,20 @@ impl VisualEngine {
             encoded_data.extend(shard);
         }
 
-        // Generate QR code
-        if encoded_data.len() > 2953 { // Max data for QR version 40
-            return Err(VisualError::DataTooLarge);
-        }
+        Ok(encoded_data)
+    }
 
-        let code = QrCode::new(&encoded_data).map_err(|_| VisualError::QrCodeError)?;
-        let svg = code.render::<qrcode::render::svg::Color>().build();
+    pub fn decode_payload(&self, qr_data: &[u8]) -> Result<VisualPayload, VisualError> {
+        let cbor_data = self.reassemble_bytes(qr_data)?;
 
-        Ok(svg)
+        // Deserialize from CBOR
+        let payload: VisualPayload = serde_cbor::from_slice(&cbor_data).map_err(|_| VisualError::CborError)?;
+
+        Ok(payload)
     }
 
-    pub fn decode_payload(&self, qr_data: &[u8]) -> Result<VisualPayload, VisualError> {
+    /// Recover the bytes given to `shard_bytes` from the scanned QR data
+    pub fn reassemble_bytes(&self, qr_data: &[u8]) -> Result<Vec<u8>, VisualError> {
         // Parse QR data (simplified - assume raw bytes)
         let total_size = qr_data.len();
         let shard_size = (total_size + 11) / 12; // Assuming 12 shards
@@ -162,7 +337,7 @@ impl VisualEngine {
         let mut shards: Vec<Option<Vec<u8>>> = Vec::with_capacity(12);
 
         for i in 0..12 {
-            let start = i * shard_size;
+            let start = std::cmp::min(i * shard_size, total_size);
             let end = std::cmp::min(start + shard_size, total_size);
             shards.push(Some(qr_data[start..end].to_vec()));
         }
@@ -184,12 +359,8 @@ impl VisualEngine {
         if reconstructed.len() < 2 + data_len {
             return Err(VisualError::CborError);
         }
-        let cbor_data = &reconstructed[2..2 + data_len];
-
-        // Deserialize from CBOR
-        let payload: VisualPayload = serde_cbor::from_slice(cbor_data).map_err(|_| VisualError::CborError)?;
-
-        Ok(payload)
+        reconstructed.truncate(2 + data_len);
+        Ok(reconstructed.split_off(2))
     }
 
     /// Encode compensation frame with enhanced layout for noisy environments
@@ -306,3 +477,57 @@ impl VisualEngine {
         Err(VisualError::ReedSolomonError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_ecc_level_reduces_capacity() {
+        let version = 10;
+        let low = VisualEngine::qr_capacity_bytes(version, QrEccLevel::L);
+        let medium = VisualEngine::qr_capacity_bytes(version, QrEccLevel::M);
+        let high = VisualEngine::qr_capacity_bytes(version, QrEccLevel::H);
+
+        assert!(low > medium);
+        assert!(medium > high);
+    }
+
+    #[test]
+    fn test_encode_with_ecc_reports_symbol_parameters() {
+        let engine = VisualEngine::new();
+        let payload = VisualPayload {
+            session_id: [1u8; 16],
+            public_key: vec![2u8; 32],
+            nonce: [3u8; 16],
+            signature: vec![4u8; 64],
+        };
+
+        let medium = engine.encode_payload_with_ecc(&payload, QrEccLevel::M).unwrap();
+        let high = engine.encode_payload_with_ecc(&payload, QrEccLevel::H).unwrap();
+
+        assert_eq!(high.ecc_level, QrEccLevel::H);
+        assert!(high.version >= medium.version);
+    }
+
+    #[test]
+    fn test_longer_range_needs_larger_modules() {
+        let engine = VisualEngine::new();
+        let camera = CameraSpec { horizontal_resolution_px: 1920, horizontal_fov_degrees: 60.0 };
+
+        let near = engine.recommended_module_size_mm(0.5, camera);
+        let far = engine.recommended_module_size_mm(3.0, camera);
+        assert!(far > near);
+        assert!((far / near - 6.0).abs() < 1e-3);
+
+        let payload = VisualPayload {
+            session_id: [1u8; 16],
+            public_key: vec![2u8; 32],
+            nonce: [3u8; 16],
+            signature: vec![4u8; 64],
+        };
+        let sized = engine.encode_payload_at_size(&payload, QrEccLevel::M, far).unwrap();
+        let physical = sized.physical_size_mm.unwrap();
+        assert!(sized.svg.contains(&format!("{:.2}mm", physical)));
+    }
+}
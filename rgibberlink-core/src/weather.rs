@@ -7,7 +7,7 @@
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use super::mission::*;
 use super::security::{WeatherCondition, TimeOfDay};
 
@@ -68,6 +68,8 @@ pub struct WindImpact {
     pub speed_cap_mps: Option<f32>,
     pub heading_correction_needed: bool,
     pub abort_threshold_exceeded: bool,
+    pub turbulence_intensity: f32, // Gust fluctuation relative to mean wind
+    pub payload_swing_m: Option<f32>, // Slung-payload excursion, formation missions only
 }
 
 /// Precipitation effects on sensors and electrical systems
@@ -132,6 +134,83 @@ pub struct ConstraintValidationResult {
     pub risk_assessment: RiskAssessment,
 }
 
+/// Usable battery capacity multiplier as a function of cell temperature
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatteryDeratingCurve {
+    pub points: Vec<(f32, f32)>, // (temperature °C, capacity multiplier), sorted by temperature
+}
+
+impl Default for BatteryDeratingCurve {
+    fn default() -> Self {
+        // Typical Li-ion/LiPo behaviour: steep loss below freezing, mild loss when hot
+        Self {
+            points: vec![
+                (-20.0, 0.50),
+                (-10.0, 0.65),
+                (0.0, 0.80),
+                (10.0, 0.92),
+                (20.0, 1.00),
+                (35.0, 1.00),
+                (45.0, 0.90),
+            ],
+        }
+    }
+}
+
+impl BatteryDeratingCurve {
+    /// Capacity multiplier at the given temperature, linearly interpolated and clamped at the ends
+    pub fn capacity_multiplier(&self, temperature_c: f32) -> f32 {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return 1.0,
+        };
+        if temperature_c <= first.0 {
+            return first.1;
+        }
+        if temperature_c >= last.0 {
+            return last.1;
+        }
+
+        for pair in self.points.windows(2) {
+            let ((t0, m0), (t1, m1)) = (pair[0], pair[1]);
+            if temperature_c >= t0 && temperature_c <= t1 {
+                if t1 <= t0 {
+                    return m1;
+                }
+                return m0 + (m1 - m0) * (temperature_c - t0) / (t1 - t0);
+            }
+        }
+        last.1
+    }
+}
+
+/// Launch decision verdict
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum GoNoGoVerdict {
+    Go,
+    Hold, // Marginal conditions, re-evaluate after `retry_after`
+    NoGo,
+}
+
+impl GoNoGoVerdict {
+    /// Stable identifier for bindings and logs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoNoGoVerdict::Go => "go",
+            GoNoGoVerdict::Hold => "hold",
+            GoNoGoVerdict::NoGo => "no_go",
+        }
+    }
+}
+
+/// Launch decision with supporting reasons
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoNoGoDecision {
+    pub verdict: GoNoGoVerdict,
+    pub reasons: Vec<String>,
+    pub retry_after: Option<Duration>,
+}
+
 /// Individual constraint violation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConstraintViolation {
@@ -190,7 +269,7 @@ pub struct RiskAssessment {
 }
 
 /// Risk levels for mission assessment
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
 pub enum RiskLevel {
     Low,
     Moderate,
@@ -214,6 +293,11 @@ pub struct WeatherManager {
     max_history_entries: usize,
     api_keys: HashMap<String, String>,
     local_sensor_interface: Option<LocalSensorInterface>,
+    fusion_policy: WeatherFusionPolicy,
+    source_readings: Vec<WeatherData>, // Latest reading per source
+    risk_compounding: RiskCompoundingPolicy,
+    turbulence_model: TurbulenceModel,
+    forecast: Vec<WeatherData>, // Forecast series, sorted by timestamp
 }
 
 /// Configuration for weather data sources
@@ -236,6 +320,223 @@ pub struct LocalSensorInterface {
     pub visibility_sensor: bool,
 }
 
+/// Field groups that prefer different weather sources when readings are fused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeatherFieldClass {
+    /// Measured now: temperature, humidity, pressure, wind, gusts, visibility
+    Immediate,
+    /// Needs a model: precipitation, cloud cover, lightning
+    Predictive,
+}
+
+/// How co-occurring constraint violations compound into the overall risk level
+///
+/// Each violation's impact is treated as an independent chance of the flight going wrong,
+/// so the combined score is `1 - Π(1 - impact)`. The score maps to a level through the
+/// thresholds, and can only raise the level the violations' severities give on their own:
+/// with the defaults one or two warnings (0.3, 0.51) stay Low, while three (0.66) reach Moderate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskCompoundingPolicy {
+    pub warning_impact: f32,
+    pub critical_impact: f32,
+    pub abort_impact: f32,
+    pub moderate_threshold: f32, // Combined score at which the risk is at least Moderate
+    pub high_threshold: f32,
+    pub extreme_threshold: f32,
+}
+
+impl Default for RiskCompoundingPolicy {
+    fn default() -> Self {
+        Self {
+            warning_impact: 0.3,
+            critical_impact: 0.7,
+            abort_impact: 1.0,
+            moderate_threshold: 0.6,
+            high_threshold: 0.75,
+            extreme_threshold: 0.95,
+        }
+    }
+}
+
+impl RiskCompoundingPolicy {
+    /// Impact of a single violation, 0.0 to 1.0
+    pub fn impact(&self, severity: &ViolationSeverity) -> f32 {
+        match severity {
+            ViolationSeverity::Warning => self.warning_impact,
+            ViolationSeverity::Critical => self.critical_impact,
+            ViolationSeverity::Abort => self.abort_impact,
+        }.clamp(0.0, 1.0)
+    }
+
+    /// Combined score of co-occurring violations
+    pub fn combined_score(&self, violations: &[ConstraintViolation]) -> f32 {
+        1.0 - violations.iter().map(|v| 1.0 - self.impact(&v.severity)).product::<f32>()
+    }
+
+    /// Level the combined score alone reaches
+    pub fn compounded_level(&self, violations: &[ConstraintViolation]) -> RiskLevel {
+        let score = self.combined_score(violations);
+        if score >= self.extreme_threshold {
+            RiskLevel::Extreme
+        } else if score >= self.high_threshold {
+            RiskLevel::High
+        } else if score >= self.moderate_threshold {
+            RiskLevel::Moderate
+        } else {
+            RiskLevel::Low
+        }
+    }
+}
+
+/// Surface roughness class of the terrain under the route
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum TerrainRoughness {
+    Water,
+    OpenTerrain,
+    Suburban,
+    Forest,
+    Urban,
+}
+
+impl TerrainRoughness {
+    /// Aerodynamic roughness length in meters
+    pub fn roughness_length_m(&self) -> f32 {
+        match self {
+            TerrainRoughness::Water => 0.0002,
+            TerrainRoughness::OpenTerrain => 0.03,
+            TerrainRoughness::Suburban => 0.5,
+            TerrainRoughness::Forest => 0.8,
+            TerrainRoughness::Urban => 1.5,
+        }
+    }
+}
+
+/// Turbulence estimate for small airframes and slung payloads.
+///
+/// Intensity is the larger of the terrain estimate, 1 / ln(height / roughness length),
+/// and the one implied by the reported gusts, (gust / mean - 1) / peak factor. Gusts tilt
+/// the lifting drones, and a sling turns that tilt into a payload excursion proportional
+/// to its length, so a long sling can swing too far even when the mean wind is acceptable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurbulenceModel {
+    pub terrain: TerrainRoughness,
+    pub height_agl_m: f32,
+    pub gust_peak_factor: f32, // Peak gust = mean * (1 + peak factor * intensity)
+    pub severe_intensity: f32, // Intensity treated as severe for a small airframe
+    pub tilt_degrees_per_mps: f32, // Airframe tilt per m/s of gust increment
+    pub max_payload_swing_m: f32, // Lateral payload excursion a formation tolerates
+}
+
+impl Default for TurbulenceModel {
+    fn default() -> Self {
+        Self {
+            terrain: TerrainRoughness::OpenTerrain,
+            height_agl_m: 50.0,
+            gust_peak_factor: 3.0,
+            severe_intensity: 0.35,
+            tilt_degrees_per_mps: 2.0,
+            max_payload_swing_m: 2.0,
+        }
+    }
+}
+
+impl TurbulenceModel {
+    /// Peak gust increment over the mean wind, m/s
+    pub fn gust_increment_mps(&self, weather: &WeatherData) -> f32 {
+        let height = self.height_agl_m.max(self.terrain.roughness_length_m() * 10.0);
+        let terrain_intensity = 1.0 / (height / self.terrain.roughness_length_m()).ln();
+        let terrain_increment = self.gust_peak_factor * terrain_intensity * weather.wind_speed_mps;
+        (weather.gust_speed_mps - weather.wind_speed_mps).max(terrain_increment).max(0.0)
+    }
+
+    /// Turbulence intensity, 0.0 in calm air
+    pub fn intensity(&self, weather: &WeatherData) -> f32 {
+        if weather.wind_speed_mps <= 0.0 || self.gust_peak_factor <= 0.0 {
+            return 0.0;
+        }
+        self.gust_increment_mps(weather) / (self.gust_peak_factor * weather.wind_speed_mps)
+    }
+
+    /// Lateral excursion of the payload on the formation's longest sling; None without slings
+    pub fn payload_swing_m(&self, weather: &WeatherData, formation: &FormationConfiguration) -> Option<f32> {
+        let longest_sling_m = formation.payload_config.lifting_slings.iter()
+            .map(|sling| sling.length_m)
+            .fold(None, |longest: Option<f32>, length| Some(longest.map_or(length, |l| l.max(length))))?;
+
+        // Stiffer formations resist gust-induced tilt
+        let stability = formation.payload_config.stability_requirements.wind_stability_factor.max(0.1);
+        let tilt_degrees = (self.gust_increment_mps(weather) * self.tilt_degrees_per_mps / stability).min(90.0);
+        Some(longest_sling_m * tilt_degrees.to_radians().sin())
+    }
+
+    /// Normalized turbulence risk, 0.0 to 1.0
+    pub fn risk(&self, intensity: f32, payload_swing_m: Option<f32>) -> f32 {
+        let intensity_risk = if self.severe_intensity > 0.0 { intensity / self.severe_intensity } else { 0.0 };
+        let swing_risk = match payload_swing_m {
+            Some(swing) if self.max_payload_swing_m > 0.0 => swing / self.max_payload_swing_m,
+            _ => 0.0,
+        };
+        intensity_risk.max(swing_risk).clamp(0.0, 1.0)
+    }
+}
+
+/// Source precedence and weighting used to fuse readings into the current estimate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherFusionPolicy {
+    pub source_reliability: Vec<(WeatherSource, f32)>, // 0.0 to 1.0, unlisted sources weigh 0.5
+    pub immediate_sources: Vec<WeatherSource>, // Preferred for immediate fields
+    pub predictive_sources: Vec<WeatherSource>, // Preferred for predictive fields
+    pub non_preferred_weight: f32, // Multiplier for a source outside the field's preferred list
+    pub age_half_life_secs: f32, // Weight halves for every half-life behind the newest reading
+    pub max_reading_age_secs: u64, // Readings older than this are left out of the blend
+}
+
+impl Default for WeatherFusionPolicy {
+    fn default() -> Self {
+        Self {
+            source_reliability: vec![
+                (WeatherSource::LocalSensor, 0.9),
+                (WeatherSource::AirportMETAR, 0.85),
+                (WeatherSource::WeatherAPI, 0.8),
+                (WeatherSource::SatelliteData, 0.7),
+                (WeatherSource::ForecastModel, 0.6),
+            ],
+            immediate_sources: vec![WeatherSource::LocalSensor, WeatherSource::AirportMETAR],
+            predictive_sources: vec![WeatherSource::WeatherAPI, WeatherSource::ForecastModel, WeatherSource::SatelliteData],
+            non_preferred_weight: 0.25,
+            age_half_life_secs: 600.0,
+            max_reading_age_secs: 3600,
+        }
+    }
+}
+
+impl WeatherFusionPolicy {
+    /// Reliability configured for a source
+    pub fn reliability(&self, source: &WeatherSource) -> f32 {
+        self.source_reliability.iter()
+            .find(|(configured, _)| configured == source)
+            .map(|(_, reliability)| reliability.clamp(0.0, 1.0))
+            .unwrap_or(0.5)
+    }
+
+    /// Blend weight of a reading for one field class, given the newest reading time
+    pub fn weight(&self, reading: &WeatherData, class: WeatherFieldClass, newest: SystemTime) -> f32 {
+        let preferred = match class {
+            WeatherFieldClass::Immediate => &self.immediate_sources,
+            WeatherFieldClass::Predictive => &self.predictive_sources,
+        };
+        let precedence = if preferred.contains(&reading.source) { 1.0 } else { self.non_preferred_weight };
+        let age_secs = newest.duration_since(reading.timestamp).unwrap_or_default().as_secs_f32();
+        let decay = if self.age_half_life_secs > 0.0 {
+            0.5f32.powf(age_secs / self.age_half_life_secs)
+        } else {
+            1.0
+        };
+
+        self.reliability(&reading.source) * precedence * decay
+    }
+}
+
 /// OpenWeatherMap API response structure
 #[cfg(feature = "weather-api")]
 #[derive(Debug, Deserialize)]
@@ -285,6 +586,11 @@ impl WeatherManager {
             max_history_entries: max_history,
             api_keys: HashMap::new(),
             local_sensor_interface: None,
+            fusion_policy: WeatherFusionPolicy::default(),
+            source_readings: Vec::new(),
+            risk_compounding: RiskCompoundingPolicy::default(),
+            turbulence_model: TurbulenceModel::default(),
+            forecast: Vec::new(),
         }
     }
 
@@ -316,15 +622,47 @@ impl WeatherManager {
             max_history_entries: max_history,
             api_keys,
             local_sensor_interface,
+            fusion_policy: WeatherFusionPolicy::default(),
+            source_readings: Vec::new(),
+            risk_compounding: RiskCompoundingPolicy::default(),
+            turbulence_model: TurbulenceModel::default(),
+            forecast: Vec::new(),
         }
     }
 
+    /// Replace the source fusion policy and re-fuse the current estimate
+    pub fn set_fusion_policy(&mut self, policy: WeatherFusionPolicy) {
+        self.fusion_policy = policy;
+        self.current_weather = self.fuse_readings();
+    }
+
+    /// Replace the rule used to compound co-occurring violations into the overall risk
+    pub fn set_risk_compounding(&mut self, policy: RiskCompoundingPolicy) {
+        self.risk_compounding = policy;
+    }
+
+    /// Replace the terrain and gust model used to estimate turbulence
+    pub fn set_turbulence_model(&mut self, model: TurbulenceModel) {
+        self.turbulence_model = model;
+    }
+
+    /// Fused best estimate across every source
+    pub fn get_current_weather(&self) -> Option<&WeatherData> {
+        self.current_weather.as_ref()
+    }
+
     /// Update weather data
     pub fn update_weather(&mut self, weather: WeatherData) -> Result<(), WeatherError> {
         // Validate weather data
         self.validate_weather_data(&weather)?;
 
-        self.current_weather = Some(weather.clone());
+        // Keep only the newest reading per source; a late, older reading does not override
+        match self.source_readings.iter_mut().find(|reading| reading.source == weather.source) {
+            Some(reading) if reading.timestamp <= weather.timestamp => *reading = weather.clone(),
+            Some(_) => {}
+            None => self.source_readings.push(weather.clone()),
+        }
+        self.current_weather = self.fuse_readings();
 
         // Add to history
         self.weather_history.push(weather);
@@ -335,6 +673,90 @@ impl WeatherManager {
         Ok(())
     }
 
+    /// Replace the stored forecast series; points may arrive in any order
+    pub fn update_forecast(&mut self, series: Vec<WeatherData>) -> Result<(), WeatherError> {
+        for point in &series {
+            self.validate_weather_data(point)?;
+        }
+        let mut series = series;
+        series.sort_by_key(|point| point.timestamp);
+        self.forecast = series;
+        Ok(())
+    }
+
+    /// Stored forecast series, oldest point first
+    pub fn forecast(&self) -> &[WeatherData] {
+        &self.forecast
+    }
+
+    /// Forecast conditions at `time`, interpolated linearly between the surrounding points.
+    /// None outside the series: the forecast is not extrapolated.
+    pub fn forecast_at(&self, time: SystemTime) -> Option<WeatherData> {
+        let after_index = self.forecast.iter().position(|point| point.timestamp >= time)?;
+        let after = &self.forecast[after_index];
+        if after.timestamp == time {
+            return Some(after.clone());
+        }
+        let before = &self.forecast[after_index.checked_sub(1)?];
+
+        let span = after.timestamp.duration_since(before.timestamp).ok()?.as_secs_f32();
+        let fraction = time.duration_since(before.timestamp).ok()?.as_secs_f32() / span;
+        Some(interpolate_weather(before, after, time, fraction))
+    }
+
+    /// Conditions expected when the mission is planned to start: the forecast at the
+    /// mission's validity start if that is still ahead and covered, otherwise current weather
+    pub fn planned_weather(&self, mission: &MissionPayload) -> Result<WeatherData, WeatherError> {
+        let planned_start = mission.header.validity_start;
+        if planned_start > SystemTime::now() {
+            if let Some(forecast) = self.forecast_at(planned_start) {
+                return Ok(forecast);
+            }
+        }
+        self.current_weather.clone().ok_or(WeatherError::NoWeatherData)
+    }
+
+    /// Blend the latest reading of each source into one estimate
+    fn fuse_readings(&self) -> Option<WeatherData> {
+        let newest = self.source_readings.iter().map(|reading| reading.timestamp).max()?;
+        let max_age = Duration::from_secs(self.fusion_policy.max_reading_age_secs);
+        let readings: Vec<&WeatherData> = self.source_readings.iter()
+            .filter(|reading| newest.duration_since(reading.timestamp).unwrap_or_default() <= max_age)
+            .collect();
+        if readings.len() == 1 {
+            return Some(readings[0].clone());
+        }
+
+        let immediate: Vec<f32> = readings.iter()
+            .map(|reading| self.fusion_policy.weight(reading, WeatherFieldClass::Immediate, newest))
+            .collect();
+        let predictive: Vec<f32> = readings.iter()
+            .map(|reading| self.fusion_policy.weight(reading, WeatherFieldClass::Predictive, newest))
+            .collect();
+        let strongest = |weights: &[f32]| {
+            weights.iter().enumerate()
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(Ordering::Equal))
+                .map(|(index, _)| readings[index])
+                .unwrap_or(readings[0])
+        };
+
+        let mut fused = strongest(&immediate).clone();
+        fused.timestamp = newest;
+        fused.temperature_celsius = weighted_mean(&readings, &immediate, |r| r.temperature_celsius);
+        fused.humidity_percent = weighted_mean(&readings, &immediate, |r| r.humidity_percent);
+        fused.pressure_hpa = weighted_mean(&readings, &immediate, |r| r.pressure_hpa);
+        fused.wind_speed_mps = weighted_mean(&readings, &immediate, |r| r.wind_speed_mps);
+        fused.wind_direction_degrees = weighted_direction(&readings, &immediate);
+        fused.gust_speed_mps = weighted_mean(&readings, &immediate, |r| r.gust_speed_mps);
+        fused.visibility_meters = weighted_mean(&readings, &immediate, |r| r.visibility_meters);
+        fused.precipitation_type = strongest(&predictive).precipitation_type.clone();
+        fused.precipitation_rate_mmh = weighted_mean(&readings, &predictive, |r| r.precipitation_rate_mmh);
+        fused.cloud_cover_percent = weighted_mean(&readings, &predictive, |r| r.cloud_cover_percent);
+        fused.lightning_probability = weighted_mean(&readings, &predictive, |r| r.lightning_probability);
+
+        Some(fused)
+    }
+
     /// Fetch weather data from OpenWeatherMap API
     #[cfg(feature = "weather-api")]
     pub async fn fetch_openweather_data(&mut self, lat: f64, lon: f64) -> Result<(), WeatherError> {
@@ -503,16 +925,20 @@ impl WeatherManager {
         Ok(forecast)
     }
 
-    /// Assess weather impact on drone operations
+    /// Assess weather impact on drone operations at the mission's planned start
     pub fn assess_weather_impact(&self, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> Result<WeatherImpact, WeatherError> {
-        let weather = self.current_weather.as_ref()
-            .ok_or(WeatherError::NoWeatherData)?;
+        let planned = self.planned_weather(mission)?;
+        let weather = &planned;
+
+        if !mission.has_flyable_flight_plan() {
+            return Err(WeatherError::EmptyFlightPlan);
+        }
 
         let mut impact = WeatherImpact {
-            wind_impact: self.assess_wind_impact(weather, drone_specs),
+            wind_impact: self.assess_wind_impact(weather, mission, drone_specs),
             precipitation_impact: self.assess_precipitation_impact(weather, drone_specs),
             visibility_impact: self.assess_visibility_impact(weather),
-            temperature_impact: self.assess_temperature_impact(weather, mission),
+            temperature_impact: self.assess_temperature_impact(weather, mission, drone_specs),
             microclimate_impact: self.assess_microclimate_impact(weather, mission),
             solar_em_impact: self.assess_solar_em_impact(weather),
             overall_risk_score: 0.0,
@@ -521,8 +947,12 @@ impl WeatherManager {
 
         // Calculate overall risk score as weighted average
         let weights = [0.25, 0.20, 0.15, 0.15, 0.10, 0.10]; // Wind, precip, vis, temp, micro, solar
+        let turbulence_risk = self.turbulence_model.risk(
+            impact.wind_impact.turbulence_intensity,
+            impact.wind_impact.payload_swing_m,
+        );
         let impacts = [
-            impact.wind_impact.track_deviation_degrees / 45.0, // Normalize to 0-1
+            (impact.wind_impact.track_deviation_degrees / 45.0).max(turbulence_risk), // Normalize to 0-1
             impact.precipitation_impact.sensor_degradation_percent / 100.0,
             (100.0 - impact.visibility_impact.navigation_reliability_percent) / 100.0,
             impact.temperature_impact.battery_efficiency_loss_percent / 100.0,
@@ -541,10 +971,112 @@ impl WeatherManager {
         Ok(impact)
     }
 
-    /// Validate mission constraints against current weather
+    /// Validate mission constraints against the weather expected at the planned start
     pub fn validate_mission_constraints(&self, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> Result<ConstraintValidationResult, WeatherError> {
-        let weather = self.current_weather.as_ref()
-            .ok_or(WeatherError::NoWeatherData)?;
+        let weather = self.planned_weather(mission)?;
+
+        self.validate_constraints_for(&weather, mission, drone_specs)
+    }
+
+    /// Launch decision for the mission under the weather expected at the planned start
+    pub fn go_no_go(&self, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> Result<GoNoGoDecision, WeatherError> {
+        let weather = self.planned_weather(mission)?;
+
+        self.go_no_go_for(&weather, mission, drone_specs)
+    }
+
+    /// Launch decision for the mission under the given (observed or forecast) weather
+    pub fn go_no_go_for(&self, weather: &WeatherData, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> Result<GoNoGoDecision, WeatherError> {
+        let validation = self.validate_constraints_for(weather, mission, drone_specs)?;
+
+        let mut verdict = GoNoGoVerdict::Go;
+        let mut reasons = Vec::new();
+        for violation in &validation.violations {
+            match violation.severity {
+                ViolationSeverity::Abort => verdict = GoNoGoVerdict::NoGo,
+                ViolationSeverity::Critical if verdict == GoNoGoVerdict::Go => verdict = GoNoGoVerdict::Hold,
+                _ => {}
+            }
+            if violation.severity != ViolationSeverity::Warning {
+                reasons.push(violation.description.clone());
+            }
+        }
+
+        // Airframe limits apply regardless of mission constraints
+        if weather.wind_speed_mps > drone_specs.max_wind_speed_mps {
+            verdict = GoNoGoVerdict::NoGo;
+            reasons.push(format!("Wind speed {} m/s exceeds drone limit {} m/s",
+                weather.wind_speed_mps, drone_specs.max_wind_speed_mps));
+        }
+        if weather.gust_speed_mps > drone_specs.abort_gust_threshold_mps {
+            verdict = GoNoGoVerdict::NoGo;
+            reasons.push(format!("Gust speed {} m/s exceeds drone abort threshold {} m/s",
+                weather.gust_speed_mps, drone_specs.abort_gust_threshold_mps));
+        }
+
+        let retry_after = match verdict {
+            GoNoGoVerdict::Go => None,
+            GoNoGoVerdict::Hold => Some(Duration::from_secs(30 * 60)),
+            GoNoGoVerdict::NoGo => Some(Duration::from_secs(2 * 3600)),
+        };
+
+        Ok(GoNoGoDecision { verdict, reasons, retry_after })
+    }
+
+    /// Find the first forecast window where the mission is a Go for at least `min_duration`.
+    /// Each forecast point is taken to hold until the next point's timestamp.
+    pub fn next_acceptable_window(
+        &self,
+        mission: &MissionPayload,
+        drone_specs: &DroneSpecifications,
+        forecast: &[WeatherData],
+        min_duration: Duration
+    ) -> Option<(SystemTime, SystemTime)> {
+        let mut points: Vec<&WeatherData> = forecast.iter().collect();
+        points.sort_by_key(|point| point.timestamp);
+
+        let mut window_start: Option<SystemTime> = None;
+        for point in points {
+            let is_go = self.go_no_go_for(point, mission, drone_specs)
+                .map(|decision| decision.verdict == GoNoGoVerdict::Go)
+                .unwrap_or(false);
+
+            match (is_go, window_start) {
+                (true, None) => window_start = Some(point.timestamp),
+                (true, Some(start)) => {
+                    if point.timestamp.duration_since(start).unwrap_or_default() >= min_duration {
+                        return Some((start, point.timestamp));
+                    }
+                }
+                (false, Some(start)) => {
+                    if point.timestamp.duration_since(start).unwrap_or_default() >= min_duration {
+                        return Some((start, point.timestamp));
+                    }
+                    window_start = None;
+                }
+                (false, None) => {}
+            }
+        }
+
+        None
+    }
+
+    /// `next_acceptable_window` over the stored forecast series
+    pub fn next_acceptable_window_in_forecast(
+        &self,
+        mission: &MissionPayload,
+        drone_specs: &DroneSpecifications,
+        min_duration: Duration
+    ) -> Option<(SystemTime, SystemTime)> {
+        self.next_acceptable_window(mission, drone_specs, &self.forecast, min_duration)
+    }
+
+    /// Evaluate mission constraints against a specific weather observation
+    fn validate_constraints_for(&self, weather: &WeatherData, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> Result<ConstraintValidationResult, WeatherError> {
+        // Energy and safety figures are meaningless for a mission that cannot fly
+        if !mission.has_flyable_flight_plan() {
+            return Err(WeatherError::EmptyFlightPlan);
+        }
 
         let mut violations = Vec::new();
         let mut adaptations = Vec::new();
@@ -556,7 +1088,10 @@ impl WeatherManager {
         self.check_safety_constraints(weather, &mission.constraints.safety, &mut violations, &mut adaptations)?;
 
         // Check energy constraints considering weather impacts
-        self.check_energy_constraints(weather, &mission.constraints.energy, drone_specs, &mut violations, &mut adaptations)?;
+        self.check_energy_constraints(weather, mission, drone_specs, &mut violations, &mut adaptations)?;
+
+        // Check turbulence against airframe and slung-payload stability
+        self.check_turbulence_constraints(weather, mission, &mut violations, &mut adaptations);
 
         let is_valid = violations.iter().all(|v| v.severity != ViolationSeverity::Abort);
 
@@ -570,16 +1105,38 @@ impl WeatherManager {
         })
     }
 
+    /// Extra power drawn on the worst leg of the mission, from the airspeed needed to hold
+    /// each leg's planned ground speed against the wind
+    fn wind_power_draw_w(&self, weather: &WeatherData, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> f32 {
+        let mut worst_delta_w: Option<f32> = None;
+
+        for (path, from, to) in mission.flight_plan.segments() {
+            let ground_speed = to.speed_limit_mps.unwrap_or(path.max_speed_mps);
+            let track = from.position.bearing_to(&to.position);
+            let airspeed = required_airspeed_mps(ground_speed, track, weather.wind_speed_mps, weather.wind_direction_degrees);
+            let delta_w = drone_specs.airspeed_power_delta_w(ground_speed, airspeed);
+            worst_delta_w = Some(worst_delta_w.map_or(delta_w, |worst| worst.max(delta_w)));
+        }
+
+        // Without legs assume a direct headwind at the drone's cruise speed
+        worst_delta_w
+            .unwrap_or_else(|| {
+                let airspeed = drone_specs.max_speed_mps + weather.wind_speed_mps;
+                drone_specs.airspeed_power_delta_w(drone_specs.max_speed_mps, airspeed)
+            })
+            .max(0.0) // Tailwind legs are not credited
+    }
+
     /// Assess wind impact on drone operations
-    fn assess_wind_impact(&self, weather: &WeatherData, drone_specs: &DroneSpecifications) -> WindImpact {
+    fn assess_wind_impact(&self, weather: &WeatherData, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> WindImpact {
         let wind_speed = weather.wind_speed_mps;
         let gust_speed = weather.gust_speed_mps;
 
         // Calculate track deviation based on wind speed
         let track_deviation = (wind_speed * 10.0).min(45.0); // Max 45 degrees deviation
 
-        // Increased power draw due to wind resistance
-        let power_increase = wind_speed * drone_specs.power_wind_coefficient;
+        // Increased power draw from the extra airspeed needed against the wind
+        let power_increase = self.wind_power_draw_w(weather, mission, drone_specs);
 
         // Endurance reduction
         let endurance_reduction = if wind_speed > drone_specs.max_wind_speed_mps {
@@ -595,6 +1152,9 @@ impl WeatherManager {
             None
         };
 
+        let payload_swing = mission.formation_config.as_ref()
+            .and_then(|formation| self.turbulence_model.payload_swing_m(weather, formation));
+
         WindImpact {
             track_deviation_degrees: track_deviation,
             increased_power_draw_w: power_increase,
@@ -602,6 +1162,8 @@ impl WeatherManager {
             speed_cap_mps: speed_cap,
             heading_correction_needed: track_deviation > 10.0,
             abort_threshold_exceeded: gust_speed > drone_specs.abort_gust_threshold_mps,
+            turbulence_intensity: self.turbulence_model.intensity(weather),
+            payload_swing_m: payload_swing,
         }
     }
 
@@ -675,17 +1237,11 @@ impl WeatherManager {
     }
 
     /// Assess temperature impact
-    fn assess_temperature_impact(&self, weather: &WeatherData, mission: &MissionPayload) -> TemperatureImpact {
+    fn assess_temperature_impact(&self, weather: &WeatherData, mission: &MissionPayload, drone_specs: &DroneSpecifications) -> TemperatureImpact {
         let temp = weather.temperature_celsius;
 
-        // Battery efficiency loss
-        let efficiency_loss = if temp < 0.0 {
-            (0.0 - temp).min(20.0) * 0.5 // 0.5% loss per degree below 0
-        } else if temp > 30.0 {
-            (temp - 30.0) * 0.3 // 0.3% loss per degree above 30
-        } else {
-            0.0
-        };
+        // Battery efficiency loss, hot or cold, comes from the drone's derating curve alone
+        let efficiency_loss = (1.0 - drone_specs.battery_derating.capacity_multiplier(temp)) * 100.0;
 
         // Component stress risk
         let stress_risk = if temp < -10.0 || temp > 40.0 { 0.8 } else { 0.0 };
@@ -795,6 +1351,10 @@ impl WeatherManager {
             actions.push("Reduce mission duration to compensate for efficiency loss".to_string());
         }
 
+        if impact.wind_impact.payload_swing_m.is_some_and(|swing| swing > self.turbulence_model.max_payload_swing_m) {
+            actions.push("Shorten slings or hold until gusts ease to limit payload swing".to_string());
+        }
+
         if impact.microclimate_impact.waypoint_smoothing_needed {
             actions.push("Apply waypoint smoothing to reduce turbulence effects".to_string());
         }
@@ -821,10 +1381,31 @@ pub struct DroneSpecifications {
     pub max_wind_speed_mps: f32,
     pub max_speed_mps: f32,
     pub abort_gust_threshold_mps: f32,
-    pub power_wind_coefficient: f32, // Watts per m/s of wind
+    pub power_wind_coefficient: f32, // Watts per m/s of airspeed above ground speed
     pub mass_kg: f32,
     pub battery_capacity_wh: f32,
     pub sensor_types: Vec<String>,
+    #[serde(default)]
+    pub battery_derating: BatteryDeratingCurve, // Usable capacity vs. battery temperature
+}
+
+impl DroneSpecifications {
+    /// Extra power needed to fly at `airspeed_mps` to hold `ground_speed_mps`
+    pub fn airspeed_power_delta_w(&self, ground_speed_mps: f32, airspeed_mps: f32) -> f32 {
+        (airspeed_mps - ground_speed_mps) * self.power_wind_coefficient
+    }
+}
+
+/// Airspeed required to hold a ground speed along a track in the given wind.
+/// `wind_from_degrees` uses the meteorological convention (direction the wind blows from).
+pub fn required_airspeed_mps(ground_speed_mps: f32, track_degrees: f32, wind_speed_mps: f32, wind_from_degrees: f32) -> f32 {
+    let track = track_degrees.to_radians();
+    let wind_to = (wind_from_degrees + 180.0).to_radians();
+
+    // Air velocity = ground velocity - wind velocity (east, north components)
+    let air_east = ground_speed_mps * track.sin() - wind_speed_mps * wind_to.sin();
+    let air_north = ground_speed_mps * track.cos() - wind_speed_mps * wind_to.cos();
+    (air_east * air_east + air_north * air_north).sqrt()
 }
 
 /// Weather processing errors
@@ -838,6 +1419,8 @@ pub enum WeatherError {
     WeatherDataTooOld(u64),
     #[error("Mission constraint validation failed")]
     ConstraintValidationFailed,
+    #[error("Mission has no flyable flight plan")]
+    EmptyFlightPlan,
 }
 
 impl WeatherManager {
@@ -1005,23 +1588,22 @@ impl WeatherManager {
     fn check_energy_constraints(
         &self,
         weather: &WeatherData,
-        constraints: &EnergyConstraints,
+        mission: &MissionPayload,
         drone_specs: &DroneSpecifications,
         violations: &mut Vec<ConstraintViolation>,
         adaptations: &mut Vec<WeatherAdaptation>
     ) -> Result<(), WeatherError> {
-        // Calculate weather-adjusted power consumption
-        let wind_power_increase = weather.wind_speed_mps * drone_specs.power_wind_coefficient;
-        let temp_efficiency_loss = if weather.temperature_celsius > 30.0 {
-            0.1 // 10% efficiency loss at high temp
-        } else {
-            0.0
-        };
+        let constraints = &mission.constraints.energy;
 
-        let adjusted_power_consumption = constraints.expected_consumption_wh * (1.0 + temp_efficiency_loss);
-
-        // Check if battery reserve is sufficient
-        let available_energy = drone_specs.battery_capacity_wh * constraints.reserve_margin_soc;
+        // Calculate weather-adjusted power consumption
+        let wind_power_increase = self.wind_power_draw_w(weather, mission, drone_specs);
+        let wind_energy_wh = wind_power_increase * mission.header.max_execution_duration.as_secs_f32() / 3600.0;
+        let adjusted_power_consumption = constraints.expected_consumption_wh + wind_energy_wh;
+
+        // Check if battery reserve is sufficient. Temperature is accounted for once, by derating
+        // capacity; consumption is not inflated for heat on top of it.
+        let derating = drone_specs.battery_derating.capacity_multiplier(weather.temperature_celsius);
+        let available_energy = drone_specs.battery_capacity_wh * derating * constraints.reserve_margin_soc;
         if adjusted_power_consumption > available_energy {
             violations.push(ConstraintViolation {
                 constraint_type: "energy_reserve".to_string(),
@@ -1044,6 +1626,51 @@ impl WeatherManager {
         Ok(())
     }
 
+    /// Check turbulence against the airframe and, for formations, slung-payload swing
+    fn check_turbulence_constraints(
+        &self,
+        weather: &WeatherData,
+        mission: &MissionPayload,
+        violations: &mut Vec<ConstraintViolation>,
+        adaptations: &mut Vec<WeatherAdaptation>
+    ) {
+        let intensity = self.turbulence_model.intensity(weather);
+        if intensity > self.turbulence_model.severe_intensity {
+            violations.push(ConstraintViolation {
+                constraint_type: "turbulence".to_string(),
+                severity: ViolationSeverity::Warning,
+                description: format!("Turbulence intensity {:.2} exceeds severe threshold {:.2}",
+                    intensity, self.turbulence_model.severe_intensity),
+                affected_components: vec!["flight_control".to_string()],
+                remediation_required: false,
+            });
+        }
+
+        let Some(formation) = mission.formation_config.as_ref() else {
+            return;
+        };
+        let Some(swing) = self.turbulence_model.payload_swing_m(weather, formation) else {
+            return;
+        };
+        if swing > self.turbulence_model.max_payload_swing_m {
+            violations.push(ConstraintViolation {
+                constraint_type: "payload_swing".to_string(),
+                severity: ViolationSeverity::Critical,
+                description: format!("Gusts of {:.1} m/s over {:.1} m/s mean wind swing the payload {:.1}m, limit {:.1}m",
+                    weather.gust_speed_mps, weather.wind_speed_mps, swing, self.turbulence_model.max_payload_swing_m),
+                affected_components: vec!["payload".to_string(), "formation".to_string()],
+                remediation_required: true,
+            });
+
+            adaptations.push(WeatherAdaptation {
+                adaptation_type: AdaptationType::SpeedAdjustment,
+                description: "Reduce formation speed and damp payload swing until gusts ease".to_string(),
+                parameter_changes: HashMap::from([("speed_factor".to_string(), 0.5)]),
+                route_modifications: Vec::new(),
+            });
+        }
+    }
+
     /// Assess overall risk from violations and adaptations
     fn assess_overall_risk(&self, violations: &[ConstraintViolation], adaptations: &[WeatherAdaptation]) -> RiskAssessment {
         let critical_count = violations.iter()
@@ -1054,7 +1681,7 @@ impl WeatherManager {
             .filter(|v| v.severity == ViolationSeverity::Abort)
             .count();
 
-        let risk_level = if abort_count > 0 {
+        let severity_level = if abort_count > 0 {
             RiskLevel::Extreme
         } else if critical_count > 2 {
             RiskLevel::High
@@ -1064,14 +1691,14 @@ impl WeatherManager {
             RiskLevel::Low
         };
 
+        // Co-occurring violations may escalate the level, never lower it
+        let compounded_level = self.risk_compounding.compounded_level(violations);
+        let risk_level = if compounded_level > severity_level { compounded_level } else { severity_level };
+
         let risk_factors = violations.iter().map(|v| {
             RiskFactor {
                 factor_type: v.constraint_type.clone(),
-                impact_level: match v.severity {
-                    ViolationSeverity::Warning => 0.3,
-                    ViolationSeverity::Critical => 0.7,
-                    ViolationSeverity::Abort => 1.0,
-                },
+                impact_level: self.risk_compounding.impact(&v.severity),
                 description: v.description.clone(),
                 mitigation_measures: if v.remediation_required {
                     vec!["Apply recommended adaptations".to_string(), "Monitor conditions closely".to_string()]
@@ -1091,9 +1718,57 @@ impl WeatherManager {
     }
 }
 
+/// Weighted mean of one field; falls back to a plain mean when every weight is zero
+fn weighted_mean(readings: &[&WeatherData], weights: &[f32], field: impl Fn(&WeatherData) -> f32) -> f32 {
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 {
+        return readings.iter().map(|reading| field(reading)).sum::<f32>() / readings.len() as f32;
+    }
+    readings.iter().zip(weights).map(|(reading, weight)| field(reading) * weight).sum::<f32>() / total
+}
+
+/// Weighted circular mean of wind direction so 350° and 10° blend to 0°, not 180°
+fn weighted_direction(readings: &[&WeatherData], weights: &[f32]) -> f32 {
+    let (east, north) = readings.iter().zip(weights).fold((0.0f32, 0.0f32), |(east, north), (reading, weight)| {
+        let radians = reading.wind_direction_degrees.to_radians();
+        (east + radians.sin() * weight, north + radians.cos() * weight)
+    });
+    if east == 0.0 && north == 0.0 {
+        return readings[0].wind_direction_degrees;
+    }
+    east.atan2(north).to_degrees().rem_euclid(360.0)
+}
+
+/// Point `fraction` of the way from `before` to `after`. Wind direction turns the short way
+/// round; categorical fields come from the nearer point.
+fn interpolate_weather(before: &WeatherData, after: &WeatherData, time: SystemTime, fraction: f32) -> WeatherData {
+    let lerp = |from: f32, to: f32| from + (to - from) * fraction;
+    let nearer = if fraction < 0.5 { before } else { after };
+    let turn = (after.wind_direction_degrees - before.wind_direction_degrees + 540.0).rem_euclid(360.0) - 180.0;
+
+    WeatherData {
+        timestamp: time,
+        location: nearer.location.clone(),
+        temperature_celsius: lerp(before.temperature_celsius, after.temperature_celsius),
+        humidity_percent: lerp(before.humidity_percent, after.humidity_percent),
+        wind_speed_mps: lerp(before.wind_speed_mps, after.wind_speed_mps),
+        wind_direction_degrees: (before.wind_direction_degrees + turn * fraction).rem_euclid(360.0),
+        gust_speed_mps: lerp(before.gust_speed_mps, after.gust_speed_mps),
+        visibility_meters: lerp(before.visibility_meters, after.visibility_meters),
+        precipitation_type: nearer.precipitation_type.clone(),
+        precipitation_rate_mmh: lerp(before.precipitation_rate_mmh, after.precipitation_rate_mmh),
+        pressure_hpa: lerp(before.pressure_hpa, after.pressure_hpa),
+        cloud_cover_percent: lerp(before.cloud_cover_percent, after.cloud_cover_percent),
+        lightning_probability: lerp(before.lightning_probability, after.lightning_probability),
+        source: nearer.source.clone(),
+        forecast_horizon_hours: nearer.forecast_horizon_hours,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::{formation, path_through};
 
     #[test]
     fn test_weather_impact_assessment() {
@@ -1129,6 +1804,7 @@ mod tests {
             mass_kg: 2.5,
             battery_capacity_wh: 100.0,
             sensor_types: vec!["camera".to_string(), "lidar".to_string()],
+            battery_derating: BatteryDeratingCurve::default(),
         };
 
         let mission = MissionPayload::default();
@@ -1221,4 +1897,312 @@ mod tests {
         assert!(config.local_sensor_enabled);
         assert_eq!(config.fallback_sources.len(), 2);
     }
+
+    fn flyable_mission() -> MissionPayload {
+        let mut mission = MissionPayload::default();
+        mission.constraints.energy.expected_consumption_wh = 5.0;
+        mission.flight_plan.paths = vec![path_through(1, &[(45.0, 2.0), (45.01, 2.0)])];
+        mission
+    }
+
+    fn forecast_point(timestamp: SystemTime, wind_speed_mps: f32) -> WeatherData {
+        WeatherData {
+            timestamp,
+            location: GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 },
+            temperature_celsius: 18.0,
+            humidity_percent: 60.0,
+            wind_speed_mps,
+            wind_direction_degrees: 270.0,
+            gust_speed_mps: wind_speed_mps + 2.0,
+            visibility_meters: 8000.0,
+            precipitation_type: None,
+            precipitation_rate_mmh: 0.0,
+            pressure_hpa: 1013.0,
+            cloud_cover_percent: 30.0,
+            lightning_probability: 0.0,
+            source: WeatherSource::ForecastModel,
+            forecast_horizon_hours: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_next_acceptable_window_after_winds_drop() {
+        let manager = WeatherManager::new(10);
+        let drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 18.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: vec!["camera".to_string()],
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+
+        // Strong winds for the first two hours, calm afterwards (30 minute steps)
+        let start = SystemTime::now();
+        let forecast: Vec<WeatherData> = (0..10)
+            .map(|step| {
+                let wind = if step < 4 { 14.0 } else { 4.0 };
+                forecast_point(start + Duration::from_secs(step * 1800), wind)
+            })
+            .collect();
+
+        let (window_start, window_end) = manager
+            .next_acceptable_window(&flyable_mission(), &drone_specs, &forecast, Duration::from_secs(3600))
+            .expect("a window should open once winds drop");
+
+        assert_eq!(window_start, start + Duration::from_secs(2 * 3600));
+        assert!(window_end.duration_since(window_start).unwrap() >= Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_headwind_raises_airspeed_and_power() {
+        let drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 18.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: Vec::new(),
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+
+        // Flying north at 10 m/s ground speed
+        let still_air = required_airspeed_mps(10.0, 0.0, 0.0, 0.0);
+        let headwind = required_airspeed_mps(10.0, 0.0, 5.0, 0.0);
+        let tailwind = required_airspeed_mps(10.0, 0.0, 5.0, 180.0);
+
+        assert!((still_air - 10.0).abs() < 1e-4);
+        assert!((headwind - 15.0).abs() < 1e-4);
+        assert!((tailwind - 5.0).abs() < 1e-4);
+        assert!(drone_specs.airspeed_power_delta_w(10.0, headwind) > drone_specs.airspeed_power_delta_w(10.0, still_air));
+
+        // Flyable mission tracks north, so a northerly wind is a headwind
+        let manager = WeatherManager::new(10);
+        let mut weather = forecast_point(SystemTime::now(), 5.0);
+        weather.wind_direction_degrees = 0.0;
+        let headwind_draw = manager.wind_power_draw_w(&weather, &flyable_mission(), &drone_specs);
+        weather.wind_direction_degrees = 180.0;
+        let tailwind_draw = manager.wind_power_draw_w(&weather, &flyable_mission(), &drone_specs);
+
+        assert!((headwind_draw - 25.0).abs() < 0.5);
+        assert_eq!(tailwind_draw, 0.0);
+    }
+
+    #[test]
+    fn test_go_no_go_over_wind_is_no_go() {
+        let manager = WeatherManager::new(10);
+        let drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 18.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: Vec::new(),
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+
+        let decision = manager
+            .go_no_go_for(&forecast_point(SystemTime::now(), 16.0), &flyable_mission(), &drone_specs)
+            .unwrap();
+
+        assert_eq!(decision.verdict, GoNoGoVerdict::NoGo);
+        assert!(!decision.reasons.is_empty());
+        assert!(decision.retry_after.is_some());
+    }
+
+    #[test]
+    fn test_temperature_impact_uses_drone_derating_curve() {
+        let manager = WeatherManager::new(10);
+        let mut drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 18.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: Vec::new(),
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+        let mut weather = forecast_point(SystemTime::now(), 4.0);
+        weather.temperature_celsius = 5.0;
+
+        let default_impact = manager.assess_temperature_impact(&weather, &flyable_mission(), &drone_specs);
+        assert!(default_impact.battery_efficiency_loss_percent > 0.0);
+
+        // A heated pack keeps full capacity in the cold
+        drone_specs.battery_derating = BatteryDeratingCurve { points: vec![(0.0, 1.0)] };
+        let heated_impact = manager.assess_temperature_impact(&weather, &flyable_mission(), &drone_specs);
+        assert_eq!(heated_impact.battery_efficiency_loss_percent, 0.0);
+    }
+
+    #[test]
+    fn test_hot_battery_is_derated_once_in_energy_check() {
+        let manager = WeatherManager::new(10);
+        let drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 18.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: Vec::new(),
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+        let mut weather = forecast_point(SystemTime::now(), 0.0);
+        weather.temperature_celsius = 45.0;
+
+        // 100 Wh derated to 90 Wh at 45°C, half of it held in reserve
+        let energy_violations = |consumption_wh| {
+            let mut mission = flyable_mission();
+            mission.constraints.energy.expected_consumption_wh = consumption_wh;
+            mission.constraints.energy.reserve_margin_soc = 0.5;
+            let mut violations = Vec::new();
+            manager.check_energy_constraints(&weather, &mission, &drone_specs, &mut violations, &mut Vec::new()).unwrap();
+            violations.iter().filter(|violation| violation.constraint_type == "energy_reserve").count()
+        };
+        assert_eq!(energy_violations(44.0), 0);
+        assert_eq!(energy_violations(46.0), 1);
+    }
+
+    #[test]
+    fn test_recent_sensor_and_older_api_reading_fuse_by_weight() {
+        let mut manager = WeatherManager::new(10);
+        let now = SystemTime::now();
+
+        let mut sensor = forecast_point(now, 6.0);
+        sensor.source = WeatherSource::LocalSensor;
+        sensor.forecast_horizon_hours = None;
+        sensor.temperature_celsius = 20.0;
+        sensor.cloud_cover_percent = 0.0;
+
+        let mut api = forecast_point(now - Duration::from_secs(600), 10.0);
+        api.source = WeatherSource::WeatherAPI;
+        api.forecast_horizon_hours = None;
+        api.temperature_celsius = 10.0;
+        api.cloud_cover_percent = 80.0;
+        api.precipitation_type = Some("rain".to_string());
+
+        manager.update_weather(sensor).unwrap();
+        manager.update_weather(api).unwrap();
+        let fused = manager.get_current_weather().unwrap();
+
+        // Immediate fields: sensor 0.9 vs API 0.8 * 0.5 (one half-life) * 0.25 (not preferred)
+        assert!((fused.temperature_celsius - 19.0).abs() < 1e-3);
+        assert!((fused.wind_speed_mps - 6.4).abs() < 1e-3);
+        assert!((fused.wind_direction_degrees - 270.0).abs() < 1e-2);
+        // Predictive fields: sensor 0.9 * 0.25 vs API 0.8 * 0.5
+        assert!((fused.cloud_cover_percent - 51.2).abs() < 1e-3);
+        assert_eq!(fused.precipitation_type.as_deref(), Some("rain"));
+        assert_eq!(fused.source, WeatherSource::LocalSensor);
+        assert_eq!(fused.timestamp, now);
+    }
+
+    #[test]
+    fn test_co_occurring_warnings_compound_to_higher_risk() {
+        let manager = WeatherManager::new(10);
+        let warning = |constraint_type: &str| ConstraintViolation {
+            constraint_type: constraint_type.to_string(),
+            severity: ViolationSeverity::Warning,
+            description: format!("marginal {}", constraint_type),
+            affected_components: Vec::new(),
+            remediation_required: false,
+        };
+        let marginal = vec![warning("wind_speed"), warning("visibility"), warning("energy_reserve")];
+
+        for single in &marginal {
+            let alone = manager.assess_overall_risk(std::slice::from_ref(single), &[]);
+            assert_eq!(alone.overall_risk_level, RiskLevel::Low);
+        }
+        assert_eq!(manager.assess_overall_risk(&marginal[..2], &[]).overall_risk_level, RiskLevel::Low);
+        let together = manager.assess_overall_risk(&marginal, &[]);
+        assert_eq!(together.overall_risk_level, RiskLevel::Moderate);
+
+        // A stricter rule escalates further
+        let mut strict = WeatherManager::new(10);
+        strict.set_risk_compounding(RiskCompoundingPolicy { high_threshold: 0.6, ..Default::default() });
+        assert_eq!(strict.assess_overall_risk(&marginal, &[]).overall_risk_level, RiskLevel::High);
+    }
+
+    fn slung_formation(sling_length_m: f32) -> FormationConfiguration {
+        let mut config = formation(Vec::new(), 20.0, 10.0);
+        config.payload_config.lifting_slings = vec![LiftingSling {
+            sling_id: "sling_1".to_string(),
+            drone_assignment: "lift_1".to_string(),
+            attachment_point: GeoCoordinate { latitude: 45.0, longitude: 2.0, altitude_msl: 100.0 },
+            sling_type: SlingType::Kevlar { diameter_mm: 6.0 },
+            length_m: sling_length_m,
+            max_load_kg: 50.0,
+            tension_sensor: None,
+        }];
+        config
+    }
+
+    #[test]
+    fn test_gusty_air_flags_payload_swing_on_long_sling() {
+        let manager = WeatherManager::new(10);
+        let drone_specs = DroneSpecifications {
+            max_wind_speed_mps: 12.0,
+            max_speed_mps: 15.0,
+            abort_gust_threshold_mps: 15.0,
+            power_wind_coefficient: 5.0,
+            mass_kg: 2.5,
+            battery_capacity_wh: 100.0,
+            sensor_types: vec!["camera".to_string()],
+            battery_derating: BatteryDeratingCurve::default(),
+        };
+        let swing_flagged = |gust_speed_mps: f32, sling_length_m: f32| {
+            let mut weather = forecast_point(SystemTime::now(), 5.0);
+            weather.gust_speed_mps = gust_speed_mps;
+            let mut mission = flyable_mission();
+            mission.formation_config = Some(slung_formation(sling_length_m));
+            let result = manager.validate_constraints_for(&weather, &mission, &drone_specs).unwrap();
+            result.violations.iter().any(|v| v.constraint_type == "payload_swing")
+        };
+
+        // Same 5 m/s mean wind: steady air is fine, a 2.4 gust ratio swings a 15 m sling too far
+        assert!(!swing_flagged(6.0, 15.0));
+        assert!(swing_flagged(12.0, 15.0));
+        // A short sling keeps the excursion within limits in the same gusts
+        assert!(!swing_flagged(12.0, 2.0));
+
+        let mut weather = forecast_point(SystemTime::now(), 5.0);
+        weather.gust_speed_mps = 12.0;
+        let mut mission = flyable_mission();
+        mission.formation_config = Some(slung_formation(15.0));
+        let impact = manager.assess_wind_impact(&weather, &mission, &drone_specs);
+        assert!(manager.turbulence_model.risk(impact.turbulence_intensity, impact.payload_swing_m) >= 1.0);
+    }
+
+    #[test]
+    fn test_forecast_at_blends_surrounding_points() {
+        let mut manager = WeatherManager::new(10);
+        let start = SystemTime::now() + Duration::from_secs(3600);
+        let mut early = forecast_point(start, 4.0);
+        early.temperature_celsius = 10.0;
+        early.wind_direction_degrees = 350.0;
+        let mut late = forecast_point(start + Duration::from_secs(2 * 3600), 12.0);
+        late.temperature_celsius = 20.0;
+        late.wind_direction_degrees = 30.0;
+        manager.update_forecast(vec![late, early]).unwrap();
+
+        // A quarter of the way from the first point to the second
+        let blended = manager.forecast_at(start + Duration::from_secs(1800)).unwrap();
+        assert!((blended.wind_speed_mps - 6.0).abs() < 1e-3);
+        assert!((blended.temperature_celsius - 12.5).abs() < 1e-3);
+        // 350° to 30° turns through north, not back through south
+        let direction = blended.wind_direction_degrees;
+        assert!(direction < 1e-2 || direction > 360.0 - 1e-2);
+
+        assert!(manager.forecast_at(start - Duration::from_secs(60)).is_none());
+        assert!(manager.forecast_at(start + Duration::from_secs(3 * 3600)).is_none());
+
+        // Assessments use the forecast at the planned start, not current conditions
+        let mut mission = flyable_mission();
+        mission.header.validity_start = start + Duration::from_secs(3600);
+        assert!((manager.planned_weather(&mission).unwrap().wind_speed_mps - 8.0).abs() < 1e-3);
+    }
 }
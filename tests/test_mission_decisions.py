@@ -0,0 +1,48 @@
+"""Decision APIs exposed to mission planners through the Python bindings."""
+
+import time
+
+import pytest
+
+realgibber = pytest.importorskip("realgibber")
+
+
+def weather(wind_speed_mps, gust_speed_mps):
+    return realgibber.WeatherData(
+        time.time(),
+        realgibber.GeoCoordinate(45.0, 2.0, 100.0),
+        15.0,             # temperature_celsius
+        60.0,             # humidity_percent
+        wind_speed_mps,
+        270.0,            # wind_direction_degrees
+        gust_speed_mps,
+        10000.0,          # visibility_meters
+        0.0,              # precipitation_rate_mmh
+        1013.0,           # pressure_hpa
+        20.0,             # cloud_cover_percent
+        0.0,              # lightning_probability
+    )
+
+
+def drone_specs():
+    return realgibber.DroneSpecifications(12.0, 15.0, 15.0, 5.0, 2.5, 100.0, 1)
+
+
+def test_over_wind_mission_is_held_or_grounded():
+    manager = realgibber.WeatherManager(10)
+    manager.update_weather(weather(wind_speed_mps=20.0, gust_speed_mps=25.0))
+    mission = realgibber.MissionPayload("Windy survey", [1] * 16)
+
+    decision = manager.go_no_go(mission, drone_specs())
+
+    assert decision["decision"] in ("hold", "no_go")
+    assert decision["reasons"]
+    assert decision["retry_after"] > 0
+
+
+def test_default_mission_reports_empty_flight_plan():
+    mission = realgibber.MissionPayload("Empty", [2] * 16)
+
+    issues = mission.validate()
+
+    assert any(issue.startswith("empty_flight_plan") for issue in issues)